@@ -0,0 +1,94 @@
+use std::{collections::HashMap, path::PathBuf};
+
+use crm_config::{Errors, Validate};
+use serde::{Deserialize, Serialize};
+
+use crate::{DEFAULT_AUDIENCE, DEFAULT_ISSUER};
+
+/// how the JWT of each request is verified
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuthConfig {
+    /// Ed25519 public key for the tokens without a key id
+    #[serde(default)]
+    pub pk: Option<String>,
+    /// Ed25519 public keys selected by the key id of the token, for key rotation
+    #[serde(default)]
+    pub keys: Vec<AuthKey>,
+    /// JWKS file with more Ed25519 keys, reloaded when it changes
+    #[serde(default)]
+    pub jwks: Option<PathBuf>,
+    #[serde(default = "default_issuers")]
+    pub issuers: Vec<String>,
+    #[serde(default = "default_audiences")]
+    pub audiences: Vec<String>,
+    /// clock tolerance in seconds when checking the expiry of a token
+    #[serde(default = "default_leeway")]
+    pub leeway: u64,
+    /// max age in seconds of a token since it was issued, unlimited if unset
+    #[serde(default)]
+    pub max_validity: Option<u64>,
+    /// tokens other services authenticate with instead of a user token, by service name
+    #[serde(default)]
+    pub service_tokens: HashMap<String, String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuthKey {
+    pub kid: String,
+    pub pk: String,
+}
+
+impl Validate for AuthConfig {
+    fn validate(&self, errors: &mut Errors) {
+        if self.pk.is_none() && self.keys.is_empty() && self.jwks.is_none() {
+            errors.add("", "one of pk, keys or jwks must be set");
+        }
+        if self.issuers.is_empty() {
+            errors.add("issuers", "must not be empty");
+        }
+        if self.audiences.is_empty() {
+            errors.add("audiences", "must not be empty");
+        }
+        for (i, key) in self.keys.iter().enumerate() {
+            if self.keys[..i].iter().any(|k| k.kid == key.kid) {
+                errors.add(&format!("keys[{}].kid", i), "is not unique");
+            }
+        }
+        if let Some(path) = &self.jwks {
+            if !path.exists() {
+                errors.add("jwks", format!("{} does not exist", path.display()));
+            }
+        }
+    }
+}
+
+impl AuthConfig {
+    /// report each of `required_services` without a token, the config being under `auth` in
+    /// the config whose env vars start with `prefix`
+    pub fn require_services(&self, prefix: &str, required_services: &[&str], errors: &mut Errors) {
+        for name in required_services {
+            if !self.service_tokens.contains_key(*name) {
+                errors.add(
+                    &format!("auth.service_tokens.{}", name),
+                    format!(
+                        "must be set with {}_AUTH__SERVICE_TOKENS__{}",
+                        prefix,
+                        name.to_uppercase()
+                    ),
+                );
+            }
+        }
+    }
+}
+
+fn default_issuers() -> Vec<String> {
+    vec![DEFAULT_ISSUER.to_string()]
+}
+
+fn default_audiences() -> Vec<String> {
+    vec![DEFAULT_AUDIENCE.to_string()]
+}
+
+fn default_leeway() -> u64 {
+    15 * 60
+}
@@ -0,0 +1,521 @@
+mod config;
+pub mod scope;
+mod tls;
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    sync::{Arc, RwLock},
+    time::SystemTime,
+};
+
+use anyhow::{bail, Context};
+use jwt_simple::prelude::*;
+use tonic::{
+    metadata::{Ascii, MetadataValue},
+    service::Interceptor,
+    Status,
+};
+use tracing::{info, warn};
+
+pub use config::{AuthConfig, AuthKey};
+pub use scope::{Authenticated, Method, MissingScope, Scopes, WithMethod};
+pub use tls::{ClientTls, Pem, TlsConfig};
+
+/// how often the JWKS file is checked for changes
+const JWKS_POLL_SECS: u64 = 10;
+pub const DEFAULT_ISSUER: &str = "chat_server";
+pub const DEFAULT_AUDIENCE: &str = "chat_web";
+
+/// verifies the token of each request, either a user token or the token of a service. The key
+/// of a user token is selected by its key id, a token without one is tried against every key
+#[derive(Debug, Clone)]
+pub struct DecodingKey {
+    keys: Arc<RwLock<KeySet>>,
+    options: VerificationOptions,
+    jwks: Option<PathBuf>,
+    /// (service name, token)
+    services: Arc<Vec<(String, String)>>,
+    /// scopes of the methods of the server, set when it wraps one
+    scopes: Option<Scopes>,
+}
+
+/// attaches the token of this service to the requests it sends to other services, or a user
+/// token to the requests of a client
+#[derive(Debug, Clone, Default)]
+pub struct ServiceToken(Option<MetadataValue<Ascii>>);
+
+/// the service that sent a request, set instead of the user for service tokens
+#[derive(Debug, Clone, PartialEq)]
+pub struct Service {
+    pub name: String,
+}
+
+/// signs tokens of users, for tests and local tools
+#[derive(Clone)]
+pub struct EncodingKey {
+    key: Ed25519KeyPair,
+    issuer: String,
+    audience: String,
+}
+
+#[derive(Debug, Default)]
+struct KeySet {
+    /// keys of the config, they never change
+    fixed: Vec<(Option<String>, Ed25519PublicKey)>,
+    /// keys of the JWKS file, as of its last modification
+    jwks: Vec<(Option<String>, Ed25519PublicKey)>,
+    jwks_modified: Option<SystemTime>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct User {
+    pub id: i64,
+    pub ws_id: i64,
+    pub fullname: String,
+    pub email: String,
+    #[serde(skip)]
+    pub password_hash: String,
+    pub created_at: String,
+    /// what the user is allowed to do, e.g. campaign:run
+    #[serde(default)]
+    pub scopes: Vec<String>,
+}
+
+/// a JSON Web Key Set, only its Ed25519 keys are used
+#[derive(Debug, Deserialize)]
+struct Jwks {
+    keys: Vec<Jwk>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Jwk {
+    kty: String,
+    #[serde(default)]
+    crv: Option<String>,
+    #[serde(default)]
+    kid: Option<String>,
+    #[serde(default)]
+    x: Option<String>,
+}
+
+impl DecodingKey {
+    pub fn load(config: &AuthConfig) -> Result<Self, jwt_simple::Error> {
+        let mut fixed = Vec::with_capacity(config.keys.len() + 1);
+        if let Some(pem) = &config.pk {
+            fixed.push((None, Ed25519PublicKey::from_pem(pem)?));
+        }
+        for key in &config.keys {
+            let pk = Ed25519PublicKey::from_pem(&key.pk)
+                .with_context(|| format!("invalid public key {}", key.kid))?;
+            fixed.push((Some(key.kid.clone()), pk));
+        }
+        let mut keys = KeySet {
+            fixed,
+            ..Default::default()
+        };
+        if let Some(path) = &config.jwks {
+            keys.jwks = load_jwks(path)?;
+            keys.jwks_modified = modified(path);
+        }
+        if keys.fixed.is_empty() && keys.jwks.is_empty() {
+            bail!("no key to verify tokens with");
+        }
+
+        let options = VerificationOptions {
+            allowed_issuers: Some(HashSet::from_iter(config.issuers.iter().cloned())),
+            allowed_audiences: Some(HashSet::from_iter(config.audiences.iter().cloned())),
+            time_tolerance: Some(Duration::from_secs(config.leeway)),
+            max_validity: config.max_validity.map(Duration::from_secs),
+            ..Default::default()
+        };
+        let services = config
+            .service_tokens
+            .iter()
+            .map(|(name, token)| (name.clone(), token.clone()))
+            .collect();
+        Ok(Self {
+            keys: Arc::new(RwLock::new(keys)),
+            options,
+            jwks: config.jwks.clone(),
+            services: Arc::new(services),
+            scopes: None,
+        })
+    }
+
+    pub fn verify(&self, token: &str) -> Result<User, jwt_simple::Error> {
+        let metadata = Token::decode_metadata(token)?;
+        let kid = metadata.key_id();
+        let keys = self.keys.read().unwrap();
+        let mut candidates = keys
+            .fixed
+            .iter()
+            .chain(keys.jwks.iter())
+            .filter(|(id, _)| kid.is_none() || id.as_deref() == kid)
+            .peekable();
+        if candidates.peek().is_none() {
+            bail!("unknown key id {}", kid.unwrap_or_default());
+        }
+
+        let mut last_error = None;
+        for (_, key) in candidates {
+            match key.verify_token::<User>(token, Some(self.options.clone())) {
+                Ok(claims) => return Ok(claims.custom),
+                Err(e) => last_error = Some(e),
+            }
+        }
+        Err(last_error.unwrap())
+    }
+
+    /// the name of the service the token belongs to, if it is a service token
+    pub fn service(&self, token: &str) -> Option<&str> {
+        self.services
+            .iter()
+            .find(|(_, t)| constant_time_eq(t.as_bytes(), token.as_bytes()))
+            .map(|(name, _)| name.as_str())
+    }
+
+    /// reload the keys of the JWKS file whenever it changes, in the background. The keys are
+    /// kept as is while the file can't be read
+    pub fn watch_jwks(&self) {
+        let Some(path) = self.jwks.clone() else {
+            return;
+        };
+        let keys = self.keys.clone();
+        tokio::spawn(async move {
+            let mut interval =
+                tokio::time::interval(std::time::Duration::from_secs(JWKS_POLL_SECS));
+            loop {
+                interval.tick().await;
+                reload_jwks(&path, &keys);
+            }
+        });
+    }
+}
+
+impl EncodingKey {
+    pub fn load(pem: &str) -> Result<Self, jwt_simple::Error> {
+        Ok(Self {
+            key: Ed25519KeyPair::from_pem(pem)?,
+            issuer: DEFAULT_ISSUER.to_string(),
+            audience: DEFAULT_AUDIENCE.to_string(),
+        })
+    }
+
+    /// a new random key, for tests
+    pub fn generate() -> Self {
+        Self {
+            key: Ed25519KeyPair::generate(),
+            issuer: DEFAULT_ISSUER.to_string(),
+            audience: DEFAULT_AUDIENCE.to_string(),
+        }
+    }
+
+    pub fn with_key_id(mut self, kid: &str) -> Self {
+        self.key = self.key.with_key_id(kid);
+        self
+    }
+
+    pub fn with_issuer(mut self, issuer: impl Into<String>) -> Self {
+        self.issuer = issuer.into();
+        self
+    }
+
+    pub fn with_audience(mut self, audience: impl Into<String>) -> Self {
+        self.audience = audience.into();
+        self
+    }
+
+    /// the public key in PEM, as set in the auth config
+    pub fn public_key_pem(&self) -> String {
+        self.key.public_key().to_pem()
+    }
+
+    /// a token of the user valid for the given duration, it is already expired if the
+    /// duration is negative
+    pub fn sign(
+        &self,
+        user: User,
+        valid_for: chrono::Duration,
+    ) -> Result<String, jwt_simple::Error> {
+        let now = Clock::now_since_epoch().as_secs() as i64;
+        let mut claims = Claims::with_custom_claims(user, Duration::from_secs(0))
+            .with_issuer(&self.issuer)
+            .with_audience(&self.audience);
+        let expires_at = (now + valid_for.num_seconds()).max(0);
+        claims.expires_at = Some(UnixTimeStamp::from_secs(expires_at as _));
+        // an expired token was issued before it expired
+        let issued_at = UnixTimeStamp::from_secs(now.min(expires_at) as _);
+        claims.issued_at = Some(issued_at);
+        claims.invalid_before = Some(issued_at);
+        self.key.sign(claims)
+    }
+}
+
+impl ServiceToken {
+    /// sends no token if none is given
+    pub fn new(token: Option<&str>) -> Result<Self, jwt_simple::Error> {
+        let token = token
+            .map(|token| format!("Bearer {}", token).parse())
+            .transpose()
+            .context("invalid service token")?;
+        Ok(Self(token))
+    }
+}
+
+impl Interceptor for ServiceToken {
+    fn call(&mut self, mut request: tonic::Request<()>) -> Result<tonic::Request<()>, Status> {
+        if let Some(token) = &self.0 {
+            request
+                .metadata_mut()
+                .insert("authorization", token.clone());
+        }
+        Ok(request)
+    }
+}
+
+/// compare tokens without leaking where they differ through timing
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+fn reload_jwks(path: &Path, keys: &RwLock<KeySet>) {
+    let modified = modified(path);
+    if modified.is_none() || modified == keys.read().unwrap().jwks_modified {
+        return;
+    }
+    match load_jwks(path) {
+        Ok(jwks) => {
+            info!("Reloaded {} keys from {}", jwks.len(), path.display());
+            let mut keys = keys.write().unwrap();
+            keys.jwks = jwks;
+            keys.jwks_modified = modified;
+        }
+        Err(e) => warn!("Failed to reload keys from {}: {}", path.display(), e),
+    }
+}
+
+fn load_jwks(path: &Path) -> Result<Vec<(Option<String>, Ed25519PublicKey)>, jwt_simple::Error> {
+    let content =
+        fs::read_to_string(path).with_context(|| format!("failed to read {}", path.display()))?;
+    let jwks: Jwks = serde_json::from_str(&content)?;
+    let mut keys = Vec::with_capacity(jwks.keys.len());
+    for jwk in jwks.keys {
+        if jwk.kty != "OKP" || jwk.crv.as_deref() != Some("Ed25519") {
+            warn!(
+                "Skipped key {:?} of {}: not Ed25519",
+                jwk.kid,
+                path.display()
+            );
+            continue;
+        }
+        let Some(x) = jwk.x else {
+            bail!("key {:?} has no public key", jwk.kid);
+        };
+        let raw = Base64UrlSafeNoPadding::decode_to_vec(x, None)?;
+        keys.push((jwk.kid, Ed25519PublicKey::from_bytes(&raw)?));
+    }
+    Ok(keys)
+}
+
+fn modified(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+impl Interceptor for DecodingKey {
+    fn call(
+        &mut self,
+        mut request: tonic::Request<()>,
+    ) -> Result<tonic::Request<()>, tonic::Status> {
+        let token = request
+            .metadata()
+            .get("authorization")
+            .and_then(|v| v.to_str().ok());
+        let token = match token {
+            Some(bearer) => bearer
+                .strip_prefix("Bearer ")
+                .ok_or_else(|| Status::unauthenticated("invalid token format"))?,
+            None => return Err(Status::unauthenticated("missing token")),
+        };
+        if let Some(name) = self.service(token) {
+            let service = Service {
+                name: name.to_string(),
+            };
+            request.extensions_mut().insert(service);
+            return Ok(request);
+        }
+        let user = self
+            .verify(token)
+            .map_err(|e| Status::unauthenticated(e.to_string()))?;
+        // users are denied the methods without a scope
+        let scope = self
+            .scope_of(request.extensions().get())
+            .ok_or_else(|| Status::permission_denied("only services can call this method"))?;
+        user.require(scope)?;
+        request.extensions_mut().insert(user);
+        Ok(request)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(keys: &[(&str, &EncodingKey)]) -> AuthConfig {
+        AuthConfig {
+            pk: None,
+            keys: keys
+                .iter()
+                .map(|(kid, key)| AuthKey {
+                    kid: kid.to_string(),
+                    pk: key.public_key_pem(),
+                })
+                .collect(),
+            jwks: None,
+            issuers: vec![DEFAULT_ISSUER.to_string(), "admin".to_string()],
+            audiences: vec![DEFAULT_AUDIENCE.to_string()],
+            leeway: 60,
+            max_validity: None,
+            service_tokens: [("crm".to_string(), "secret".to_string())].into(),
+        }
+    }
+
+    fn key(kid: &str) -> EncodingKey {
+        EncodingKey::load(&Ed25519KeyPair::generate().to_pem())
+            .unwrap()
+            .with_key_id(kid)
+    }
+
+    fn user() -> User {
+        User {
+            id: 1,
+            ws_id: 1,
+            fullname: "Tyr Chen".to_string(),
+            email: "tchen@acme.org".to_string(),
+            password_hash: String::new(),
+            created_at: "2024-07-01T00:00:00Z".to_string(),
+            scopes: vec![scope::CAMPAIGN_PREVIEW.to_string()],
+        }
+    }
+
+    fn sign(key: &EncodingKey) -> String {
+        key.sign(user(), chrono::Duration::hours(1)).unwrap()
+    }
+
+    #[test]
+    fn verify_should_select_key_by_kid() {
+        let old = key("old");
+        let new = key("new").with_issuer("admin");
+        let dk = DecodingKey::load(&config(&[("old", &old), ("new", &new)])).unwrap();
+
+        assert_eq!(dk.verify(&sign(&old)).unwrap(), user());
+        assert_eq!(dk.verify(&sign(&new)).unwrap(), user());
+        assert!(dk.verify(&sign(&new.with_issuer("other"))).is_err());
+
+        assert!(dk.verify(&sign(&key("unknown"))).is_err());
+        // a key id must match its key
+        assert!(dk.verify(&sign(&key("new"))).is_err());
+    }
+
+    #[test]
+    fn service_token_should_be_accepted() {
+        let mut dk = DecodingKey::load(&config(&[("k1", &key("k1"))])).unwrap();
+        let request = ServiceToken::new(Some("secret"))
+            .unwrap()
+            .call(tonic::Request::new(()))
+            .unwrap();
+        let request = dk.call(request).unwrap();
+        assert_eq!(
+            request.extensions().get::<Service>().unwrap().name,
+            "crm".to_string()
+        );
+        assert!(request.extensions().get::<User>().is_none());
+
+        let mut request = tonic::Request::new(());
+        request
+            .metadata_mut()
+            .insert("authorization", "Bearer wrong".parse().unwrap());
+        assert_eq!(
+            dk.call(request).unwrap_err().code(),
+            tonic::Code::Unauthenticated
+        );
+    }
+
+    #[test]
+    fn interceptor_should_require_scope_of_method() {
+        let key = key("k1");
+        let dk = DecodingKey::load(&config(&[("k1", &key)])).unwrap();
+        let dk = DecodingKey {
+            scopes: Some(&[
+                ("Welcome", scope::CAMPAIGN_PREVIEW),
+                ("CancelCampaign", scope::CAMPAIGN_RUN),
+            ]),
+            ..dk
+        };
+        let call = |method: &str, token: &str| {
+            let mut request = tonic::Request::new(());
+            request.extensions_mut().insert(Method(method.to_string()));
+            let bearer = format!("Bearer {}", token).parse().unwrap();
+            request.metadata_mut().insert("authorization", bearer);
+            dk.clone().call(request).map(|_| ()).map_err(|e| e.code())
+        };
+
+        let token = sign(&key);
+        assert_eq!(call("Welcome", &token), Ok(()));
+        assert_eq!(
+            call("CancelCampaign", &token),
+            Err(tonic::Code::PermissionDenied)
+        );
+        // methods without a scope are denied to users
+        assert_eq!(
+            call("GetCampaign", &token),
+            Err(tonic::Code::PermissionDenied)
+        );
+        // services are trusted with every scope
+        assert_eq!(call("CancelCampaign", "secret"), Ok(()));
+        assert_eq!(call("GetCampaign", "secret"), Ok(()));
+    }
+
+    #[test]
+    fn expired_token_should_be_rejected() {
+        let key = key("k1");
+        let dk = DecodingKey::load(&config(&[("k1", &key)])).unwrap();
+        let token = key.sign(user(), chrono::Duration::minutes(-5)).unwrap();
+        assert!(dk.verify(&token).is_err());
+        // within the leeway
+        let token = key.sign(user(), chrono::Duration::seconds(-10)).unwrap();
+        assert!(dk.verify(&token).is_ok());
+    }
+
+    #[test]
+    fn jwks_should_be_reloaded_when_changed() {
+        let jwk = |kid: &str, key: &EncodingKey| {
+            let pk = Ed25519PublicKey::from_pem(&key.public_key_pem()).unwrap();
+            let x = Base64UrlSafeNoPadding::encode_to_string(pk.to_bytes()).unwrap();
+            format!(
+                r#"{{"keys":[{{"kty":"OKP","crv":"Ed25519","kid":"{}","x":"{}"}}]}}"#,
+                kid, x
+            )
+        };
+        let k1 = key("k1");
+        let path = std::env::temp_dir().join(format!("jwks-{}.json", std::process::id()));
+        fs::write(&path, jwk("k1", &k1)).unwrap();
+        let dk = DecodingKey::load(&AuthConfig {
+            jwks: Some(path.clone()),
+            ..config(&[])
+        })
+        .unwrap();
+        assert!(dk.verify(&sign(&k1)).is_ok());
+
+        let k2 = key("k2");
+        fs::write(&path, jwk("k2", &k2)).unwrap();
+        // make the change visible on file systems with a coarse mtime
+        let file = fs::File::options().write(true).open(&path).unwrap();
+        file.set_modified(SystemTime::now() + std::time::Duration::from_secs(1))
+            .unwrap();
+        reload_jwks(&path, &dk.keys);
+        assert!(dk.verify(&sign(&k2)).is_ok());
+        assert!(dk.verify(&sign(&k1)).is_err());
+        fs::remove_file(path).unwrap();
+    }
+}
@@ -0,0 +1,132 @@
+use tonic::{
+    codegen::{http, Context, Poll, Service},
+    server::NamedService,
+    service::interceptor::InterceptedService,
+    Status,
+};
+
+use crate::{DecodingKey, User};
+
+/// start campaigns that send messages, cancel them, and send messages
+pub const CAMPAIGN_RUN: &str = "campaign:run";
+/// preview campaigns in dry-run mode, and look at the campaigns, their schedules, and the users
+/// and contents they target
+pub const CAMPAIGN_PREVIEW: &str = "campaign:preview";
+
+/// the scope each method of a service needs, by method name. Users can't call the methods not
+/// listed, only services can
+pub type Scopes = &'static [(&'static str, &'static str)];
+
+/// a server whose requests are authenticated by the decoding key, which rejects the requests
+/// lacking the scope of their method
+pub type Authenticated<S> = WithMethod<InterceptedService<S, DecodingKey>>;
+
+/// the method a request calls, the interceptor can't see the uri
+#[derive(Debug, Clone, PartialEq)]
+pub struct Method(pub String);
+
+/// the scope the caller lacks
+#[derive(Debug, Clone, PartialEq)]
+pub struct MissingScope(pub &'static str);
+
+/// records the method of each request before handing it to the inner service
+#[derive(Debug, Clone)]
+pub struct WithMethod<S>(S);
+
+impl User {
+    pub fn has_scope(&self, scope: &str) -> bool {
+        self.scopes.iter().any(|s| s == scope)
+    }
+
+    pub fn require(&self, scope: &'static str) -> Result<(), MissingScope> {
+        if self.has_scope(scope) {
+            Ok(())
+        } else {
+            Err(MissingScope(scope))
+        }
+    }
+}
+
+impl DecodingKey {
+    /// authenticate the requests of the server, requiring the given scopes
+    pub fn wrap<S>(self, svc: S, scopes: Scopes) -> Authenticated<S> {
+        let dk = Self {
+            scopes: Some(scopes),
+            ..self
+        };
+        WithMethod(InterceptedService::new(svc, dk))
+    }
+
+    /// the scope the method needs, none if only services can call it
+    pub(crate) fn scope_of(&self, method: Option<&Method>) -> Option<&'static str> {
+        let method = method?;
+        self.scopes?
+            .iter()
+            .find(|(name, _)| *name == method.0)
+            .map(|(_, scope)| *scope)
+    }
+}
+
+impl From<MissingScope> for Status {
+    fn from(e: MissingScope) -> Self {
+        Status::permission_denied(format!("missing scope {}", e.0))
+    }
+}
+
+impl<S: NamedService> NamedService for WithMethod<S> {
+    const NAME: &'static str = S::NAME;
+}
+
+impl<S, B> Service<http::Request<B>> for WithMethod<S>
+where
+    S: Service<http::Request<B>>,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = S::Future;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.0.poll_ready(cx)
+    }
+
+    fn call(&mut self, mut req: http::Request<B>) -> Self::Future {
+        let method = req.uri().path().rsplit('/').next().unwrap_or_default();
+        let method = Method(method.to_string());
+        req.extensions_mut().insert(method);
+        self.0.call(req)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::future::{ready, Ready};
+
+    use super::*;
+
+    /// answers with the method recorded in the request
+    struct Echo;
+
+    impl Service<http::Request<()>> for Echo {
+        type Response = Option<Method>;
+        type Error = ();
+        type Future = Ready<Result<Self::Response, ()>>;
+
+        fn poll_ready(&mut self, _: &mut Context<'_>) -> Poll<Result<(), ()>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn call(&mut self, req: http::Request<()>) -> Self::Future {
+            ready(Ok(req.extensions().get().cloned()))
+        }
+    }
+
+    #[tokio::test]
+    async fn with_method_should_record_method() {
+        let req = http::Request::builder()
+            .uri("http://localhost/user_stats.UserStats/RowQuery")
+            .body(())
+            .unwrap();
+        let method = WithMethod(Echo).call(req).await.unwrap();
+        assert_eq!(method, Some(Method("RowQuery".to_string())));
+    }
+}
@@ -0,0 +1,188 @@
+use std::fs;
+
+use anyhow::{bail, Context, Result};
+use crm_config::{Errors, Validate};
+use serde::{Deserialize, Serialize};
+use tonic::transport::{Certificate, ClientTlsConfig, Identity, ServerTlsConfig};
+
+/// PEM given inline, or the path of a file holding it
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Pem(pub String);
+
+/// TLS of a server: its certificate and key, and the CA it trusts when connecting to the
+/// other services
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TlsConfig {
+    pub cert: Pem,
+    pub key: Pem,
+    #[serde(default)]
+    pub ca: Option<Pem>,
+    /// CA bundle of the client certificates, the clients must present one when set
+    #[serde(default)]
+    pub client_ca: Option<Pem>,
+}
+
+/// TLS of the connections to a service: the CA bundle it is verified with, the name in its
+/// certificate if not the host of its url, and the identity presented when it requires client
+/// certificates
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ClientTls {
+    pub ca: Option<Pem>,
+    pub domain: Option<String>,
+    pub cert: Option<Pem>,
+    pub key: Option<Pem>,
+}
+
+impl Pem {
+    pub fn load(&self) -> Result<String> {
+        if self.0.trim_start().starts_with("-----BEGIN") {
+            return Ok(self.0.clone());
+        }
+        fs::read_to_string(&self.0).with_context(|| format!("failed to read PEM file {}", self.0))
+    }
+}
+
+impl TlsConfig {
+    pub fn server_config(&self) -> Result<ServerTlsConfig> {
+        let identity = Identity::from_pem(self.cert.load()?, self.key.load()?);
+        let mut tls = ServerTlsConfig::new().identity(identity);
+        if let Some(ca) = &self.client_ca {
+            tls = tls.client_ca_root(Certificate::from_pem(ca.load()?));
+        }
+        Ok(tls)
+    }
+
+    /// TLS of the connections to https services, whose name is taken from their url
+    pub fn client_config(&self) -> Result<ClientTlsConfig> {
+        let mut tls = ClientTlsConfig::new();
+        if let Some(ca) = &self.ca {
+            tls = tls.ca_certificate(Certificate::from_pem(ca.load()?));
+        }
+        Ok(tls)
+    }
+}
+
+impl ClientTls {
+    pub fn client_config(&self) -> Result<ClientTlsConfig> {
+        let mut tls = ClientTlsConfig::new();
+        if let Some(ca) = &self.ca {
+            tls = tls.ca_certificate(Certificate::from_pem(ca.load()?));
+        }
+        if let Some(domain) = &self.domain {
+            tls = tls.domain_name(domain);
+        }
+        match (&self.cert, &self.key) {
+            (Some(cert), Some(key)) => {
+                tls = tls.identity(Identity::from_pem(cert.load()?, key.load()?));
+            }
+            (None, None) => {}
+            _ => bail!("client cert and key must be set together"),
+        }
+        Ok(tls)
+    }
+}
+
+impl Validate for Pem {
+    fn validate(&self, errors: &mut Errors) {
+        if let Err(e) = self.load() {
+            errors.add("", format!("{:#}", e));
+        }
+    }
+}
+
+impl Validate for TlsConfig {
+    fn validate(&self, errors: &mut Errors) {
+        errors.field("cert", &self.cert);
+        errors.field("key", &self.key);
+        errors.field("ca", &self.ca);
+        errors.field("client_ca", &self.client_ca);
+    }
+}
+
+impl Validate for ClientTls {
+    fn validate(&self, errors: &mut Errors) {
+        errors.field("ca", &self.ca);
+        errors.field("cert", &self.cert);
+        errors.field("key", &self.key);
+        if self.cert.is_some() != self.key.is_some() {
+            errors.add("", "cert and key must be set together");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::SocketAddr;
+
+    use tokio::net::TcpListener;
+    use tokio_stream::wrappers::TcpListenerStream;
+    use tonic::transport::{Channel, Server};
+    use tonic_health::{
+        pb::{health_client::HealthClient, HealthCheckRequest},
+        server::health_reporter,
+    };
+
+    use super::*;
+
+    #[test]
+    fn pem_should_be_inline_or_read_from_file() -> Result<()> {
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/../fixtures/cert.pem");
+        let pem = Pem(path.to_string()).load()?;
+        assert!(pem.starts_with("-----BEGIN CERTIFICATE-----"));
+        assert_eq!(Pem(pem.clone()).load()?, pem);
+        assert!(Pem("/not/found.pem".to_string()).load().is_err());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn server_should_require_client_certificate() -> Result<()> {
+        let tls = TlsConfig {
+            cert: fixture("server.pem"),
+            key: fixture("server.key"),
+            ca: None,
+            client_ca: Some(fixture("ca.pem")),
+        };
+        let (_, health) = health_reporter();
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let addr = listener.local_addr()?;
+        let server = Server::builder()
+            .tls_config(tls.server_config()?)?
+            .add_service(health)
+            .serve_with_incoming(TcpListenerStream::new(listener));
+        tokio::spawn(server);
+
+        let client = ClientTls {
+            ca: Some(fixture("ca.pem")),
+            domain: Some("localhost".to_string()),
+            cert: Some(fixture("crm.pem")),
+            key: Some(fixture("crm.key")),
+        };
+        check(addr, &client).await?;
+
+        let anonymous = ClientTls {
+            cert: None,
+            key: None,
+            ..client
+        };
+        assert!(check(addr, &anonymous).await.is_err());
+        Ok(())
+    }
+
+    async fn check(addr: SocketAddr, client: &ClientTls) -> Result<()> {
+        let channel = Channel::from_shared(format!("https://{}", addr))?
+            .tls_config(client.client_config()?)?
+            .connect()
+            .await?;
+        HealthClient::new(channel)
+            .check(HealthCheckRequest::default())
+            .await?;
+        Ok(())
+    }
+
+    fn fixture(name: &str) -> Pem {
+        let dir = concat!(env!("CARGO_MANIFEST_DIR"), "/../fixtures/mtls/");
+        Pem(format!("{}{}", dir, name))
+    }
+}
@@ -0,0 +1,69 @@
+use std::net::{IpAddr, Ipv6Addr, SocketAddr};
+
+use crm_auth::TlsConfig;
+use crm_config::{Errors, Validate};
+use serde::{Deserialize, Serialize};
+
+use crate::{LogConfig, MetricsConfig, TracingConfig};
+
+/// where and how a service listens, under `server` in its config. A service with fields of its
+/// own there flattens it into its own struct
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ServerConfig {
+    /// address to listen on, e.g. 0.0.0.0 in a container
+    #[serde(default = "default_host")]
+    pub host: IpAddr,
+    #[serde(deserialize_with = "crm_config::number")]
+    pub port: u16,
+    /// seconds given on shutdown to the in-flight requests, then to the work left in the
+    /// background, in all
+    #[serde(
+        default = "default_grace_timeout",
+        deserialize_with = "crm_config::number"
+    )]
+    pub grace_timeout: u64,
+    /// TLS of the server, plaintext if unset
+    pub tls: Option<TlsConfig>,
+}
+
+/// the logs, spans and metrics of a service, flattened at the top of its config
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TelemetryConfig {
+    #[serde(default)]
+    pub log: LogConfig,
+    #[serde(default)]
+    pub tracing: TracingConfig,
+    #[serde(default)]
+    pub metrics: MetricsConfig,
+}
+
+fn default_host() -> IpAddr {
+    IpAddr::V6(Ipv6Addr::LOCALHOST)
+}
+
+fn default_grace_timeout() -> u64 {
+    30
+}
+
+impl ServerConfig {
+    pub fn addr(&self) -> SocketAddr {
+        SocketAddr::new(self.host, self.port)
+    }
+}
+
+impl Validate for ServerConfig {
+    fn validate(&self, errors: &mut Errors) {
+        if self.port == 0 {
+            errors.add("port", "must not be 0");
+        }
+        errors.field("tls", &self.tls);
+    }
+}
+
+impl Validate for TelemetryConfig {
+    fn validate(&self, errors: &mut Errors) {
+        errors.field("log", &self.log);
+        errors.field("tracing", &self.tracing);
+        errors.field("metrics", &self.metrics);
+    }
+}
@@ -0,0 +1,232 @@
+use std::{future::Future, net::SocketAddr, time::Duration};
+
+use anyhow::{bail, Result};
+pub use config::{ServerConfig, TelemetryConfig};
+use crm_auth::TlsConfig;
+use crm_config::{Source, Validate};
+use futures::FutureExt;
+pub use log::LogConfig;
+use serde::de::DeserializeOwned;
+use tokio::{
+    signal::unix::{signal, SignalKind},
+    time::{sleep_until, Instant},
+};
+pub use tonic::async_trait;
+use tonic::{
+    service::{Routes, RoutesBuilder},
+    transport::Server,
+};
+use tonic_health::{
+    pb::health_server::{Health, HealthServer},
+    server::health_reporter,
+    ServingStatus,
+};
+use tonic_reflection::server::{ServerReflection, ServerReflectionServer};
+pub use trace::TracingConfig;
+
+pub use self::metrics::MetricsConfig;
+use tracing::{info, warn};
+
+mod config;
+pub mod log;
+pub mod metrics;
+pub mod trace;
+
+/// seconds between two health checks of a service
+const HEALTH_CHECK_SECS: u64 = 5;
+
+/// the config of a service, loaded by [`run`]
+pub trait Config: DeserializeOwned + Validate + Send + 'static {
+    /// where the config is read from: its file in the working directory or in /etc/config, then
+    /// the file in `{prefix}_CONFIG`, with single fields overridden by env vars like
+    /// `{prefix}_SERVER__PORT`
+    const SOURCE: Source;
+
+    /// where and how the server listens
+    fn server(&self) -> &ServerConfig;
+
+    /// the logs, spans and metrics of the service
+    fn telemetry(&self) -> &TelemetryConfig;
+
+    /// read the config from its source
+    fn load() -> Result<Self> {
+        crm_config::load(&Self::SOURCE)
+    }
+
+    /// address to listen on
+    fn addr(&self) -> SocketAddr {
+        self.server().addr()
+    }
+
+    /// time given on shutdown to the in-flight requests, then to the work left in the
+    /// background, in all
+    fn grace_timeout(&self) -> Duration {
+        Duration::from_secs(self.server().grace_timeout)
+    }
+
+    /// TLS of the server, plaintext if unset
+    fn tls(&self) -> Option<&TlsConfig> {
+        self.server().tls.as_ref()
+    }
+
+    /// how the logs are printed
+    fn log(&self) -> &LogConfig {
+        &self.telemetry().log
+    }
+
+    /// where the spans are exported
+    fn tracing(&self) -> &TracingConfig {
+        &self.telemetry().tracing
+    }
+
+    /// the Prometheus endpoint, served on the host of the server
+    fn metrics(&self) -> &MetricsConfig {
+        &self.telemetry().metrics
+    }
+}
+
+/// a grpc service served by [`run`], along with the health and reflection services
+#[async_trait]
+pub trait Service: Clone + Send + Sync + 'static {
+    type Config: Config;
+
+    /// name of the grpc service, its status is reported by the health service
+    const NAME: &'static str;
+
+    /// encoded descriptors of its protos, served by the reflection service
+    const FILE_DESCRIPTOR_SET: &'static [u8];
+
+    async fn start(config: Self::Config) -> Result<Self>;
+
+    /// add the grpc service along with its interceptors
+    fn add_to(self, routes: &mut RoutesBuilder) -> Result<()>;
+
+    /// whether the service can take requests, checked every few seconds
+    async fn is_healthy(&self) -> bool {
+        true
+    }
+
+    /// finish the work left in the background on shutdown, for at most `grace`, what the
+    /// in-flight requests left of the grace timeout
+    async fn drain(&self, _grace: Duration) {}
+}
+
+/// load the config of a service, then serve it until ctrl-c or SIGTERM
+pub async fn run<S: Service>() -> Result<()> {
+    let config = S::Config::load()?;
+    if config.metrics().port == Some(config.addr().port()) {
+        bail!("invalid config: metrics.port: must not be the port of the server");
+    }
+    let tracer = trace::init(config.tracing(), S::NAME)?;
+    log::init(config.log(), tracer)?;
+
+    let addr = config.addr();
+    let grace = config.grace_timeout();
+    if let Some(port) = config.metrics().port {
+        metrics::serve(SocketAddr::new(addr.ip(), port)).await?;
+    }
+    let rpc_metrics = metrics::RpcMetricsLayer::new(&[
+        S::FILE_DESCRIPTOR_SET,
+        tonic_health::pb::FILE_DESCRIPTOR_SET,
+        tonic_reflection::pb::v1::FILE_DESCRIPTOR_SET,
+    ])?;
+    let mut server = Server::builder()
+        .trace_fn(trace::server_span)
+        .layer(rpc_metrics);
+    if let Some(tls) = config.tls() {
+        server = server.tls_config(tls.server_config()?)?;
+    }
+    let svc = S::start(config).await?;
+    // the grace timeout runs from the signal
+    let signal = shutdown_signal()
+        .map(move |_| Instant::now() + grace)
+        .shared();
+    let routes = routes(svc.clone(), signal.clone().map(|_| ())).await?;
+    info!("{} listening on {}", S::NAME, addr);
+
+    let serve = server
+        .add_routes(routes)
+        .serve_with_shutdown(addr, signal.clone().map(|_| ()));
+    tokio::select! {
+        ret = serve => ret?,
+        _ = async { sleep_until(signal.clone().await).await } => {
+            warn!("Requests still running after {:?} were dropped", grace);
+        }
+    }
+    let deadline = signal.await;
+    svc.drain(deadline.saturating_duration_since(Instant::now()))
+        .await;
+    trace::shutdown();
+    Ok(())
+}
+
+/// the service with its health and reflection services, reported as not serving once
+/// `shutdown` resolves
+pub async fn routes<S: Service>(
+    svc: S,
+    shutdown: impl Future<Output = ()> + Send + 'static,
+) -> Result<Routes> {
+    let mut routes = RoutesBuilder::default();
+    routes
+        .add_service(health(svc.clone(), shutdown).await)
+        .add_service(reflection::<S>()?);
+    svc.add_to(&mut routes)?;
+    Ok(routes.routes())
+}
+
+/// the health service, reporting the server and the service as serving while it is healthy,
+/// and as not serving from `shutdown` on, so that no new requests are routed to it while it
+/// drains
+pub async fn health<S: Service>(
+    svc: S,
+    shutdown: impl Future<Output = ()> + Send + 'static,
+) -> HealthServer<impl Health> {
+    let (mut reporter, server) = health_reporter();
+    for service in ["", S::NAME] {
+        reporter
+            .set_service_status(service, ServingStatus::NotServing)
+            .await;
+    }
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(Duration::from_secs(HEALTH_CHECK_SECS));
+        tokio::pin!(shutdown);
+        loop {
+            let status = tokio::select! {
+                biased;
+                _ = &mut shutdown => break,
+                _ = interval.tick() => match svc.is_healthy().await {
+                    true => ServingStatus::Serving,
+                    false => ServingStatus::NotServing,
+                },
+            };
+            for service in ["", S::NAME] {
+                reporter.set_service_status(service, status).await;
+            }
+        }
+        for service in ["", S::NAME] {
+            reporter
+                .set_service_status(service, ServingStatus::NotServing)
+                .await;
+        }
+    });
+    server
+}
+
+/// the reflection service, so that the service can be called without its protos
+pub fn reflection<S: Service>() -> Result<ServerReflectionServer<impl ServerReflection>> {
+    let server = tonic_reflection::server::Builder::configure()
+        .register_encoded_file_descriptor_set(S::FILE_DESCRIPTOR_SET)
+        .register_encoded_file_descriptor_set(tonic_health::pb::FILE_DESCRIPTOR_SET)
+        .build_v1()?;
+    Ok(server)
+}
+
+/// resolves on ctrl-c or SIGTERM
+async fn shutdown_signal() {
+    let mut terminate = signal(SignalKind::terminate()).expect("Failed to listen for SIGTERM");
+    tokio::select! {
+        _ = tokio::signal::ctrl_c() => {}
+        _ = terminate.recv() => {}
+    }
+    info!("Shutting down");
+}
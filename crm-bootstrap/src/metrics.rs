@@ -0,0 +1,343 @@
+use std::{
+    collections::{HashMap, HashSet},
+    net::SocketAddr,
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll},
+    time::Instant,
+};
+
+use ::metrics::{counter, histogram};
+use anyhow::Result;
+use axum::{routing::get, Router};
+use crm_config::{Errors, Validate};
+use futures::future::BoxFuture;
+use http_body::{Body, Frame, SizeHint};
+use metrics_exporter_prometheus::{Matcher, PrometheusBuilder};
+use prost::Message;
+use prost_types::FileDescriptorSet;
+use serde::{Deserialize, Serialize};
+use tokio::net::TcpListener;
+use tonic::{codegen::http, Code};
+use tower::{Layer, Service};
+use tracing::{info, warn};
+
+/// buckets of the durations, in seconds
+const DURATION_BUCKETS: &[f64] = &[
+    0.001, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0, 30.0,
+];
+/// buckets of the counts, e.g. of the users matched by a campaign
+const COUNT_BUCKETS: &[f64] = &[
+    0.0,
+    1.0,
+    10.0,
+    100.0,
+    1_000.0,
+    10_000.0,
+    100_000.0,
+    1_000_000.0,
+];
+
+/// the Prometheus endpoint, at `http://{server.host}:{port}/metrics`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct MetricsConfig {
+    /// no endpoint if unset
+    #[serde(deserialize_with = "crm_config::optional_number")]
+    pub port: Option<u16>,
+}
+
+/// label of the services and methods the server doesn't serve
+const UNKNOWN: &str = "unknown";
+
+/// counts the requests to the server by method and grpc status, and times them until the end of
+/// their response. The requests to the services and methods not served are labeled `unknown`, any
+/// client could add series otherwise
+#[derive(Debug, Clone)]
+pub struct RpcMetricsLayer {
+    /// the methods served, by service
+    methods: Arc<HashMap<String, HashSet<String>>>,
+}
+
+#[derive(Debug, Clone)]
+pub struct RpcMetrics<S> {
+    inner: S,
+    methods: Arc<HashMap<String, HashSet<String>>>,
+}
+
+/// a response body recording its request once its grpc status is known
+pub struct MetricsBody<B> {
+    inner: B,
+    rpc: Option<Rpc>,
+}
+
+#[derive(Debug)]
+struct Rpc {
+    service: String,
+    method: String,
+    start: Instant,
+}
+
+impl Validate for MetricsConfig {
+    fn validate(&self, errors: &mut Errors) {
+        if self.port == Some(0) {
+            errors.add("port", "must not be 0");
+        }
+    }
+}
+
+/// install the Prometheus recorder and serve its metrics at /metrics
+pub(crate) async fn serve(addr: SocketAddr) -> Result<()> {
+    let handle = PrometheusBuilder::new()
+        .set_buckets_for_metric(Matcher::Suffix("_seconds".to_string()), DURATION_BUCKETS)?
+        .set_buckets(COUNT_BUCKETS)?
+        .install_recorder()?;
+    let app = Router::new().route("/metrics", get(move || async move { handle.render() }));
+    let listener = TcpListener::bind(addr).await?;
+    info!("Metrics at http://{}/metrics", addr);
+    tokio::spawn(async move {
+        if let Err(e) = axum::serve(listener, app).await {
+            warn!("Metrics endpoint failed: {}", e);
+        }
+    });
+    Ok(())
+}
+
+impl RpcMetricsLayer {
+    /// a layer labeling the methods of the services in the encoded descriptor sets
+    pub fn new(descriptors: &[&[u8]]) -> Result<Self> {
+        let mut methods: HashMap<String, HashSet<String>> = HashMap::new();
+        for descriptor in descriptors {
+            for file in FileDescriptorSet::decode(*descriptor)?.file {
+                for service in &file.service {
+                    let name = match file.package() {
+                        "" => service.name().to_string(),
+                        package => format!("{}.{}", package, service.name()),
+                    };
+                    methods
+                        .entry(name)
+                        .or_default()
+                        .extend(service.method.iter().map(|m| m.name().to_string()));
+                }
+            }
+        }
+        Ok(Self {
+            methods: Arc::new(methods),
+        })
+    }
+}
+
+impl<S> Layer<S> for RpcMetricsLayer {
+    type Service = RpcMetrics<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        RpcMetrics {
+            inner,
+            methods: self.methods.clone(),
+        }
+    }
+}
+
+impl<S> RpcMetrics<S> {
+    /// the service and the method of a request path, `unknown` if they aren't served
+    fn labels(&self, path: &str) -> (String, String) {
+        let path = path.trim_start_matches('/');
+        let (service, method) = path.split_once('/').unwrap_or((path, ""));
+        match self.methods.get(service) {
+            Some(methods) if methods.contains(method) => (service.to_string(), method.to_string()),
+            Some(_) => (service.to_string(), UNKNOWN.to_string()),
+            None => (UNKNOWN.to_string(), UNKNOWN.to_string()),
+        }
+    }
+}
+
+impl<S, ReqBody, ResBody> Service<http::Request<ReqBody>> for RpcMetrics<S>
+where
+    S: Service<http::Request<ReqBody>, Response = http::Response<ResBody>>,
+    S::Future: Send + 'static,
+{
+    type Response = http::Response<MetricsBody<ResBody>>;
+    type Error = S::Error;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: http::Request<ReqBody>) -> Self::Future {
+        let (service, method) = self.labels(req.uri().path());
+        let rpc = Rpc {
+            service,
+            method,
+            start: Instant::now(),
+        };
+        let fut = self.inner.call(req);
+        Box::pin(async move {
+            let res = fut.await?;
+            // errors returned before any message only have headers
+            let rpc = match grpc_code(res.headers()) {
+                Some(code) => {
+                    rpc.record(code);
+                    None
+                }
+                None => Some(rpc),
+            };
+            Ok(res.map(|inner| MetricsBody { inner, rpc }))
+        })
+    }
+}
+
+impl<B: Body + Unpin> Body for MetricsBody<B> {
+    type Data = B::Data;
+    type Error = B::Error;
+
+    fn poll_frame(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+        let ret = Pin::new(&mut self.inner).poll_frame(cx);
+        let code = match &ret {
+            Poll::Ready(Some(Ok(frame))) => frame
+                .trailers_ref()
+                .map(|trailers| grpc_code(trailers).unwrap_or(Code::Unknown)),
+            Poll::Ready(Some(Err(_))) | Poll::Ready(None) => Some(Code::Unknown),
+            Poll::Pending => None,
+        };
+        if let Some(code) = code {
+            if let Some(rpc) = self.rpc.take() {
+                rpc.record(code);
+            }
+        }
+        ret
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.inner.is_end_stream()
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        self.inner.size_hint()
+    }
+}
+
+impl<B> Drop for MetricsBody<B> {
+    /// the client went away before the end of the response
+    fn drop(&mut self) {
+        if let Some(rpc) = self.rpc.take() {
+            rpc.record(Code::Cancelled);
+        }
+    }
+}
+
+impl Rpc {
+    fn record(self, code: Code) {
+        let code = format!("{:?}", code);
+        counter!(
+            "rpc_requests_total",
+            "service" => self.service.clone(),
+            "method" => self.method.clone(),
+            "code" => code,
+        )
+        .increment(1);
+        histogram!(
+            "rpc_request_duration_seconds",
+            "service" => self.service,
+            "method" => self.method,
+        )
+        .record(self.start.elapsed().as_secs_f64());
+    }
+}
+
+fn grpc_code(headers: &http::HeaderMap) -> Option<Code> {
+    headers
+        .get("grpc-status")
+        .map(|status| Code::from_bytes(status.as_bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+    use ::metrics::with_local_recorder;
+    use http_body_util::{BodyExt, Empty, Full};
+    use metrics_util::debugging::{DebugValue, DebuggingRecorder};
+    use tonic::codegen::Bytes;
+
+    use super::*;
+
+    #[test]
+    fn rpc_should_be_counted_by_grpc_status() {
+        let recorder = DebuggingRecorder::new();
+        let snapshotter = recorder.snapshotter();
+        let ok = tower::service_fn(|_: http::Request<()>| async {
+            let body = Full::new(Bytes::from_static(b"message"))
+                .with_trailers(async {
+                    let mut trailers = http::HeaderMap::new();
+                    trailers.insert("grpc-status", "0".parse().unwrap());
+                    Some(Ok(trailers))
+                })
+                .boxed_unsync();
+            Ok::<_, std::convert::Infallible>(http::Response::new(body))
+        });
+        let not_found = tower::service_fn(|_: http::Request<()>| async {
+            let body = Empty::<Bytes>::new().boxed_unsync();
+            let mut res = http::Response::new(body);
+            res.headers_mut()
+                .insert("grpc-status", "5".parse().unwrap());
+            Ok::<_, std::convert::Infallible>(res)
+        });
+        let request = || {
+            http::Request::builder()
+                .uri("/grpc.health.v1.Health/Check")
+                .body(())
+                .unwrap()
+        };
+        let layer = health_layer();
+
+        with_local_recorder(&recorder, || {
+            futures::executor::block_on(async {
+                let mut svc = layer.layer(ok);
+                let res = svc.call(request()).await.unwrap();
+                res.into_body().collect().await.unwrap();
+                let mut svc = layer.layer(not_found);
+                let res = svc.call(request()).await.unwrap();
+                drop(res);
+            })
+        });
+
+        let counts: Vec<_> = snapshotter
+            .snapshot()
+            .into_vec()
+            .into_iter()
+            .filter_map(|(key, _, _, value)| match value {
+                DebugValue::Counter(n) => {
+                    let code = key.key().labels().find(|l| l.key() == "code")?;
+                    Some((code.value().to_string(), n))
+                }
+                _ => None,
+            })
+            .collect();
+        assert_eq!(counts.len(), 2);
+        assert!(counts.contains(&("Ok".to_string(), 1)));
+        assert!(counts.contains(&("NotFound".to_string(), 1)));
+    }
+
+    #[test]
+    fn routes_not_served_should_be_unknown() {
+        let svc = health_layer().layer(());
+        let labels = |service: &str, method: &str| (service.to_string(), method.to_string());
+        assert_eq!(
+            svc.labels("/grpc.health.v1.Health/Watch"),
+            labels("grpc.health.v1.Health", "Watch")
+        );
+        assert_eq!(
+            svc.labels("/grpc.health.v1.Health/Drop"),
+            labels("grpc.health.v1.Health", "unknown")
+        );
+        for path in ["/crm.Crm/Welcome", "/random-1234/x", "/", ""] {
+            assert_eq!(svc.labels(path), labels("unknown", "unknown"), "{}", path);
+        }
+    }
+
+    fn health_layer() -> RpcMetricsLayer {
+        RpcMetricsLayer::new(&[tonic_health::pb::FILE_DESCRIPTOR_SET]).unwrap()
+    }
+}
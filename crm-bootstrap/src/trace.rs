@@ -0,0 +1,252 @@
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use anyhow::Result;
+use crm_config::{Errors, Validate};
+use futures::Stream;
+use opentelemetry::{
+    global,
+    propagation::{Extractor, Injector},
+    trace::{TraceContextExt, TracerProvider as _},
+    KeyValue,
+};
+use opentelemetry_otlp::WithExportConfig;
+use opentelemetry_sdk::{
+    propagation::TraceContextPropagator,
+    runtime,
+    trace::{self, Tracer, TracerProvider},
+    Resource,
+};
+use serde::{Deserialize, Serialize};
+use tonic::{codegen::http, transport::Endpoint};
+use tracing::{field, info_span, Span};
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+
+/// where the spans of a service are exported. The trace context of each request is propagated
+/// to the services it calls whether they are exported or not, its trace id is the id of the
+/// request in the logs
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct TracingConfig {
+    pub exporter: Exporter,
+    /// grpc endpoint of the OTLP collector
+    pub endpoint: String,
+    /// name of the service in the traces, its grpc service name if unset
+    pub service_name: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Exporter {
+    #[default]
+    None,
+    /// print the spans, for local runs
+    Stdout,
+    Otlp,
+}
+
+/// a span for each item of a stream, lasting from the first poll for the item until it is
+/// produced
+pub struct ItemSpans<S> {
+    stream: S,
+    parent: Span,
+    name: &'static str,
+    index: usize,
+    span: Option<Span>,
+}
+
+impl Default for TracingConfig {
+    fn default() -> Self {
+        Self {
+            exporter: Exporter::None,
+            endpoint: "http://localhost:4317".to_string(),
+            service_name: None,
+        }
+    }
+}
+
+impl Validate for TracingConfig {
+    fn validate(&self, errors: &mut Errors) {
+        if self.exporter == Exporter::Otlp {
+            if let Err(e) = Endpoint::from_shared(self.endpoint.clone()) {
+                errors.add("endpoint", e);
+            }
+        }
+    }
+}
+
+/// install the trace context propagator, and the tracer of the exporter. Without an exporter the
+/// spans are still traced, for their trace ids
+pub(crate) fn init(config: &TracingConfig, name: &str) -> Result<Tracer> {
+    global::set_text_map_propagator(TraceContextPropagator::new());
+    let name = config.service_name.as_deref().unwrap_or(name).to_string();
+    let trace_config = trace::Config::default()
+        .with_resource(Resource::new([KeyValue::new("service.name", name.clone())]));
+    let provider = match config.exporter {
+        Exporter::None => TracerProvider::builder().with_config(trace_config).build(),
+        Exporter::Stdout => TracerProvider::builder()
+            .with_config(trace_config)
+            .with_simple_exporter(opentelemetry_stdout::SpanExporter::default())
+            .build(),
+        Exporter::Otlp => opentelemetry_otlp::new_pipeline()
+            .tracing()
+            .with_exporter(
+                opentelemetry_otlp::new_exporter()
+                    .tonic()
+                    .with_endpoint(&config.endpoint),
+            )
+            .with_trace_config(trace_config)
+            .install_batch(runtime::Tokio)?,
+    };
+    global::set_tracer_provider(provider.clone());
+    Ok(provider.tracer(name))
+}
+
+/// export the spans left before exiting
+pub(crate) fn shutdown() {
+    global::shutdown_tracer_provider();
+}
+
+/// the span of a request to the server, continuing the trace of the caller. Its trace id is
+/// recorded as `request_id`, in every log of the request. Health checks are not traced
+pub fn server_span(req: &http::Request<()>) -> Span {
+    let path = req.uri().path();
+    let Some((service, method)) = path.trim_start_matches('/').split_once('/') else {
+        return Span::none();
+    };
+    if service == tonic_health::pb::health_server::SERVICE_NAME {
+        return Span::none();
+    }
+    let span = info_span!(
+        "rpc",
+        otel.name = path,
+        otel.kind = "server",
+        rpc.system = "grpc",
+        rpc.service = service,
+        rpc.method = method,
+        request_id = field::Empty,
+    );
+    let parent = global::get_text_map_propagator(|p| p.extract(&HeaderExtractor(req.headers())));
+    span.set_parent(parent);
+    let trace_id = span.context().span().span_context().trace_id();
+    span.record("request_id", field::display(trace_id));
+    span
+}
+
+/// the span of a request to another service, a child of the current span. Its trace context
+/// is added to the headers of the request
+pub fn client_span<B>(req: &mut http::Request<B>) -> Span {
+    let path = req.uri().path();
+    let (service, method) = path
+        .trim_start_matches('/')
+        .split_once('/')
+        .unwrap_or_default();
+    let span = info_span!(
+        "rpc",
+        otel.name = path,
+        otel.kind = "client",
+        rpc.system = "grpc",
+        rpc.service = service,
+        rpc.method = method,
+    );
+    let cx = span.context();
+    global::get_text_map_propagator(|p| {
+        p.inject_context(&cx, &mut HeaderInjector(req.headers_mut()))
+    });
+    span
+}
+
+/// a span for each item of `stream`, as children of the current span
+pub fn item_spans<S: Stream + Unpin>(stream: S, name: &'static str) -> ItemSpans<S> {
+    ItemSpans {
+        stream,
+        parent: Span::current(),
+        name,
+        index: 0,
+        span: None,
+    }
+}
+
+impl<S: Stream + Unpin> Stream for ItemSpans<S> {
+    type Item = S::Item;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<S::Item>> {
+        let this = &mut *self;
+        let span = this.span.get_or_insert_with(
+            || info_span!(parent: &this.parent, "item", otel.name = this.name, index = this.index),
+        );
+        let ret = span.in_scope(|| Pin::new(&mut this.stream).poll_next(cx));
+        if let Poll::Ready(item) = &ret {
+            // the span of the end of the stream is dropped along with the stream
+            if item.is_some() {
+                this.span = None;
+                this.index += 1;
+            }
+        }
+        ret
+    }
+}
+
+struct HeaderExtractor<'a>(&'a http::HeaderMap);
+
+struct HeaderInjector<'a>(&'a mut http::HeaderMap);
+
+impl Extractor for HeaderExtractor<'_> {
+    fn get(&self, key: &str) -> Option<&str> {
+        self.0.get(key).and_then(|v| v.to_str().ok())
+    }
+
+    fn keys(&self) -> Vec<&str> {
+        self.0.keys().map(|k| k.as_str()).collect()
+    }
+}
+
+impl Injector for HeaderInjector<'_> {
+    fn set(&mut self, key: &str, value: String) {
+        let Ok(name) = http::header::HeaderName::from_bytes(key.as_bytes()) else {
+            return;
+        };
+        if let Ok(value) = http::HeaderValue::from_str(&value) {
+            self.0.insert(name, value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::StreamExt;
+    use tracing_subscriber::{layer::SubscriberExt, Registry};
+
+    use super::*;
+
+    #[test]
+    fn trace_context_should_pass_from_client_to_server() {
+        global::set_text_map_propagator(TraceContextPropagator::new());
+        let provider = TracerProvider::builder().build();
+        let layer = tracing_opentelemetry::layer().with_tracer(provider.tracer("test"));
+        let subscriber = Registry::default().with(layer);
+        tracing::subscriber::with_default(subscriber, || {
+            let mut req = http::Request::builder()
+                .uri("http://[::1]:50001/user_stats.UserStats/Query")
+                .body(())
+                .unwrap();
+            let client = client_span(&mut req);
+            let trace_id = client.context().span().span_context().trace_id();
+            let header = req.headers()["traceparent"].to_str().unwrap();
+            assert!(header.contains(&trace_id.to_string()), "{}", header);
+
+            let server = server_span(&req);
+            assert_eq!(server.context().span().span_context().trace_id(), trace_id);
+        });
+    }
+
+    #[tokio::test]
+    async fn item_spans_should_not_change_the_stream() {
+        let items: Vec<_> = item_spans(futures::stream::iter([1, 2, 3]), "item")
+            .collect()
+            .await;
+        assert_eq!(items, [1, 2, 3]);
+    }
+}
@@ -0,0 +1,171 @@
+use std::{fmt::Display, str::FromStr};
+
+use serde::{
+    de::{
+        value::{MapDeserializer, SeqDeserializer},
+        Error as _, IntoDeserializer, Unexpected, Visitor,
+    },
+    forward_to_deserialize_any, Deserialize, Deserializer,
+};
+use serde_yaml::{Error, Value};
+
+/// a config value whose strings also deserialize as the numbers and bools they spell, the env
+/// overrides are kept as strings so a string field gets them as they are
+pub(crate) struct Lenient(pub Value);
+
+/// parse a string into the type the field wants, anything else deserializes as usual
+macro_rules! parse {
+    ($($method:ident => $visit:ident,)*) => {$(
+        fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+            match self.0 {
+                Value::String(s) => match s.parse() {
+                    Ok(v) => visitor.$visit(v),
+                    Err(_) => Err(Error::invalid_type(Unexpected::Str(&s), &visitor)),
+                },
+                value => value.$method(visitor),
+            }
+        }
+    )*};
+}
+
+impl<'de> Deserializer<'de> for Lenient {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        match self.0 {
+            Value::Mapping(map) => {
+                let mut map =
+                    MapDeserializer::new(map.into_iter().map(|(k, v)| (Lenient(k), Lenient(v))));
+                let value = visitor.visit_map(&mut map)?;
+                map.end()?;
+                Ok(value)
+            }
+            Value::Sequence(seq) => {
+                let mut seq = SeqDeserializer::new(seq.into_iter().map(Lenient));
+                let value = visitor.visit_seq(&mut seq)?;
+                seq.end()?;
+                Ok(value)
+            }
+            value => value.deserialize_any(visitor),
+        }
+    }
+
+    parse! {
+        deserialize_bool => visit_bool,
+        deserialize_i8 => visit_i8,
+        deserialize_i16 => visit_i16,
+        deserialize_i32 => visit_i32,
+        deserialize_i64 => visit_i64,
+        deserialize_u8 => visit_u8,
+        deserialize_u16 => visit_u16,
+        deserialize_u32 => visit_u32,
+        deserialize_u64 => visit_u64,
+        deserialize_f32 => visit_f32,
+        deserialize_f64 => visit_f64,
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        match self.0 {
+            Value::Null => visitor.visit_none(),
+            value => visitor.visit_some(Lenient(value)),
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        self.0.deserialize_enum(name, variants, visitor)
+    }
+
+    fn deserialize_char<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        self.0.deserialize_char(visitor)
+    }
+
+    fn deserialize_str<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        self.0.deserialize_str(visitor)
+    }
+
+    fn deserialize_string<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        self.0.deserialize_string(visitor)
+    }
+
+    fn deserialize_identifier<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        self.0.deserialize_identifier(visitor)
+    }
+
+    fn deserialize_unit<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        self.0.deserialize_unit(visitor)
+    }
+
+    fn deserialize_unit_struct<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        self.0.deserialize_unit_struct(name, visitor)
+    }
+
+    forward_to_deserialize_any! {
+        i128 u128 bytes byte_buf seq tuple tuple_struct map struct ignored_any
+    }
+}
+
+impl<'de> IntoDeserializer<'de, Error> for Lenient {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self {
+        self
+    }
+}
+
+/// a number, or a string spelling it
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Number<T> {
+    Number(T),
+    String(String),
+}
+
+/// deserialize a number of a `#[serde(flatten)]` struct, whose fields don't get the env overrides
+/// converted as they are buffered first
+pub fn number<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de> + FromStr,
+    T::Err: Display,
+{
+    match Number::deserialize(deserializer)? {
+        Number::Number(n) => Ok(n),
+        Number::String(s) => s
+            .parse()
+            .map_err(|e| D::Error::custom(format!("invalid number {:?}: {}", s, e))),
+    }
+}
+
+/// [`number`] of an optional field
+pub fn optional_number<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de> + FromStr,
+    T::Err: Display,
+{
+    match Option::<Number<T>>::deserialize(deserializer)? {
+        None => Ok(None),
+        Some(Number::Number(n)) => Ok(Some(n)),
+        Some(Number::String(s)) => s
+            .parse()
+            .map(Some)
+            .map_err(|e| D::Error::custom(format!("invalid number {:?}: {}", s, e))),
+    }
+}
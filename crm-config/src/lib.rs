@@ -0,0 +1,443 @@
+use std::{env, fmt, fs, path::Path};
+
+use anyhow::{anyhow, bail, Context, Result};
+use serde::de::DeserializeOwned;
+use serde_yaml::{Mapping, Value};
+
+use lenient::Lenient;
+pub use lenient::{number, optional_number};
+
+mod lenient;
+
+/// where the config of a service is looked up. The file is read from the working directory or
+/// from /etc/config, then from the path in `{prefix}_CONFIG`, and each `{prefix}_A__B` env var
+/// overrides the field `a.b`, with the number or bool it spells when the field isn't a string
+#[derive(Debug, Clone, Copy)]
+pub struct Source {
+    pub file: &'static str,
+    pub prefix: &'static str,
+}
+
+/// checks a config beyond what deserializing it does, reporting each invalid field by its path
+pub trait Validate {
+    fn validate(&self, errors: &mut Errors);
+}
+
+/// invalid fields found while validating a config
+#[derive(Debug, Default)]
+pub struct Errors {
+    path: Vec<String>,
+    errors: Vec<(String, String)>,
+}
+
+/// load the config of `source`, each layer overriding the fields of the previous ones:
+/// defaults of `T`, the config file, the file in `{prefix}_CONFIG`, then the env overrides
+pub fn load<T: DeserializeOwned + Validate>(source: &Source) -> Result<T> {
+    load_from(source, env::vars())
+}
+
+/// [`load`] with the env vars given, e.g. those of the process along with a few of the tests
+pub fn load_from<T: DeserializeOwned + Validate>(
+    source: &Source,
+    vars: impl IntoIterator<Item = (String, String)>,
+) -> Result<T> {
+    let vars: Vec<_> = vars.into_iter().collect();
+    let mut value = Value::Mapping(Mapping::new());
+    let mut found = false;
+
+    let local = Path::new(source.file);
+    let etc = Path::new("/etc/config").join(source.file);
+    if let Some(path) = [local, &etc].into_iter().find(|p| p.exists()) {
+        merge(&mut value, read(path)?);
+        found = true;
+    }
+    let var = format!("{}_CONFIG", source.prefix);
+    if let Some((_, path)) = vars.iter().find(|(k, _)| *k == var) {
+        let layer = read(Path::new(path)).with_context(|| format!("{} is invalid", var))?;
+        merge(&mut value, layer);
+        found = true;
+    }
+    for (key, v) in &vars {
+        if let Some(path) = override_path(source.prefix, key) {
+            set(&mut value, &path, Value::String(v.clone()));
+        }
+    }
+
+    let config: Result<T> = serde_path_to_error::deserialize(Lenient(value)).map_err(|e| {
+        let path = e.path().to_string();
+        let e = e.into_inner();
+        match path.as_str() {
+            "." => anyhow!("invalid config: {}", e),
+            _ => anyhow!("invalid config: {}: {}", path, e),
+        }
+    });
+    let config = match config {
+        Err(e) if !found => {
+            return Err(e.context(format!(
+                "no config found in ./{}, /etc/config/{} or {}",
+                source.file, source.file, var
+            )))
+        }
+        config => config?,
+    };
+
+    let mut errors = Errors::default();
+    config.validate(&mut errors);
+    errors.into_result()?;
+    Ok(config)
+}
+
+fn read(path: &Path) -> Result<Value> {
+    let content =
+        fs::read_to_string(path).with_context(|| format!("failed to read {}", path.display()))?;
+    serde_yaml::from_str(&content).with_context(|| format!("failed to parse {}", path.display()))
+}
+
+/// the field path of an override, `CRM_SERVER__PORT` is `server.port`. The `{prefix}_CONFIG`
+/// var and vars without any `__` are not overrides
+fn override_path(prefix: &str, key: &str) -> Option<Vec<String>> {
+    let rest = key.strip_prefix(prefix)?.strip_prefix('_')?;
+    if !rest.contains("__") {
+        return None;
+    }
+    let path: Vec<_> = rest.split("__").map(|s| s.to_lowercase()).collect();
+    path.iter().all(|s| !s.is_empty()).then_some(path)
+}
+
+/// merge `layer` into `base`, mappings field by field and anything else by replacing it
+fn merge(base: &mut Value, layer: Value) {
+    match (base, layer) {
+        (Value::Mapping(base), Value::Mapping(layer)) => {
+            for (k, v) in layer {
+                match base.get_mut(&k) {
+                    Some(old) => merge(old, v),
+                    None => {
+                        base.insert(k, v);
+                    }
+                }
+            }
+        }
+        // an empty file
+        (_, Value::Null) => {}
+        (base, layer) => *base = layer,
+    }
+}
+
+fn set(value: &mut Value, path: &[String], v: Value) {
+    let Some((key, rest)) = path.split_first() else {
+        *value = v;
+        return;
+    };
+    if !value.is_mapping() {
+        *value = Value::Mapping(Mapping::new());
+    }
+    let map = value.as_mapping_mut().unwrap();
+    let field = map.entry(Value::String(key.clone())).or_insert(Value::Null);
+    set(field, rest, v);
+}
+
+impl Errors {
+    /// report the field `name` of the value being validated as invalid
+    pub fn add(&mut self, name: &str, msg: impl fmt::Display) {
+        let mut path = self.path.clone();
+        if !name.is_empty() {
+            path.push(name.to_string());
+        }
+        self.errors.push((join(&path), msg.to_string()));
+    }
+
+    /// validate the field `name`
+    pub fn field(&mut self, name: &str, value: &impl Validate) {
+        self.path.push(name.to_string());
+        value.validate(self);
+        self.path.pop();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    fn into_result(self) -> Result<()> {
+        if self.errors.is_empty() {
+            return Ok(());
+        }
+        let errors: Vec<_> = self
+            .errors
+            .iter()
+            .map(|(path, msg)| match path.as_str() {
+                "" => format!("  {}", msg),
+                path => format!("  {}: {}", path, msg),
+            })
+            .collect();
+        bail!("invalid config:\n{}", errors.join("\n"))
+    }
+}
+
+/// `a.b[0].c` from `["a", "b", "[0]", "c"]`
+fn join(path: &[String]) -> String {
+    let mut s = String::new();
+    for part in path {
+        if !s.is_empty() && !part.starts_with('[') {
+            s.push('.');
+        }
+        s.push_str(part);
+    }
+    s
+}
+
+impl<T: Validate> Validate for Option<T> {
+    fn validate(&self, errors: &mut Errors) {
+        if let Some(v) = self {
+            v.validate(errors);
+        }
+    }
+}
+
+impl<T: Validate> Validate for Vec<T> {
+    fn validate(&self, errors: &mut Errors) {
+        for (i, v) in self.iter().enumerate() {
+            errors.field(&format!("[{}]", i), v);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use serde::Deserialize;
+    use tempfile::NamedTempFile;
+
+    use super::*;
+
+    #[derive(Debug, Deserialize)]
+    struct Config {
+        server: Server,
+        #[serde(default)]
+        backends: Vec<Backend>,
+    }
+
+    #[derive(Debug, Deserialize)]
+    struct Server {
+        port: u16,
+        #[serde(default = "default_host")]
+        host: String,
+        #[serde(default)]
+        token: Option<String>,
+        #[serde(default)]
+        tls: Option<bool>,
+    }
+
+    #[derive(Debug, Deserialize)]
+    struct Backend {
+        url: String,
+    }
+
+    fn default_host() -> String {
+        "::1".to_string()
+    }
+
+    impl Validate for Config {
+        fn validate(&self, errors: &mut Errors) {
+            errors.field("server", &self.server);
+            errors.field("backends", &self.backends);
+        }
+    }
+
+    impl Validate for Server {
+        fn validate(&self, errors: &mut Errors) {
+            if self.port == 0 {
+                errors.add("port", "must not be 0");
+            }
+        }
+    }
+
+    impl Validate for Backend {
+        fn validate(&self, errors: &mut Errors) {
+            if !self.url.starts_with("http") {
+                errors.add("url", "must be an http or https url");
+            }
+        }
+    }
+
+    const SOURCE: Source = Source {
+        file: "not-found.yml",
+        prefix: "TEST",
+    };
+
+    /// a config file, deleted once dropped
+    fn file(content: &str) -> NamedTempFile {
+        let mut file = tempfile::Builder::new().suffix(".yml").tempfile().unwrap();
+        file.write_all(content.as_bytes()).unwrap();
+        file
+    }
+
+    fn path(file: &NamedTempFile) -> &str {
+        file.path().to_str().unwrap()
+    }
+
+    fn vars(vars: &[(&str, &str)]) -> Vec<(String, String)> {
+        vars.iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn env_should_override_file_and_defaults() -> Result<()> {
+        let yml = file("server:\n  port: 50000\n  host: 0.0.0.0\n");
+        let path = path(&yml);
+        let config: Config = load_from(&SOURCE, vars(&[("TEST_CONFIG", path)]))?;
+        assert_eq!(config.server.port, 50000);
+        assert_eq!(config.server.host, "0.0.0.0");
+
+        let config: Config = load_from(
+            &SOURCE,
+            vars(&[
+                ("TEST_CONFIG", path),
+                ("TEST_SERVER__PORT", "50001"),
+                ("TEST_PORT", "1"),
+                ("OTHER_SERVER__PORT", "2"),
+            ]),
+        )?;
+        assert_eq!(config.server.port, 50001);
+        assert_eq!(config.server.host, "0.0.0.0");
+
+        let config: Config = load_from(&SOURCE, vars(&[("TEST_SERVER__PORT", "50002")]))?;
+        assert_eq!(config.server.port, 50002);
+        assert_eq!(config.server.host, "::1");
+        Ok(())
+    }
+
+    #[test]
+    fn env_should_override_strings_as_they_are() -> Result<()> {
+        let config: Config = load_from(
+            &SOURCE,
+            vars(&[
+                ("TEST_SERVER__PORT", "50000"),
+                ("TEST_SERVER__HOST", "123456"),
+                ("TEST_SERVER__TOKEN", "~"),
+                ("TEST_SERVER__TLS", "true"),
+            ]),
+        )?;
+        assert_eq!(config.server.port, 50000);
+        assert_eq!(config.server.host, "123456");
+        assert_eq!(config.server.token.as_deref(), Some("~"));
+        assert_eq!(config.server.tls, Some(true));
+
+        for token in ["null", "[secret", "{secret}", "- secret"] {
+            let config: Config = load_from(
+                &SOURCE,
+                vars(&[
+                    ("TEST_SERVER__PORT", "50000"),
+                    ("TEST_SERVER__TOKEN", token),
+                ]),
+            )?;
+            assert_eq!(config.server.token.as_deref(), Some(token));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn number_should_parse_overrides_of_flattened_fields() -> Result<()> {
+        #[derive(Debug, Deserialize)]
+        struct Flattened {
+            server: Outer,
+        }
+
+        #[derive(Debug, Deserialize)]
+        struct Outer {
+            #[serde(flatten)]
+            listen: Listen,
+        }
+
+        #[derive(Debug, Deserialize)]
+        struct Listen {
+            #[serde(deserialize_with = "number")]
+            port: u16,
+            #[serde(default, deserialize_with = "optional_number")]
+            metrics: Option<u16>,
+        }
+
+        impl Validate for Flattened {
+            fn validate(&self, _errors: &mut Errors) {}
+        }
+
+        let config: Flattened = load_from(
+            &SOURCE,
+            vars(&[
+                ("TEST_SERVER__PORT", "50000"),
+                ("TEST_SERVER__METRICS", "9090"),
+            ]),
+        )?;
+        assert_eq!(config.server.listen.port, 50000);
+        assert_eq!(config.server.listen.metrics, Some(9090));
+
+        let yml = file("server:\n  port: 50000\n");
+        let path = path(&yml);
+        let config: Flattened = load_from(&SOURCE, vars(&[("TEST_CONFIG", path)]))?;
+        assert_eq!(config.server.listen.port, 50000);
+        assert_eq!(config.server.listen.metrics, None);
+        Ok(())
+    }
+
+    #[test]
+    fn override_path_should_split_on_double_underscore() {
+        let path = |key| override_path("USER_STAT", key);
+        assert_eq!(
+            path("USER_STAT_SERVER__DB_URL"),
+            Some(vec!["server".to_string(), "db_url".to_string()])
+        );
+        assert_eq!(path("USER_STAT_CONFIG"), None);
+        assert_eq!(path("USER_STATS_SERVER__PORT"), None);
+        assert_eq!(path("USER_STAT_SERVER____PORT"), None);
+    }
+
+    #[test]
+    fn errors_should_have_field_path() {
+        let yml = file("server:\n  port: 50000\n");
+        let err = load_from::<Config>(
+            &SOURCE,
+            vars(&[("TEST_CONFIG", path(&yml)), ("TEST_SERVER__PORT", "port")]),
+        )
+        .unwrap_err()
+        .to_string();
+        assert!(
+            err.starts_with("invalid config: server.port: invalid type"),
+            "{}",
+            err
+        );
+
+        let err = load_from::<Config>(&SOURCE, vars(&[]))
+            .unwrap_err()
+            .to_string();
+        assert_eq!(
+            err,
+            "no config found in ./not-found.yml, /etc/config/not-found.yml or TEST_CONFIG"
+        );
+
+        let missing = file("server:\n  host: localhost\n");
+        let err = load_from::<Config>(&SOURCE, vars(&[("TEST_CONFIG", path(&missing))]))
+            .unwrap_err()
+            .to_string();
+        assert_eq!(err, "invalid config: server: missing field `port`");
+
+        let err = load_from::<Config>(&SOURCE, vars(&[("TEST_CONFIG", "/not/found.yml")]))
+            .unwrap_err()
+            .to_string();
+        assert_eq!(err, "TEST_CONFIG is invalid");
+    }
+
+    #[test]
+    fn validate_should_report_every_invalid_field() {
+        let yml = file(
+            "server:\n  port: 0\nbackends:\n  - url: http://[::1]:50001\n  - url: ftp://[::1]\n",
+        );
+        let err = load_from::<Config>(&SOURCE, vars(&[("TEST_CONFIG", path(&yml))]))
+            .unwrap_err()
+            .to_string();
+        assert_eq!(
+            err,
+            "invalid config:\n  server.port: must not be 0\n  backends[1].url: must be an http or https url"
+        );
+    }
+}
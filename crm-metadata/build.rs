@@ -1,4 +1,4 @@
-use std::{fs, process::Command};
+use std::{env, fs, path::PathBuf, process::Command};
 
 use anyhow::Result;
 use proto_builder_trait::tonic::BuilderAttributes;
@@ -8,6 +8,7 @@ fn main() -> Result<()> {
     let builder = tonic_build::configure();
     builder
         .out_dir("src/pb")
+        .file_descriptor_set_path(PathBuf::from(env::var("OUT_DIR")?).join("metadata.bin"))
         .with_type_attributes(&["MaterializeRequest"], &[r#"#[derive(Eq, Hash)]"#])
         .compile(
             &[
@@ -20,7 +21,7 @@ fn main() -> Result<()> {
 
     Command::new("cargo")
         .arg("fmt")
-        .spawn()
+        .status()
         .expect("executing cargo fmt failed");
     Ok(())
 }
@@ -8,7 +8,7 @@ use fake::{
 use prost_types::Timestamp;
 use rand::Rng;
 use tokio_stream::wrappers::ReceiverStream;
-use tracing::info;
+use tracing::{info, info_span, Instrument};
 
 use crate::{
     pb::{Content, MaterializeRequest, Publisher},
@@ -27,12 +27,16 @@ impl MetadataService {
     ) -> ServiceResult<ResponseStream> {
         let (tx, rx) = mpsc::channel(CHANNEL_SIZE);
 
-        tokio::spawn(async move {
-            while let Some(Ok(req)) = stream.next().await {
-                let content = Content::materialize(req.id);
-                tx.send(Ok(content)).await.unwrap();
+        tokio::spawn(
+            async move {
+                while let Some(Ok(req)) = stream.next().await {
+                    let span = info_span!("materialize", content_id = req.id);
+                    let content = span.in_scope(|| Content::materialize(req.id));
+                    tx.send(Ok(content)).await.unwrap();
+                }
             }
-        });
+            .in_current_span(),
+        );
         let stream = ReceiverStream::new(rx);
         Ok(Response::new(Box::pin(stream)))
     }
@@ -108,7 +112,7 @@ mod tests {
     use anyhow::Result;
     #[tokio::test]
     async fn materialize_should_work() -> Result<()> {
-        let config = AppConfig::load()?;
+        let config = AppConfig::load_for_test()?;
         let service = MetadataService::new(config);
         let stream = tokio_stream::iter(vec![
             Ok(MaterializeRequest { id: 1 }),
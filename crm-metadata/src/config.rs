@@ -1,36 +1,49 @@
-use anyhow::{bail, Result};
+use crm_auth::AuthConfig;
+use crm_bootstrap::{Config, ServerConfig, TelemetryConfig};
+use crm_config::{Errors, Source, Validate};
 use serde::{Deserialize, Serialize};
-use std::{env, fs::File};
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct AppConfig {
     pub server: ServerConfig,
     pub auth: AuthConfig,
+    #[serde(flatten)]
+    pub telemetry: TelemetryConfig,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
-pub struct AuthConfig {
-    pub pk: String,
+impl AppConfig {
+    /// the config of the tests, with a token for crm
+    #[cfg(feature = "test_utils")]
+    pub fn load_for_test() -> anyhow::Result<Self> {
+        let var = format!("{}_AUTH__SERVICE_TOKENS__CRM", Self::SOURCE.prefix);
+        let test = (var, "crm-test-token".to_string());
+        crm_config::load_from(&Self::SOURCE, std::env::vars().chain([test]))
+    }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
-pub struct ServerConfig {
-    pub port: u16,
+impl Config for AppConfig {
+    /// read from ./metadata.yml or /etc/config/metadata.yml, then from METADATA_CONFIG and
+    /// METADATA_* env vars
+    const SOURCE: Source = Source {
+        file: "metadata.yml",
+        prefix: "METADATA",
+    };
+
+    fn server(&self) -> &ServerConfig {
+        &self.server
+    }
+
+    fn telemetry(&self) -> &TelemetryConfig {
+        &self.telemetry
+    }
 }
 
-impl AppConfig {
-    pub fn load() -> Result<Self> {
-        // read from  ./app.yml, or /etc/config/app.yml, or from env CHAT_CONFIG
-        let ret = match (
-            File::open("metadata.yml"),
-            File::open("/etc/config/metadata.yml"),
-            env::var("METADATA_CONFIG"),
-        ) {
-            (Ok(reader), _, _) => serde_yaml::from_reader(reader),
-            (_, Ok(reader), _) => serde_yaml::from_reader(reader),
-            (_, _, Ok(path)) => serde_yaml::from_reader(File::open(path)?),
-            _ => bail!("Config file not found"),
-        };
-        Ok(ret?)
+impl Validate for AppConfig {
+    fn validate(&self, errors: &mut Errors) {
+        errors.field("server", &self.server);
+        errors.field("auth", &self.auth);
+        self.auth
+            .require_services(Self::SOURCE.prefix, &["crm"], errors);
+        self.telemetry.validate(errors);
     }
 }
@@ -3,17 +3,23 @@ mod config;
 pub mod pb;
 pub use abi::Tpl;
 pub use config::AppConfig;
+use crm_auth::{scope, Authenticated, DecodingKey, Scopes};
 use futures::Stream;
 use pb::{
     metadata_server::{Metadata, MetadataServer},
-    Content, MaterializeRequest,
+    Content, MaterializeRequest, FILE_DESCRIPTOR_SET,
 };
-use std::pin::Pin;
-use tonic::{async_trait, Request, Response, Status, Streaming};
+use std::{pin::Pin, sync::Arc};
+use tonic::{
+    async_trait, server::NamedService, service::RoutesBuilder, Request, Response, Status, Streaming,
+};
+
+/// scope of each method, the contents are what campaign previews show
+const SCOPES: Scopes = &[("Materialize", scope::CAMPAIGN_PREVIEW)];
 
-#[allow(unused)]
+#[derive(Clone)]
 pub struct MetadataService {
-    config: AppConfig,
+    config: Arc<AppConfig>,
 }
 
 type ServiceResult<T> = Result<Response<T>, Status>;
@@ -34,10 +40,30 @@ impl Metadata for MetadataService {
 
 impl MetadataService {
     pub fn new(config: AppConfig) -> Self {
-        MetadataService { config }
+        MetadataService {
+            config: Arc::new(config),
+        }
+    }
+
+    pub fn into_server(self) -> anyhow::Result<Authenticated<MetadataServer<Self>>> {
+        let dk = DecodingKey::load(&self.config.auth)?;
+        dk.watch_jwks();
+        Ok(dk.wrap(MetadataServer::new(self), SCOPES))
+    }
+}
+
+#[async_trait]
+impl crm_bootstrap::Service for MetadataService {
+    type Config = AppConfig;
+    const NAME: &'static str = MetadataServer::<Self>::NAME;
+    const FILE_DESCRIPTOR_SET: &'static [u8] = FILE_DESCRIPTOR_SET;
+
+    async fn start(config: AppConfig) -> anyhow::Result<Self> {
+        Ok(Self::new(config))
     }
 
-    pub fn into_server(self) -> MetadataServer<Self> {
-        MetadataServer::new(self)
+    fn add_to(self, routes: &mut RoutesBuilder) -> anyhow::Result<()> {
+        routes.add_service(self.into_server()?);
+        Ok(())
     }
 }
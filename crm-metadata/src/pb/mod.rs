@@ -1,2 +1,5 @@
 mod metadata;
 pub use metadata::*;
+
+/// descriptors of the protos, for the reflection service
+pub const FILE_DESCRIPTOR_SET: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/metadata.bin"));
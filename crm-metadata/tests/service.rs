@@ -1,18 +1,25 @@
 use std::{net::SocketAddr, time::Duration};
 
 use anyhow::Result;
+use crm_auth::ServiceToken;
 use crm_metadata::{
     pb::{metadata_client::MetadataClient, MaterializeRequest},
     AppConfig, MetadataService,
 };
 use futures::StreamExt;
 use tokio::time::sleep;
-use tonic::{transport::Server, Request};
+use tonic::{
+    transport::{Channel, Server},
+    Request,
+};
 
 #[tokio::test]
 async fn test_metadata() -> Result<()> {
-    let addr = start_server().await?;
-    let mut client = MetadataClient::connect(format!("http://{}", addr)).await?;
+    let (addr, token) = start_server().await?;
+    let channel = Channel::from_shared(format!("http://{}", addr))?
+        .connect()
+        .await?;
+    let mut client = MetadataClient::with_interceptor(channel, token);
     let stream = tokio_stream::iter(vec![
         MaterializeRequest { id: 1 },
         MaterializeRequest { id: 2 },
@@ -25,19 +32,22 @@ async fn test_metadata() -> Result<()> {
     assert_eq!(ret.len(), 3);
     Ok(())
 }
-async fn start_server() -> Result<SocketAddr> {
-    let config = AppConfig::load()?;
+/// start the service, with the token crm calls it with
+async fn start_server() -> Result<(SocketAddr, ServiceToken)> {
+    let config = AppConfig::load_for_test()?;
     let addr = format!("[::1]:{}", config.server.port).parse()?;
-    let svc = MetadataService::new(config).into_server();
+    let token = ServiceToken::new(config.auth.service_tokens.get("crm").map(|t| t.as_str()))?;
+    let svc = MetadataService::new(config);
+    let routes = crm_bootstrap::routes(svc, std::future::pending()).await?;
 
     tokio::spawn(async move {
         Server::builder()
-            .add_service(svc)
+            .add_routes(routes)
             .serve(addr)
             .await
             .unwrap();
     });
     sleep(Duration::from_secs(1)).await;
 
-    Ok(addr)
+    Ok((addr, token))
 }
@@ -1,11 +1,12 @@
 use anyhow::Result;
-use std::{fs, process::Command};
+use std::{env, fs, path::PathBuf, process::Command};
 
 fn main() -> Result<()> {
     fs::create_dir_all("src/pb")?;
     let builder = tonic_build::configure();
     builder
         .out_dir("src/pb")
+        .file_descriptor_set_path(PathBuf::from(env::var("OUT_DIR")?).join("notification.bin"))
         .compile(
             &[
                 "../protos/notification/messages.proto",
@@ -17,7 +18,7 @@ fn main() -> Result<()> {
 
     Command::new("cargo")
         .arg("fmt")
-        .spawn()
+        .status()
         .expect("executing cargo fmt failed");
     Ok(())
 }
@@ -3,7 +3,7 @@ use tracing::warn;
 
 use crate::pb::{send_request::Msg, EmailMessage, SendRequest, SendResponse};
 
-use super::{to_ts, Sender};
+use super::{to_ts, Queued, Sender};
 
 //empty file
 impl Sender for EmailMessage {
@@ -13,10 +13,14 @@ impl Sender for EmailMessage {
     ) -> Result<crate::pb::SendResponse, tonic::Status> {
         let message_id = self.message_id.clone();
 
-        svc.sender.send(Msg::Email(self)).await.map_err(|e| {
-            warn!("Failed to send email message {:?}", e);
-            Status::internal("Failed to send message")
-        })?;
+        svc.sender
+            .send(Queued::new(Msg::Email(self)))
+            .await
+            .map_err(|e| {
+                warn!("Failed to send email message {:?}", e);
+                Status::internal("Failed to send message")
+            })?;
+        svc.queued("email");
 
         Ok(SendResponse {
             message_id,
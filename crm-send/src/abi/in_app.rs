@@ -8,15 +8,19 @@ use crate::{
     NotificationService,
 };
 
-use super::{to_ts, Sender};
+use super::{to_ts, Queued, Sender};
 
 impl Sender for InAppMessage {
     async fn send(self, svc: NotificationService) -> Result<SendResponse, Status> {
         let message_id = self.message_id.clone();
-        svc.sender.send(Msg::InApp(self)).await.map_err(|e| {
-            warn!("Failed to send message: {:?}", e);
-            Status::internal(format!("Failed to send in-app message: {}", e))
-        })?;
+        svc.sender
+            .send(Queued::new(Msg::InApp(self)))
+            .await
+            .map_err(|e| {
+                warn!("Failed to send message: {:?}", e);
+                Status::internal(format!("Failed to send in-app message: {}", e))
+            })?;
+        svc.queued("in_app");
 
         Ok(SendResponse {
             message_id,
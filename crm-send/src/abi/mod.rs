@@ -1,20 +1,30 @@
-use std::{ops::Deref, sync::Arc, time::Duration};
+use std::{
+    ops::Deref,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
 
 use chrono::Utc;
+use crm_auth::{scope, Authenticated, DecodingKey, Scopes};
 use crm_metadata::{pb::Content, Tpl};
 use futures::{Stream, StreamExt};
+use metrics::{counter, gauge};
 use prost_types::Timestamp;
-use tokio::{sync::mpsc, time::sleep};
+use tokio::{
+    sync::{mpsc, Notify},
+    task::JoinHandle,
+    time::{sleep, timeout},
+};
 use tokio_stream::wrappers::ReceiverStream;
-use tonic::{Response, Status};
-use tracing::{info, warn};
+use tonic::{server::NamedService, service::RoutesBuilder, Response, Status};
+use tracing::{info, info_span, warn, Instrument, Span};
 use uuid::Uuid;
 
 use crate::{
     config::AppConfig,
     pb::{
         notification_server::NotificationServer, send_request::Msg, EmailMessage, SendRequest,
-        SendResponse,
+        SendResponse, FILE_DESCRIPTOR_SET,
     },
     NotificationService, NotificationServiceInner, ResponseStream, ServiceResult,
 };
@@ -23,20 +33,56 @@ mod email;
 mod in_app;
 mod sms;
 const CHANNEL_SIZE: usize = 1024;
+/// scope of each method, sending messages is running a campaign
+const SCOPES: Scopes = &[("Send", scope::CAMPAIGN_RUN)];
+/// messages waiting in the queue of dummy_send
+const QUEUE_DEPTH: &str = "send_queue_depth";
+
+/// a message waiting to be sent, with the span of the request that queued it
+pub(crate) struct Queued {
+    msg: Msg,
+    span: Span,
+}
 
 pub trait Sender {
     async fn send(self, svc: NotificationService) -> Result<SendResponse, Status>;
 }
 impl NotificationService {
     pub fn new(config: AppConfig) -> Self {
-        let sender = dummy_send();
-        let inner = NotificationServiceInner { config, sender };
+        let closing = Arc::new(Notify::new());
+        let (sender, worker) = dummy_send(closing.clone());
+        let inner = NotificationServiceInner {
+            config,
+            sender,
+            closing,
+            worker: Mutex::new(Some(worker)),
+        };
         Self {
             inner: Arc::new(inner),
         }
     }
-    pub fn into_server(self) -> NotificationServer<Self> {
-        NotificationServer::new(self)
+    pub fn into_server(self) -> anyhow::Result<Authenticated<NotificationServer<Self>>> {
+        let dk = DecodingKey::load(&self.config.auth)?;
+        dk.watch_jwks();
+        Ok(dk.wrap(NotificationServer::new(self), SCOPES))
+    }
+
+    /// count a message queued for a channel
+    pub(crate) fn queued(&self, channel: &'static str) {
+        counter!("send_messages_queued_total", "channel" => channel).increment(1);
+    }
+
+    /// stop taking messages and send the queued ones, those left after the grace timeout are
+    /// dropped
+    pub async fn drain(&self, grace: Duration) {
+        self.closing.notify_one();
+        let Some(worker) = self.worker.lock().unwrap().take() else {
+            return;
+        };
+        if timeout(grace, worker).await.is_err() {
+            let left = self.sender.max_capacity() - self.sender.capacity();
+            warn!("{} queued messages dropped after {:?}", left, grace);
+        }
     }
 
     pub async fn send(
@@ -45,28 +91,66 @@ impl NotificationService {
     ) -> ServiceResult<ResponseStream> {
         let (tx, rx) = mpsc::channel(CHANNEL_SIZE);
         let notif = self.clone();
-        tokio::spawn(async move {
-            while let Some(Ok(req)) = stream.next().await {
-                let notif_clone = notif.clone();
-                let res = match req.msg {
-                    Some(Msg::Email(email)) => email.send(notif_clone).await,
-                    Some(Msg::Sms(sms)) => sms.send(notif_clone).await,
-                    Some(Msg::InApp(in_app)) => in_app.send(notif_clone).await,
-                    // None => todo!(),
-                    None => {
-                        warn!("Invalid message ");
-                        Err(Status::internal("Invalid message"))
-                    }
-                };
-                tx.send(res).await.unwrap();
+        tokio::spawn(
+            async move {
+                while let Some(Ok(req)) = stream.next().await {
+                    let notif_clone = notif.clone();
+                    let span = |channel| info_span!("message", channel);
+                    let res = match req.msg {
+                        Some(Msg::Email(email)) => {
+                            email.send(notif_clone).instrument(span("email")).await
+                        }
+                        Some(Msg::Sms(sms)) => sms.send(notif_clone).instrument(span("sms")).await,
+                        Some(Msg::InApp(in_app)) => {
+                            in_app.send(notif_clone).instrument(span("in_app")).await
+                        }
+                        // None => todo!(),
+                        None => {
+                            warn!("Invalid message ");
+                            Err(Status::internal("Invalid message"))
+                        }
+                    };
+                    tx.send(res).await.unwrap();
+                }
             }
-        });
+            .in_current_span(),
+        );
 
         let stream = ReceiverStream::new(rx);
         Ok(Response::new(Box::pin(stream)))
     }
 }
 
+#[crm_bootstrap::async_trait]
+impl crm_bootstrap::Service for NotificationService {
+    type Config = AppConfig;
+    const NAME: &'static str = NotificationServer::<Self>::NAME;
+    const FILE_DESCRIPTOR_SET: &'static [u8] = FILE_DESCRIPTOR_SET;
+
+    async fn start(config: AppConfig) -> anyhow::Result<Self> {
+        Ok(Self::new(config))
+    }
+
+    fn add_to(self, routes: &mut RoutesBuilder) -> anyhow::Result<()> {
+        routes.add_service(self.into_server()?);
+        Ok(())
+    }
+
+    /// messages queued by the requests are sent in the background
+    async fn drain(&self, grace: Duration) {
+        NotificationService::drain(self, grace).await
+    }
+}
+
+impl Queued {
+    fn new(msg: Msg) -> Self {
+        Self {
+            msg,
+            span: Span::current(),
+        }
+    }
+}
+
 impl SendRequest {
     pub fn new(
         subject: String,
@@ -86,15 +170,29 @@ impl SendRequest {
     }
 }
 
-fn dummy_send() -> mpsc::Sender<Msg> {
+fn dummy_send(closing: Arc<Notify>) -> (mpsc::Sender<Queued>, JoinHandle<()>) {
     let (tx, mut rx) = mpsc::channel(CHANNEL_SIZE);
-    tokio::spawn(async move {
-        while let Some(msg) = rx.recv().await {
-            info!("Sending message: {:?}", msg);
-            sleep(Duration::from_millis(300)).await;
+    let worker = tokio::spawn(async move {
+        loop {
+            tokio::select! {
+                msg = rx.recv() => {
+                    let Some(Queued { msg, span }) = msg else {
+                        break;
+                    };
+                    gauge!(QUEUE_DEPTH).set(rx.len() as f64);
+                    async {
+                        info!("Sending message: {:?}", msg);
+                        sleep(Duration::from_millis(300)).await;
+                    }
+                    .instrument(span)
+                    .await;
+                }
+                // the messages already queued are still received
+                _ = closing.notified() => rx.close(),
+            }
         }
     });
-    tx
+    (tx, worker)
 }
 
 impl Deref for NotificationService {
@@ -121,7 +219,7 @@ mod tests {
 
     #[tokio::test]
     async fn send_should_work() -> Result<()> {
-        let config = AppConfig::load()?;
+        let config = AppConfig::load_for_test()?;
         let service = NotificationService::new(config);
         let stream = tokio_stream::iter(vec![
             Ok(EmailMessage::fake().into()),
@@ -133,4 +231,19 @@ mod tests {
         assert_eq!(ret.len(), 3);
         Ok(())
     }
+
+    #[tokio::test]
+    async fn drain_should_send_queued_messages() -> Result<()> {
+        let config = AppConfig::load_for_test()?;
+        let service = NotificationService::new(config);
+        for _ in 0..3 {
+            EmailMessage::fake().send(service.clone()).await?;
+        }
+        service.drain(Duration::from_secs(5)).await;
+        assert_eq!(service.sender.capacity(), CHANNEL_SIZE);
+
+        // the queue is closed
+        assert!(EmailMessage::fake().send(service.clone()).await.is_err());
+        Ok(())
+    }
 }
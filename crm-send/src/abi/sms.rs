@@ -8,15 +8,19 @@ use crate::{
     NotificationService,
 };
 
-use super::{to_ts, Sender};
+use super::{to_ts, Queued, Sender};
 
 impl Sender for SmsMessage {
     async fn send(self, svc: NotificationService) -> Result<SendResponse, Status> {
         let message_id = self.message_id.clone();
-        svc.sender.send(Msg::Sms(self)).await.map_err(|e| {
-            warn!("Failed to send SMS message {}: {}", message_id, e);
-            Status::internal("Failed to send SMS message")
-        })?;
+        svc.sender
+            .send(Queued::new(Msg::Sms(self)))
+            .await
+            .map_err(|e| {
+                warn!("Failed to send SMS message {}: {}", message_id, e);
+                Status::internal("Failed to send SMS message")
+            })?;
+        svc.queued("sms");
         Ok(SendResponse {
             message_id,
             timestamp: Some(to_ts()),
@@ -1,9 +1,16 @@
-use std::{pin::Pin, sync::Arc};
+use std::{
+    pin::Pin,
+    sync::{Arc, Mutex},
+};
 
+use abi::Queued;
 pub use config::AppConfig;
 use futures::Stream;
-use pb::{notification_server::Notification, send_request::Msg, SendRequest, SendResponse};
-use tokio::sync::mpsc;
+use pb::{notification_server::Notification, SendRequest, SendResponse};
+use tokio::{
+    sync::{mpsc, Notify},
+    task::JoinHandle,
+};
 use tonic::{async_trait, Request, Response, Status, Streaming};
 
 mod abi;
@@ -18,7 +25,10 @@ pub struct NotificationService {
 #[allow(unused)]
 pub struct NotificationServiceInner {
     config: AppConfig,
-    sender: mpsc::Sender<Msg>,
+    sender: mpsc::Sender<Queued>,
+    /// stops the queue from taking messages, the queued ones are still sent
+    closing: Arc<Notify>,
+    worker: Mutex<Option<JoinHandle<()>>>,
 }
 
 type ServiceResult<T> = Result<Response<T>, Status>;
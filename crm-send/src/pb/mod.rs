@@ -1,2 +1,6 @@
 mod notification;
 pub use notification::*;
+
+/// descriptors of the protos, for the reflection service
+pub const FILE_DESCRIPTOR_SET: &[u8] =
+    include_bytes!(concat!(env!("OUT_DIR"), "/notification.bin"));
@@ -1,6 +1,11 @@
-use std::{net::SocketAddr, time::Duration};
+use std::{
+    future::{self, Future},
+    net::SocketAddr,
+    time::Duration,
+};
 
 use anyhow::Result;
+use crm_auth::{scope, EncodingKey, ServiceToken, User};
 use crm_send::{
     pb::{
         notification_client::NotificationClient, EmailMessage, InAppMessage, SendRequest,
@@ -8,14 +13,29 @@ use crm_send::{
     },
     AppConfig, NotificationService,
 };
-use futures::StreamExt;
-use tokio::time::sleep;
-use tonic::{transport::Server, Request};
+use futures::{FutureExt, StreamExt};
+use tokio::{sync::oneshot, time::sleep};
+use tonic::{
+    transport::{Channel, Server},
+    Code, Request,
+};
+use tonic_health::pb::{
+    health_check_response::ServingStatus, health_client::HealthClient, HealthCheckRequest,
+};
+use tonic_reflection::pb::v1::{
+    server_reflection_client::ServerReflectionClient, server_reflection_request::MessageRequest,
+    server_reflection_response::MessageResponse, ServerReflectionRequest,
+};
+
+const PORT_BASE: u32 = 61000;
 
 #[tokio::test]
 async fn test_send() -> Result<()> {
-    let addr = start_server().await?;
-    let mut client = NotificationClient::connect(format!("http://{}", addr)).await?;
+    let (addr, token) = start_server(PORT_BASE).await?;
+    let channel = Channel::from_shared(format!("http://{}", addr))?
+        .connect()
+        .await?;
+    let mut client = NotificationClient::with_interceptor(channel, token);
     let stream = tokio_stream::iter(vec![
         SendRequest {
             msg: Some(EmailMessage::fake().into()),
@@ -37,19 +57,138 @@ async fn test_send() -> Result<()> {
     Ok(())
 }
 
-async fn start_server() -> Result<SocketAddr> {
-    let config = AppConfig::load()?;
-    let addr = format!("[::1]:{}", config.server.port).parse()?;
-    let svc = NotificationService::new(config).into_server();
+#[tokio::test]
+async fn send_without_token_should_fail() -> Result<()> {
+    let (addr, _) = start_server(PORT_BASE + 1).await?;
+    let mut client = NotificationClient::connect(format!("http://{}", addr)).await?;
+    let stream = tokio_stream::iter(vec![SendRequest {
+        msg: Some(EmailMessage::fake().into()),
+    }]);
+    let ret = client.send(Request::new(stream)).await;
+    assert_eq!(ret.unwrap_err().code(), Code::Unauthenticated);
+    Ok(())
+}
+
+#[tokio::test]
+async fn send_without_scope_should_be_denied() -> Result<()> {
+    let key = EncodingKey::generate();
+    let mut config = AppConfig::load_for_test()?;
+    config.auth.pk = Some(key.public_key_pem());
+    let (addr, _) = start_server_with(PORT_BASE + 3, config, future::pending()).await?;
+    let channel = Channel::from_shared(format!("http://{}", addr))?
+        .connect()
+        .await?;
+    // no scope, then the scope of analysts previewing campaigns
+    for scopes in [vec![], vec![scope::CAMPAIGN_PREVIEW.to_string()]] {
+        let user = User {
+            id: 1,
+            ws_id: 1,
+            fullname: "Tyr Chen".to_string(),
+            email: "tchen@acme.org".to_string(),
+            password_hash: String::new(),
+            created_at: "2024-07-01T00:00:00Z".to_string(),
+            scopes,
+        };
+        let token = key.sign(user, chrono::Duration::hours(1))?;
+        // a user token sent the way crm sends its own
+        let token = ServiceToken::new(Some(&token))?;
+        let mut client = NotificationClient::with_interceptor(channel.clone(), token);
+        let stream = tokio_stream::iter(vec![SendRequest {
+            msg: Some(EmailMessage::fake().into()),
+        }]);
+        let ret = client.send(Request::new(stream)).await;
+        assert_eq!(ret.unwrap_err().code(), Code::PermissionDenied);
+    }
+    Ok(())
+}
+
+/// start the service, with the token crm calls it with
+#[tokio::test]
+async fn health_and_reflection_should_not_need_token() -> Result<()> {
+    let (addr, _) = start_server(PORT_BASE + 2).await?;
+    let channel = Channel::from_shared(format!("http://{}", addr))?
+        .connect()
+        .await?;
+    let mut client = HealthClient::new(channel.clone());
+    let req = HealthCheckRequest {
+        service: "notification.Notification".to_string(),
+    };
+    let res = client.check(req).await?.into_inner();
+    assert_eq!(res.status(), ServingStatus::Serving);
+
+    let mut client = ServerReflectionClient::new(channel);
+    let req = ServerReflectionRequest {
+        host: String::new(),
+        message_request: Some(MessageRequest::ListServices(String::new())),
+    };
+    let mut stream = client
+        .server_reflection_info(futures::stream::iter([req]))
+        .await?
+        .into_inner();
+    let Some(MessageResponse::ListServicesResponse(res)) =
+        stream.next().await.unwrap()?.message_response
+    else {
+        panic!("expected the list of services");
+    };
+    let services: Vec<_> = res.service.into_iter().map(|s| s.name).collect();
+    assert!(services.contains(&"notification.Notification".to_string()));
+    assert!(services.contains(&"grpc.health.v1.Health".to_string()));
+    Ok(())
+}
+
+#[tokio::test]
+async fn health_should_not_serve_on_shutdown() -> Result<()> {
+    let (tx, rx) = oneshot::channel::<()>();
+    let config = AppConfig::load_for_test()?;
+    let (addr, _) = start_server_with(PORT_BASE + 4, config, rx.map(|_| ())).await?;
+    let channel = Channel::from_shared(format!("http://{}", addr))?
+        .connect()
+        .await?;
+    let mut client = HealthClient::new(channel);
+    for service in ["", "notification.Notification"] {
+        let req = HealthCheckRequest {
+            service: service.to_string(),
+        };
+        let res = client.check(req).await?.into_inner();
+        assert_eq!(res.status(), ServingStatus::Serving);
+    }
+
+    tx.send(()).unwrap();
+    let req = HealthCheckRequest {
+        service: "notification.Notification".to_string(),
+    };
+    let mut stream = client.watch(req).await?.into_inner();
+    while stream.message().await?.unwrap().status() != ServingStatus::NotServing {}
+    let res = client
+        .check(HealthCheckRequest::default())
+        .await?
+        .into_inner();
+    assert_eq!(res.status(), ServingStatus::NotServing);
+    Ok(())
+}
+
+async fn start_server(port: u32) -> Result<(SocketAddr, ServiceToken)> {
+    start_server_with(port, AppConfig::load_for_test()?, future::pending()).await
+}
+
+async fn start_server_with(
+    port: u32,
+    config: AppConfig,
+    shutdown: impl Future<Output = ()> + Send + 'static,
+) -> Result<(SocketAddr, ServiceToken)> {
+    let addr = format!("[::1]:{}", port).parse()?;
+    let token = ServiceToken::new(config.auth.service_tokens.get("crm").map(|t| t.as_str()))?;
+    let svc = NotificationService::new(config);
+    let routes = crm_bootstrap::routes(svc, shutdown).await?;
 
     tokio::spawn(async move {
         Server::builder()
-            .add_service(svc)
+            .add_routes(routes)
             .serve(addr)
             .await
             .unwrap();
     });
     sleep(Duration::from_secs(1)).await;
 
-    Ok(addr)
+    Ok((addr, token))
 }
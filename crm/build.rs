@@ -1,11 +1,13 @@
 use anyhow::Result;
 use proto_builder_trait::tonic::BuilderAttributes;
-use std::{fs, process::Command};
+use std::{env, fs, path::PathBuf, process::Command};
 fn main() -> Result<()> {
     fs::create_dir_all("src/pb")?;
     let builder = tonic_build::configure();
     builder
         .out_dir("src/pb")
+        .file_descriptor_set_path(PathBuf::from(env::var("OUT_DIR")?).join("crm.bin"))
+        .extern_path(".notification", "::crm_send::pb")
         .with_derive_builder(&["WelcomeRequest", "RecallRequest", "RemindRequest"], None)
         .with_field_attributes(
             &["WelcomeRequest.content_ids"],
@@ -18,7 +20,7 @@ fn main() -> Result<()> {
 
     Command::new("cargo")
         .arg("fmt")
-        .spawn()
+        .status()
         .expect("executing cargo fmt failed");
     Ok(())
 }
@@ -0,0 +1,56 @@
+CREATE type campaign_kind as Enum('welcome', 'recall', 'remind');
+
+CREATE type campaign_status as Enum('running', 'succeeded', 'failed', 'cancelled');
+
+CREATE type schedule_run_status as Enum('fired', 'failed', 'missed');
+
+CREATE type delivery_status as Enum('accepted', 'failed');
+
+-- a campaign started by a request, with its delivery summary. A running campaign is leased by
+-- the server delivering it, which renews heartbeat_at until the campaign finishes. The
+-- campaigns whose lease expired were interrupted
+CREATE table campaigns(
+    id varchar(64) NOT NULL PRIMARY KEY,
+    ws_id bigint NOT NULL,
+    request_id varchar(256) NOT NULL,
+    kind campaign_kind NOT NULL,
+    status campaign_status NOT NULL DEFAULT 'running',
+    interval int NOT NULL,
+    content_ids int [] NOT NULL DEFAULT '{}',
+    matched int NOT NULL DEFAULT 0,
+    accepted int NOT NULL DEFAULT 0,
+    failed int NOT NULL DEFAULT 0,
+    errors text [] NOT NULL DEFAULT '{}',
+    error text,
+    created_at timestamptz NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    finished_at timestamptz,
+    heartbeat_at timestamptz NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    -- a request id starts at most one campaign per workspace, across restarts and servers
+    UNIQUE (ws_id, request_id)
+);
+
+-- a run of a campaign scheduled in the crm config
+CREATE table schedule_runs(
+    id bigserial PRIMARY KEY,
+    schedule varchar(128) NOT NULL,
+    ws_id bigint NOT NULL,
+    kind campaign_kind NOT NULL,
+    status schedule_run_status NOT NULL,
+    campaign_id varchar(64) REFERENCES campaigns(id),
+    error text,
+    scheduled_at timestamptz NOT NULL,
+    fired_at timestamptz,
+    -- a run is recorded once, the server recording it first is the one firing it
+    UNIQUE (schedule, scheduled_at)
+);
+
+-- what was sent to whom by a campaign
+CREATE table campaign_recipients(
+    campaign_id varchar(64) NOT NULL REFERENCES campaigns(id),
+    email varchar(128) NOT NULL,
+    message_id varchar(64) NOT NULL,
+    status delivery_status NOT NULL,
+    error text,
+    sent_at timestamptz NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (campaign_id, email, message_id)
+);
@@ -0,0 +1,11 @@
+CREATE INDEX campaigns_status_idx ON campaigns(status);
+
+CREATE INDEX campaigns_created_at_idx ON campaigns(created_at);
+
+CREATE INDEX campaigns_ws_id_created_at_idx ON campaigns(ws_id, created_at);
+
+CREATE INDEX campaigns_status_heartbeat_at_idx ON campaigns(status, heartbeat_at);
+
+CREATE INDEX schedule_runs_ws_id_idx ON schedule_runs(ws_id);
+
+CREATE INDEX campaign_recipients_email_idx ON campaign_recipients(email);
@@ -0,0 +1,321 @@
+use std::{
+    error::Error,
+    fmt, io,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use tokio::sync::Notify;
+use tonic::{
+    body::BoxBody,
+    codegen::{http, BoxFuture, Context, Poll, Service, StdError},
+    transport::{Channel, ClientTlsConfig, Endpoint},
+    Code, ConnectError, Status, TimeoutExpired,
+};
+use tonic_health::pb::{
+    health_check_response::ServingStatus, health_client::HealthClient, HealthCheckRequest,
+};
+use tracing::{info, warn, Instrument};
+
+use crate::config::BackendConfig;
+
+/// a lazily connected channel to one of the services crm depends on. A request failing to connect
+/// or losing its connection marks the backend down, requests then fail fast until its backoff is
+/// over while a background probe reconnects it
+#[derive(Clone)]
+pub struct Backend {
+    name: &'static str,
+    endpoint: Endpoint,
+    channel: Channel,
+    state: Arc<Mutex<State>>,
+    down: Arc<Notify>,
+    config: Arc<BackendConfig>,
+}
+
+/// whether a backend can take requests
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Readiness {
+    /// not connected yet
+    Connecting,
+    Ready,
+    /// the last connection, request or probe failed
+    Down,
+}
+
+#[derive(Debug)]
+struct State {
+    readiness: Readiness,
+    failures: u32,
+    retry_at: Instant,
+}
+
+impl Backend {
+    /// `tls` is used when the url is https
+    pub fn new(
+        name: &'static str,
+        url: &str,
+        config: &BackendConfig,
+        tls: Option<&ClientTlsConfig>,
+    ) -> anyhow::Result<Self> {
+        let mut endpoint = Endpoint::from_shared(url.to_string())?
+            .connect_timeout(Duration::from_millis(config.connect_timeout))
+            .timeout(Duration::from_millis(config.request_timeout));
+        if let Some(tls) = tls {
+            endpoint = endpoint.tls_config(tls.clone())?;
+        }
+        Ok(Self {
+            name,
+            channel: endpoint.connect_lazy(),
+            endpoint,
+            state: Arc::new(Mutex::new(State {
+                readiness: Readiness::Connecting,
+                failures: 0,
+                retry_at: Instant::now(),
+            })),
+            down: Default::default(),
+            config: Arc::new(config.clone()),
+        })
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    pub fn readiness(&self) -> Readiness {
+        self.state.lock().unwrap().readiness
+    }
+
+    /// connect in the background, reconnect after each failure once the backoff is over and
+    /// probe the backend now and then while it is ready, with a health check of its server
+    pub fn watch(&self) {
+        let backend = self.clone();
+        tokio::spawn(async move {
+            loop {
+                if backend.readiness() == Readiness::Ready {
+                    // requests may be too rare to notice a backend going away
+                    let interval = Duration::from_millis(backend.config.probe_interval);
+                    let _ = tokio::time::timeout(interval, backend.down.notified()).await;
+                }
+                let retry_at = backend.state.lock().unwrap().retry_at;
+                tokio::time::sleep_until(retry_at.into()).await;
+                // through the lazy channel, which reconnects by itself on its next request
+                let mut client = HealthClient::new(backend.channel.clone());
+                match client.check(HealthCheckRequest::default()).await {
+                    Ok(res) if res.get_ref().status() == ServingStatus::Serving => {
+                        backend.succeeded()
+                    }
+                    Ok(res) => backend.failed(&format!("{:?}", res.get_ref().status())),
+                    // reachable, without a health service
+                    Err(status) if status.code() == Code::Unimplemented => backend.succeeded(),
+                    Err(status) => backend.failed(&status),
+                }
+            }
+        });
+    }
+
+    /// how long to fail fast while backing off, the first request after it is let through
+    fn backing_off(&self) -> Option<Duration> {
+        let state = self.state.lock().unwrap();
+        let wait = state.retry_at.saturating_duration_since(Instant::now());
+        (state.readiness == Readiness::Down && !wait.is_zero()).then_some(wait)
+    }
+
+    fn succeeded(&self) {
+        let mut state = self.state.lock().unwrap();
+        if state.readiness != Readiness::Ready {
+            info!("{} is ready", self.name);
+        }
+        state.readiness = Readiness::Ready;
+        state.failures = 0;
+    }
+
+    fn failed(&self, e: &dyn fmt::Display) {
+        let mut state = self.state.lock().unwrap();
+        let backoff = backoff(&self.config, state.failures);
+        warn!("{} failed, retry in {:?}: {}", self.name, backoff, e);
+        state.readiness = Readiness::Down;
+        state.failures = state.failures.saturating_add(1);
+        state.retry_at = Instant::now() + backoff;
+        self.down.notify_one();
+    }
+}
+
+/// min_backoff doubled for each failure before the last one, up to max_backoff
+fn backoff(config: &BackendConfig, failures: u32) -> Duration {
+    let ms = config
+        .min_backoff
+        .saturating_mul(1 << failures.min(32))
+        .min(config.max_backoff);
+    Duration::from_millis(ms)
+}
+
+/// whether a transport error is about reaching the backend, a request that timed out only says
+/// the backend is slow to answer it
+fn unreachable(e: &(dyn Error + 'static)) -> bool {
+    let mut source = Some(e);
+    while let Some(e) = source {
+        if e.is::<TimeoutExpired>() {
+            return false;
+        }
+        if e.is::<ConnectError>() || e.is::<io::Error>() {
+            return true;
+        }
+        source = e.source();
+    }
+    false
+}
+
+impl fmt::Debug for Backend {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Backend")
+            .field("name", &self.name)
+            .field("uri", self.endpoint.uri())
+            .field("state", &self.state)
+            .finish()
+    }
+}
+
+impl Service<http::Request<BoxBody>> for Backend {
+    type Response = http::Response<BoxBody>;
+    type Error = StdError;
+    type Future = BoxFuture<Self::Response, Self::Error>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.channel.poll_ready(cx).map_err(Into::into)
+    }
+
+    fn call(&mut self, mut req: http::Request<BoxBody>) -> Self::Future {
+        if let Some(wait) = self.backing_off() {
+            let status = Status::unavailable(format!(
+                "{} is unavailable, retry in {}ms",
+                self.name,
+                wait.as_millis()
+            ));
+            return Box::pin(async move { Err(status.into()) });
+        }
+        let span = crm_bootstrap::trace::client_span(&mut req);
+        let backend = self.clone();
+        let fut = self.channel.call(req);
+        let fut = async move {
+            // errors of the transport, statuses returned by the backend are responses
+            match fut.await {
+                Ok(res) => {
+                    backend.succeeded();
+                    Ok(res)
+                }
+                Err(e) => {
+                    if unreachable(&e) {
+                        backend.failed(&e);
+                    }
+                    Err(e.into())
+                }
+            }
+        };
+        Box::pin(fut.instrument(span))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tokio::net::TcpListener;
+    use tokio_stream::wrappers::TcpListenerStream;
+    use tonic::transport::Server;
+    use tonic_health::server::health_reporter;
+    use user_stat::{user_stats_client::UserStatsClient, QueryRequest};
+
+    use super::*;
+
+    #[test]
+    fn backoff_should_double_up_to_max() {
+        let config = BackendConfig {
+            min_backoff: 100,
+            max_backoff: 1_000,
+            ..Default::default()
+        };
+        assert_eq!(backoff(&config, 0), Duration::from_millis(100));
+        assert_eq!(backoff(&config, 3), Duration::from_millis(800));
+        assert_eq!(backoff(&config, 4), Duration::from_millis(1_000));
+        assert_eq!(backoff(&config, u32::MAX), Duration::from_millis(1_000));
+    }
+
+    #[tokio::test]
+    async fn backend_down_should_fail_fast_until_backoff_is_over() {
+        let config = BackendConfig {
+            min_backoff: 60_000,
+            ..Default::default()
+        };
+        // nothing listens on the port
+        let backend = Backend::new("user-stat", "http://127.0.0.1:1", &config, None).unwrap();
+        assert_eq!(backend.readiness(), Readiness::Connecting);
+
+        let mut client = UserStatsClient::new(backend.clone());
+        let status = client.query(QueryRequest::default()).await.unwrap_err();
+        assert_eq!(status.code(), Code::Unavailable);
+        assert_eq!(backend.readiness(), Readiness::Down);
+
+        let status = client.query(QueryRequest::default()).await.unwrap_err();
+        assert_eq!(status.code(), Code::Unavailable);
+        assert!(status
+            .message()
+            .starts_with("user-stat is unavailable, retry in"));
+    }
+
+    #[tokio::test]
+    async fn request_timeout_should_not_mark_backend_down() {
+        let config = BackendConfig {
+            request_timeout: 200,
+            ..Default::default()
+        };
+        // a backend that accepts connections but never answers
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move {
+            let mut conns = Vec::new();
+            while let Ok((conn, _)) = listener.accept().await {
+                conns.push(conn);
+            }
+        });
+        let backend = Backend::new("user-stat", &url, &config, None).unwrap();
+
+        let mut client = UserStatsClient::new(backend.clone());
+        let status = client.query(QueryRequest::default()).await.unwrap_err();
+        assert_eq!(status.code(), Code::Cancelled);
+        assert_ne!(backend.readiness(), Readiness::Down);
+    }
+
+    #[tokio::test]
+    async fn probe_should_follow_health_of_backend() {
+        let config = BackendConfig {
+            min_backoff: 10,
+            probe_interval: 10,
+            ..Default::default()
+        };
+        let (mut reporter, health) = health_reporter();
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        tokio::spawn(
+            Server::builder()
+                .add_service(health)
+                .serve_with_incoming(TcpListenerStream::new(listener)),
+        );
+        let backend = Backend::new("user-stat", &url, &config, None).unwrap();
+        backend.watch();
+        wait_for(&backend, Readiness::Ready).await;
+
+        reporter
+            .set_service_status("", tonic_health::ServingStatus::NotServing)
+            .await;
+        wait_for(&backend, Readiness::Down).await;
+    }
+
+    async fn wait_for(backend: &Backend, readiness: Readiness) {
+        let wait = async {
+            while backend.readiness() != readiness {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+        };
+        tokio::time::timeout(Duration::from_secs(5), wait)
+            .await
+            .unwrap();
+    }
+}
@@ -0,0 +1,570 @@
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+    time::Instant,
+};
+
+use chrono::{Duration, Utc};
+use crm_send::pb::SendRequest;
+use futures::{future::BoxFuture, stream::BoxStream};
+use metrics::{counter, histogram};
+use tokio::{sync::watch, time::sleep};
+use tonic::{Response, Status};
+use tracing::{info, warn, Instrument};
+use uuid::Uuid;
+
+use super::{delivery::preview, to_ts};
+use crate::{
+    pb::{Campaign, CampaignPreview, CampaignStatus, DeliverySummary, ListCampaignsResponse},
+    CrmService,
+};
+
+/// how long a finished campaign is kept in memory, it stays in the database
+const CAMPAIGN_TTL_HOURS: i64 = 24;
+/// how often the running campaigns are checked while draining
+const DRAIN_POLL_MS: u64 = 100;
+/// share of the grace timeout left to the campaigns interrupted by a shutdown, to record what
+/// they sent, e.g. 4 for the last quarter
+const FLUSH_SHARE: u32 = 4;
+/// how often the server renews the leases of the campaigns it runs
+const LEASE_RENEW_SECS: u64 = 30;
+/// how long a running campaign is left to its server without a renewal, before it is failed
+const LEASE_SECS: u64 = 120;
+
+/// rendered messages of a campaign, and a future resolving to the number of matched users and
+/// the error that stopped matching, if any
+pub(crate) type Rendered = (
+    BoxStream<'static, SendRequest>,
+    BoxFuture<'static, (u32, Option<String>)>,
+);
+
+/// what happened to a campaign request
+#[derive(Debug, Clone)]
+pub enum Outcome {
+    Started(Campaign),
+    Previewed(CampaignPreview),
+}
+
+/// why a campaign can't be cancelled
+#[derive(Debug, PartialEq)]
+pub enum CancelError {
+    NotFound,
+    Finished(CampaignStatus),
+}
+
+/// campaigns delivered in the background, with their live progress
+#[derive(Debug, Clone, Default)]
+pub struct CampaignStore {
+    inner: Arc<Mutex<HashMap<String, Job>>>,
+    /// no campaign is started once the server is shutting down
+    closed: Arc<AtomicBool>,
+}
+
+#[derive(Debug)]
+struct Job {
+    campaign: Campaign,
+    /// tells the task delivering the campaign to stop, set while the task runs
+    stop: Option<watch::Sender<bool>>,
+}
+
+impl CrmService {
+    /// render the campaign and preview it in dry-run mode, otherwise record it and start
+    /// delivering it in the background. `def` holds the request the campaign is made of, a
+    /// request id that already started a campaign gets that campaign back
+    pub(crate) async fn launch(
+        &self,
+        def: Campaign,
+        dry_run: bool,
+        render: BoxFuture<'static, Result<Rendered, Status>>,
+    ) -> Result<Outcome, Status> {
+        if dry_run {
+            let (reqs, matched) = render.await?;
+            let preview = preview(reqs).await;
+            if let (_, Some(error)) = matched.await {
+                warn!("Preview is incomplete: {}", error);
+            }
+            return Ok(Outcome::Previewed(preview));
+        }
+
+        if let Some(campaign) = self.started_campaign(&def).await? {
+            return Ok(Outcome::Started(campaign));
+        }
+        if self.campaigns.is_closed() {
+            return Err(Status::unavailable("server is shutting down"));
+        }
+        let campaign = self.campaigns.create(def);
+        match self.insert_campaign(&campaign).await {
+            Ok(true) => {}
+            // a concurrent request with the same id recorded its campaign first
+            Ok(false) => {
+                self.campaigns.remove(&campaign.id);
+                let started = self.started_campaign(&campaign).await?;
+                return started
+                    .map(Outcome::Started)
+                    .ok_or_else(|| Status::aborted("request id conflict, retry the request"));
+            }
+            Err(e) => {
+                self.campaigns.remove(&campaign.id);
+                return Err(e);
+            }
+        }
+        info!(
+            "Campaign {} started for request {}",
+            campaign.id, campaign.request_id
+        );
+        let svc = self.clone();
+        let (ws_id, id) = (campaign.ws_id, campaign.id.clone());
+        let (stop, mut stopped) = watch::channel(false);
+        self.campaigns.set_stop(&id, stop);
+        tokio::spawn(
+            async move {
+                let rendered = tokio::select! {
+                    ret = render => ret,
+                    _ = stopped.wait_for(|stop| *stop) => {
+                        Err(Status::cancelled("stopped before its delivery"))
+                    }
+                };
+                let ret = match rendered {
+                    Ok((reqs, matched)) => svc.deliver(ws_id, &id, reqs, matched, stopped).await,
+                    Err(e) => Err(e),
+                };
+                svc.campaigns.finish(&id, ret);
+                svc.save_progress(&id).await;
+                svc.campaigns.done(&id);
+            }
+            .in_current_span(),
+        );
+        Ok(Outcome::Started(campaign))
+    }
+
+    /// the campaign, a running one is taken from memory for its live progress
+    pub async fn get_campaign(&self, ws_id: i64, id: &str) -> Result<Response<Campaign>, Status> {
+        let campaign = match self.campaigns.get(id) {
+            Some(campaign) => campaign,
+            None => self
+                .load_campaign(id)
+                .await?
+                .ok_or_else(|| Status::not_found(format!("campaign {} not found", id)))?,
+        };
+        check_workspace(ws_id, &campaign)?;
+        Ok(Response::new(campaign))
+    }
+
+    pub async fn list_campaigns(
+        &self,
+        ws_id: i64,
+        status: CampaignStatus,
+    ) -> Result<Response<ListCampaignsResponse>, Status> {
+        let campaigns = self
+            .load_campaigns(ws_id, status)
+            .await?
+            .into_iter()
+            .map(|campaign| self.campaigns.refresh(campaign))
+            .collect();
+        Ok(Response::new(ListCampaignsResponse { campaigns }))
+    }
+
+    pub async fn cancel_campaign(
+        &self,
+        ws_id: i64,
+        id: &str,
+    ) -> Result<Response<Campaign>, Status> {
+        if let Some(campaign) = self.campaigns.get(id) {
+            check_workspace(ws_id, &campaign)?;
+        }
+        let campaign = match self.campaigns.cancel(id) {
+            Ok(campaign) => campaign,
+            // a campaign of a previous run of the server is no longer running
+            Err(CancelError::NotFound) => match self.load_campaign(id).await? {
+                Some(campaign) => {
+                    check_workspace(ws_id, &campaign)?;
+                    return Err(CancelError::Finished(campaign.status()).into_status(id));
+                }
+                None => return Err(CancelError::NotFound.into_status(id)),
+            },
+            Err(e) => return Err(e.into_status(id)),
+        };
+        self.save_campaign(&campaign).await?;
+        Ok(Response::new(campaign))
+    }
+
+    /// stop starting campaigns and wait for the running ones. Those still running near the end
+    /// of the grace timeout are stopped and saved as failed, then given the rest of it to record
+    /// the recipients they sent to
+    pub async fn drain(&self, grace: std::time::Duration) {
+        self.campaigns.close();
+        let flush = grace / FLUSH_SHARE;
+        let poll = std::time::Duration::from_millis(DRAIN_POLL_MS);
+        let deadline = Instant::now() + (grace - flush);
+        while !self.campaigns.running().is_empty() && Instant::now() < deadline {
+            sleep(poll).await;
+        }
+        for id in self.campaigns.running() {
+            self.campaigns.interrupt(&id);
+            self.save_progress(&id).await;
+        }
+
+        let deadline = Instant::now() + flush;
+        while !self.campaigns.delivering().is_empty() && Instant::now() < deadline {
+            sleep(poll).await;
+        }
+        let left = self.campaigns.delivering();
+        if !left.is_empty() {
+            warn!(
+                "{} interrupted campaigns didn't record all their recipients",
+                left.len()
+            );
+        }
+    }
+
+    /// renew the leases of the campaigns running here in the background, and fail the campaigns
+    /// whose server stopped renewing theirs, e.g. after a crash or a restart
+    pub(crate) fn watch_leases(&self) {
+        let svc = self.clone();
+        tokio::spawn(async move {
+            let renew = std::time::Duration::from_secs(LEASE_RENEW_SECS);
+            let mut interval = tokio::time::interval(renew);
+            loop {
+                interval.tick().await;
+                if let Err(e) = svc.renew_leases(&svc.campaigns.running()).await {
+                    warn!("Failed to renew the leases of campaigns: {}", e);
+                }
+                let lease = std::time::Duration::from_secs(LEASE_SECS);
+                if let Err(e) = svc.fail_expired_campaigns(lease).await {
+                    warn!("Failed to fail interrupted campaigns: {}", e);
+                }
+            }
+        });
+    }
+
+    /// save the progress of a campaign, a failure is only logged as the campaign goes on
+    pub(crate) async fn save_progress(&self, id: &str) {
+        let Some(campaign) = self.campaigns.get(id) else {
+            return;
+        };
+        if let Err(e) = self.save_campaign(&campaign).await {
+            warn!("Failed to save campaign {}: {}", id, e);
+        }
+    }
+}
+
+/// campaigns by kind and status, with the users they matched and the messages they sent
+fn record_finished(campaign: &Campaign) {
+    let kind = format!("{:?}", campaign.kind()).to_lowercase();
+    let status = format!("{:?}", campaign.status()).to_lowercase();
+    counter!("crm_campaigns_total", "kind" => kind.clone(), "status" => status).increment(1);
+    let Some(summary) = &campaign.summary else {
+        return;
+    };
+    histogram!("crm_campaign_matched_users", "kind" => kind).record(summary.matched as f64);
+    counter!("crm_messages_total", "status" => "accepted").increment(summary.accepted as _);
+    counter!("crm_messages_total", "status" => "failed").increment(summary.failed as _);
+}
+
+/// a campaign is only visible to the workspace it was started in
+fn check_workspace(ws_id: i64, campaign: &Campaign) -> Result<(), WorkspaceError> {
+    if campaign.ws_id != ws_id {
+        return Err(WorkspaceError(campaign.id.clone()));
+    }
+    Ok(())
+}
+
+/// the campaign of the given id belongs to another workspace
+#[derive(Debug)]
+struct WorkspaceError(String);
+
+impl From<WorkspaceError> for Status {
+    fn from(e: WorkspaceError) -> Self {
+        Status::permission_denied(format!("campaign {} belongs to another workspace", e.0))
+    }
+}
+
+impl CancelError {
+    pub fn into_status(self, id: &str) -> Status {
+        match self {
+            CancelError::NotFound => Status::not_found(format!("campaign {} not found", id)),
+            CancelError::Finished(status) => Status::failed_precondition(format!(
+                "campaign {} is already {}",
+                id,
+                status.as_str_name()
+            )),
+        }
+    }
+}
+
+impl Outcome {
+    pub fn into_parts(self) -> (Option<Campaign>, Option<CampaignPreview>) {
+        match self {
+            Outcome::Started(campaign) => (Some(campaign), None),
+            Outcome::Previewed(preview) => (None, Some(preview)),
+        }
+    }
+}
+
+impl Job {
+    /// tell the task of the campaign to stop, it still records what it sent
+    fn stop(&self) {
+        if let Some(stop) = &self.stop {
+            stop.send_replace(true);
+        }
+    }
+}
+
+impl CampaignStore {
+    /// a running campaign made of the given request
+    pub fn create(&self, def: Campaign) -> Campaign {
+        let campaign = Campaign {
+            id: Uuid::new_v4().to_string(),
+            status: CampaignStatus::Running as _,
+            summary: Some(DeliverySummary::default()),
+            created_at: Some(to_ts(Utc::now())),
+            ..def
+        };
+
+        let mut jobs = self.inner.lock().unwrap();
+        let expired = (Utc::now() - Duration::hours(CAMPAIGN_TTL_HOURS)).timestamp();
+        jobs.retain(|_, job| {
+            job.campaign
+                .finished_at
+                .is_none_or(|at| at.seconds >= expired)
+        });
+        jobs.insert(
+            campaign.id.clone(),
+            Job {
+                campaign: campaign.clone(),
+                stop: None,
+            },
+        );
+        campaign
+    }
+
+    pub fn close(&self) {
+        self.closed.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_closed(&self) -> bool {
+        self.closed.load(Ordering::Relaxed)
+    }
+
+    /// ids of the running campaigns
+    pub fn running(&self) -> Vec<String> {
+        let jobs = self.inner.lock().unwrap();
+        jobs.iter()
+            .filter(|(_, job)| job.campaign.status() == CampaignStatus::Running)
+            .map(|(id, _)| id.clone())
+            .collect()
+    }
+
+    /// ids of the campaigns whose task still runs, stopped ones record what they sent meanwhile
+    pub fn delivering(&self) -> Vec<String> {
+        let jobs = self.inner.lock().unwrap();
+        jobs.iter()
+            .filter(|(_, job)| job.stop.is_some())
+            .map(|(id, _)| id.clone())
+            .collect()
+    }
+
+    pub fn remove(&self, id: &str) {
+        self.inner.lock().unwrap().remove(id);
+    }
+
+    pub fn get(&self, id: &str) -> Option<Campaign> {
+        let jobs = self.inner.lock().unwrap();
+        jobs.get(id).map(|job| job.campaign.clone())
+    }
+
+    /// the latest state of the campaign, or the given one if it is no longer kept
+    pub fn refresh(&self, campaign: Campaign) -> Campaign {
+        self.get(&campaign.id).unwrap_or(campaign)
+    }
+
+    pub fn cancel(&self, id: &str) -> Result<Campaign, CancelError> {
+        let mut jobs = self.inner.lock().unwrap();
+        let job = jobs.get_mut(id).ok_or(CancelError::NotFound)?;
+        if job.campaign.status() != CampaignStatus::Running {
+            return Err(CancelError::Finished(job.campaign.status()));
+        }
+        job.stop();
+        job.campaign.set_status(CampaignStatus::Cancelled);
+        job.campaign.finished_at = Some(to_ts(Utc::now()));
+        record_finished(&job.campaign);
+        info!("Campaign {} cancelled", id);
+        Ok(job.campaign.clone())
+    }
+
+    /// stop a running campaign on shutdown, it keeps the progress it made and records the
+    /// recipients it sent to
+    fn interrupt(&self, id: &str) {
+        let mut jobs = self.inner.lock().unwrap();
+        let Some(job) = jobs.get_mut(id) else {
+            return;
+        };
+        if job.campaign.status() != CampaignStatus::Running {
+            return;
+        }
+        job.stop();
+        job.campaign.set_status(CampaignStatus::Failed);
+        job.campaign.error = "interrupted by a shutdown".to_string();
+        job.campaign.finished_at = Some(to_ts(Utc::now()));
+        record_finished(&job.campaign);
+        warn!("Campaign {} interrupted by a shutdown", id);
+    }
+
+    /// the delivery summary of a campaign, kept up to date until its task ends, also once it
+    /// was stopped
+    pub(crate) fn progress(&self, id: &str, summary: &DeliverySummary) {
+        let mut jobs = self.inner.lock().unwrap();
+        if let Some(job) = jobs.get_mut(id) {
+            if job.stop.is_some() {
+                job.campaign.summary = Some(summary.clone());
+            }
+        }
+    }
+
+    fn set_stop(&self, id: &str, stop: watch::Sender<bool>) {
+        let mut jobs = self.inner.lock().unwrap();
+        if let Some(job) = jobs.get_mut(id) {
+            job.stop = Some(stop);
+        }
+    }
+
+    /// the task of the campaign ended
+    fn done(&self, id: &str) {
+        if let Some(job) = self.inner.lock().unwrap().get_mut(id) {
+            job.stop = None;
+        }
+    }
+
+    /// the outcome of the task of a campaign. A stopped campaign keeps its status and only takes
+    /// the final summary
+    fn finish(&self, id: &str, ret: Result<DeliverySummary, Status>) {
+        if let Ok(summary) = &ret {
+            self.progress(id, summary);
+        }
+        self.update(id, |campaign| {
+            match ret {
+                Ok(summary) => {
+                    campaign.set_status(CampaignStatus::Succeeded);
+                    campaign.summary = Some(summary);
+                }
+                Err(e) => {
+                    warn!("Campaign {} failed: {}", id, e);
+                    campaign.set_status(CampaignStatus::Failed);
+                    campaign.error = e.message().to_string();
+                }
+            }
+            campaign.finished_at = Some(to_ts(Utc::now()));
+            record_finished(campaign);
+        });
+    }
+
+    /// update a running campaign, a cancelled one is left as is
+    fn update(&self, id: &str, f: impl FnOnce(&mut Campaign)) {
+        let mut jobs = self.inner.lock().unwrap();
+        if let Some(job) = jobs.get_mut(id) {
+            if job.campaign.status() == CampaignStatus::Running {
+                f(&mut job.campaign);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::FutureExt;
+
+    use super::*;
+    use crate::pb::CampaignKind;
+
+    #[test]
+    fn campaign_store_should_track_status() {
+        let store = CampaignStore::default();
+        let c1 = store.create(def(CampaignKind::Welcome, "r1"));
+        let c2 = store.create(def(CampaignKind::Recall, "r2"));
+        assert_eq!(c1.status(), CampaignStatus::Running);
+
+        let summary = DeliverySummary {
+            matched: 3,
+            accepted: 3,
+            ..Default::default()
+        };
+        store.finish(&c1.id, Ok(summary.clone()));
+        let c1 = store.get(&c1.id).unwrap();
+        assert_eq!(c1.status(), CampaignStatus::Succeeded);
+        assert_eq!(c1.summary, Some(summary));
+
+        let c2 = store.cancel(&c2.id).unwrap();
+        assert_eq!(c2.status(), CampaignStatus::Cancelled);
+        // a cancelled campaign is not overwritten by its task
+        store.finish(&c2.id, Err(Status::internal("stopped")));
+        assert_eq!(
+            store.get(&c2.id).unwrap().status(),
+            CampaignStatus::Cancelled
+        );
+        assert_eq!(
+            store.cancel(&c2.id).unwrap_err(),
+            CancelError::Finished(CampaignStatus::Cancelled)
+        );
+        assert_eq!(store.cancel("unknown").unwrap_err(), CancelError::NotFound);
+    }
+
+    #[tokio::test]
+    async fn campaign_of_another_workspace_should_be_denied() -> anyhow::Result<()> {
+        let (_tdb, svc) = CrmService::new_for_test().await?;
+        let campaign = svc.campaigns.create(Campaign {
+            ws_id: 1,
+            ..def(CampaignKind::Welcome, "r1")
+        });
+        svc.insert_campaign(&campaign).await?;
+
+        let ret = svc.get_campaign(2, &campaign.id).await;
+        assert_eq!(ret.unwrap_err().code(), tonic::Code::PermissionDenied);
+        let ret = svc.cancel_campaign(2, &campaign.id).await;
+        assert_eq!(ret.unwrap_err().code(), tonic::Code::PermissionDenied);
+        assert_eq!(
+            svc.get_campaign(1, &campaign.id)
+                .await?
+                .into_inner()
+                .status(),
+            CampaignStatus::Running
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn drain_should_interrupt_campaigns_after_grace() -> anyhow::Result<()> {
+        let (_tdb, svc) = CrmService::new_for_test().await?;
+        let render = futures::future::pending().boxed();
+        let Outcome::Started(campaign) = svc
+            .launch(def(CampaignKind::Welcome, "r1"), false, render)
+            .await?
+        else {
+            panic!("campaign should be started");
+        };
+
+        svc.drain(std::time::Duration::from_millis(200)).await;
+        // the interrupted task ended within the grace timeout, after saving the campaign
+        assert!(svc.campaigns.delivering().is_empty());
+        let campaign = svc.load_campaign(&campaign.id).await?.unwrap();
+        assert_eq!(campaign.status(), CampaignStatus::Failed);
+        assert_eq!(campaign.error, "interrupted by a shutdown");
+
+        let render = futures::future::pending().boxed();
+        let ret = svc
+            .launch(def(CampaignKind::Welcome, "r2"), false, render)
+            .await;
+        assert_eq!(ret.unwrap_err().code(), tonic::Code::Unavailable);
+        Ok(())
+    }
+
+    fn def(kind: CampaignKind, request_id: &str) -> Campaign {
+        Campaign {
+            kind: kind as _,
+            request_id: request_id.to_string(),
+            ..Default::default()
+        }
+    }
+}
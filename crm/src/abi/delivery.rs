@@ -0,0 +1,342 @@
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicU32, Ordering},
+        Arc, Mutex,
+    },
+};
+
+use crm_send::pb::{send_request::Msg, SendRequest};
+use futures::{future::BoxFuture, stream::BoxStream, Stream, StreamExt};
+use tokio::sync::{mpsc, watch};
+use tokio_stream::wrappers::ReceiverStream;
+use tonic::Status;
+use tracing::{info, warn, Instrument};
+use user_stat::{NotificationChannel, UpdateNotificationRequest};
+
+use super::records::RecipientStatus;
+use crate::{
+    pb::{CampaignPreview, DeliverySummary},
+    CrmService,
+};
+
+/// max number of failure reasons kept in a delivery summary
+const MAX_ERROR_SAMPLES: usize = 10;
+/// max number of rendered messages kept in a campaign preview
+const MAX_PREVIEW_SAMPLES: usize = 5;
+/// max number of accepted recipients recorded and updated at once
+const UPDATE_BATCH_SIZE: usize = 1000;
+
+impl CrmService {
+    /// send the rendered messages of a campaign through the notification service and report
+    /// what it accepted as the campaign progress. The outcome of each recipient is recorded,
+    /// and users of accepted emails get their last email notification updated for frequency
+    /// capping, batch by batch. Once `stop` is set, sending stops and the recipients accepted
+    /// so far are still recorded. `matched` resolves to the number of matched users and the
+    /// error that stopped matching, if any
+    pub(crate) async fn deliver(
+        &self,
+        ws_id: i64,
+        campaign_id: &str,
+        reqs: BoxStream<'static, SendRequest>,
+        matched: BoxFuture<'static, (u32, Option<String>)>,
+        mut stop: watch::Receiver<bool>,
+    ) -> Result<DeliverySummary, Status> {
+        // message id -> recipients, for the messages still waiting for a response
+        let pending = Arc::new(Mutex::new(HashMap::new()));
+        let rendered = Arc::new(AtomicU32::new(0));
+        // forward through a channel: a boxed stream handed to the notification client can't be
+        // proven Send inside the spawned campaign task
+        let (tx, rx) = mpsc::channel(1024);
+        {
+            let pending = pending.clone();
+            let rendered = rendered.clone();
+            let mut reqs = reqs;
+            tokio::spawn(
+                async move {
+                    while let Some(req) = reqs.next().await {
+                        rendered.fetch_add(1, Ordering::Relaxed);
+                        if let Some(Msg::Email(email)) = &req.msg {
+                            let mut pending = pending.lock().unwrap();
+                            pending.insert(email.message_id.clone(), email.recipients.clone());
+                        }
+                        if tx.send(req).await.is_err() {
+                            break;
+                        }
+                    }
+                }
+                .in_current_span(),
+            );
+        }
+
+        let mut summary = DeliverySummary::default();
+        // (email, message id) of the accepted messages not flushed yet
+        let mut sent = Vec::new();
+        let mut last_error = None;
+        let mut res = self
+            .notification
+            .clone()
+            .send(ReceiverStream::new(rx))
+            .await?
+            .into_inner();
+        loop {
+            // the responses already received are counted before stopping
+            let ret = tokio::select! {
+                biased;
+                ret = res.next() => ret,
+                _ = stop.wait_for(|stop| *stop) => {
+                    info!("Campaign {} stopped, recording what was sent", campaign_id);
+                    last_error = Some("the campaign stopped before it was accepted".to_string());
+                    break;
+                }
+            };
+            let Some(ret) = ret else {
+                break;
+            };
+            match ret {
+                Ok(res) => {
+                    summary.accepted += 1;
+                    if let Some(recipients) = pending.lock().unwrap().remove(&res.message_id) {
+                        sent.extend(recipients.into_iter().map(|r| (r, res.message_id.clone())));
+                    }
+                }
+                Err(e) => {
+                    warn!("Failed to send message: {}", e);
+                    summary.failed += 1;
+                    summary.add_error(e.message());
+                    last_error = Some(e.message().to_string());
+                }
+            }
+            summary.matched = rendered.load(Ordering::Relaxed);
+            self.campaigns.progress(campaign_id, &summary);
+            if sent.len() >= UPDATE_BATCH_SIZE {
+                self.flush_sent(ws_id, campaign_id, &mut sent, &mut summary)
+                    .await;
+            }
+        }
+        drop(res);
+        self.flush_sent(ws_id, campaign_id, &mut sent, &mut summary)
+            .await;
+
+        // the messages never accepted by the notification service
+        let unsent = std::mem::take(&mut *pending.lock().unwrap())
+            .into_iter()
+            .flat_map(|(id, recipients)| recipients.into_iter().map(move |r| (r, id.clone())))
+            .collect::<Vec<_>>();
+        let error = last_error.as_deref().or(Some("not accepted"));
+        if let Err(e) = self
+            .insert_recipients(campaign_id, &unsent, RecipientStatus::Failed, error)
+            .await
+        {
+            warn!("Failed to record failed recipients: {}", e);
+        }
+
+        let (matched, error) = matched.await;
+        if let Some(error) = error {
+            summary.add_error(error);
+        }
+        Ok(summary.finish(matched))
+    }
+
+    /// record the accepted recipients and update their last email notification for frequency
+    /// capping. Failures are recorded in the summary as the messages were already sent
+    async fn flush_sent(
+        &self,
+        ws_id: i64,
+        campaign_id: &str,
+        sent: &mut Vec<(String, String)>,
+        summary: &mut DeliverySummary,
+    ) {
+        if sent.is_empty() {
+            return;
+        }
+        let sent = std::mem::take(sent);
+        if let Err(e) = self
+            .insert_recipients(campaign_id, &sent, RecipientStatus::Accepted, None)
+            .await
+        {
+            warn!("Failed to record sent recipients: {}", e);
+            summary.add_error(e.message());
+        }
+
+        let req = UpdateNotificationRequest {
+            emails: sent.into_iter().map(|(email, _)| email).collect(),
+            channel: NotificationChannel::Email as _,
+            notified_at: None,
+            ws_id,
+        };
+        if let Err(e) = self.user_stats.clone().update_notification(req).await {
+            warn!("Failed to update notified users: {}", e);
+            summary.add_error(e.message());
+        }
+        self.campaigns.progress(campaign_id, summary);
+        self.save_progress(campaign_id).await;
+    }
+}
+
+/// count the rendered messages and keep a few of them, without sending anything
+pub(crate) async fn preview(mut reqs: impl Stream<Item = SendRequest> + Unpin) -> CampaignPreview {
+    let mut preview = CampaignPreview::default();
+    while let Some(req) = reqs.next().await {
+        preview.audience += 1;
+        if preview.samples.len() < MAX_PREVIEW_SAMPLES {
+            preview.samples.push(req);
+        }
+    }
+    preview
+}
+
+impl DeliverySummary {
+    pub fn add_error(&mut self, reason: impl Into<String>) {
+        if self.errors.len() < MAX_ERROR_SAMPLES {
+            self.errors.push(reason.into());
+        }
+    }
+
+    /// every matched user whose message was not accepted counts as failed, not only the
+    /// messages rejected so far
+    pub fn finish(mut self, matched: u32) -> Self {
+        self.matched = matched;
+        self.failed = matched.saturating_sub(self.accepted);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        pb::{Campaign, CampaignKind, CampaignStatus},
+        AppConfig,
+    };
+    use crm_metadata::pb::Content;
+    use crm_send::pb::{
+        notification_server::{Notification, NotificationServer},
+        SendResponse,
+    };
+    use futures::{future, FutureExt};
+    use tokio::net::TcpListener;
+    use tokio_stream::wrappers::TcpListenerStream;
+    use tonic::{transport::Server, Request, Response, Streaming};
+
+    #[test]
+    fn delivery_summary_should_count_failed() {
+        let mut summary = DeliverySummary {
+            accepted: 7,
+            ..Default::default()
+        };
+        for i in 0..20 {
+            summary.add_error(format!("error {}", i));
+        }
+        let summary = summary.finish(10);
+        assert_eq!(summary.matched, 10);
+        assert_eq!(summary.failed, 3);
+        assert_eq!(summary.errors.len(), MAX_ERROR_SAMPLES);
+    }
+
+    #[tokio::test]
+    async fn preview_should_keep_samples() {
+        let contents = vec![Content::materialize(1)];
+        let reqs = (0..8).map(|i| {
+            let recipient = format!("user{}@acme.org", i);
+            SendRequest::new(
+                "Welcome".into(),
+                "crm@acme.org".into(),
+                &[recipient],
+                &contents,
+            )
+        });
+        let preview = preview(futures::stream::iter(reqs)).await;
+        assert_eq!(preview.audience, 8);
+        assert_eq!(preview.samples.len(), MAX_PREVIEW_SAMPLES);
+    }
+
+    #[tokio::test]
+    async fn stopped_delivery_should_record_sent_recipients() -> anyhow::Result<()> {
+        let (_tdb, svc) = CrmService::new_for_test().await?;
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let mut config = AppConfig::load_for_test()?;
+        config.server.notification = format!("http://{}", listener.local_addr()?);
+        let (accepted_tx, mut accepted) = mpsc::unbounded_channel();
+        tokio::spawn(
+            Server::builder()
+                .add_service(NotificationServer::new(Accepting(accepted_tx)))
+                .serve_with_incoming(TcpListenerStream::new(listener)),
+        );
+        let svc = CrmService::with_pool(config, svc.pool.clone())?;
+        let campaign = Campaign {
+            id: "c1".to_string(),
+            request_id: "r1".to_string(),
+            kind: CampaignKind::Welcome as _,
+            status: CampaignStatus::Running as _,
+            ws_id: 1,
+            ..Default::default()
+        };
+        svc.insert_campaign(&campaign).await?;
+        let pool = svc.pool.clone();
+
+        // the campaign has more users to send to when it is stopped
+        let (tx, rx) = mpsc::channel(8);
+        let (stop, stopped) = watch::channel(false);
+        let delivery = tokio::spawn(async move {
+            let reqs = ReceiverStream::new(rx).boxed();
+            let matched = future::ready((10, None)).boxed();
+            svc.deliver(1, "c1", reqs, matched, stopped).await
+        });
+        let contents = vec![Content::materialize(1)];
+        for i in 0..3 {
+            let recipient = format!("user{}@acme.org", i);
+            let req = SendRequest::new(
+                "Welcome".into(),
+                "crm@acme.org".into(),
+                &[recipient],
+                &contents,
+            );
+            tx.send(req).await?;
+        }
+        for _ in 0..3 {
+            accepted.recv().await;
+        }
+        stop.send_replace(true);
+
+        let summary = delivery.await??;
+        assert_eq!(summary.accepted, 3);
+        assert_eq!(summary.failed, 7);
+        let sql = "SELECT count(*) FROM campaign_recipients WHERE campaign_id = 'c1' \
+            AND status = 'accepted'";
+        let recorded: i64 = sqlx::query_scalar(sql).fetch_one(&pool).await?;
+        assert_eq!(recorded, 3);
+        drop(tx);
+        Ok(())
+    }
+
+    /// a notification service accepting every message, and telling the test which one it
+    /// accepted
+    struct Accepting(mpsc::UnboundedSender<String>);
+
+    #[tonic::async_trait]
+    impl Notification for Accepting {
+        type SendStream = BoxStream<'static, Result<SendResponse, Status>>;
+
+        async fn send(
+            &self,
+            request: Request<Streaming<SendRequest>>,
+        ) -> Result<Response<Self::SendStream>, Status> {
+            let accepted = self.0.clone();
+            let res = request.into_inner().filter_map(move |req| {
+                let res = match req.map(|req| req.msg) {
+                    Ok(Some(Msg::Email(email))) => {
+                        let _ = accepted.send(email.message_id.clone());
+                        Some(Ok(SendResponse {
+                            message_id: email.message_id,
+                            timestamp: None,
+                        }))
+                    }
+                    _ => None,
+                };
+                future::ready(res)
+            });
+            Ok(Response::new(res.boxed()))
+        }
+    }
+}
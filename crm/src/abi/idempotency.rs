@@ -0,0 +1,90 @@
+use tonic::Status;
+use tracing::info;
+
+use crate::{pb::Campaign, CrmService};
+
+impl CrmService {
+    /// the campaign already started by the request id of `def` in its workspace, so a retried
+    /// request is not sent twice. A request that failed before its campaign was recorded can be
+    /// retried with the same id
+    pub(crate) async fn started_campaign(
+        &self,
+        def: &Campaign,
+    ) -> Result<Option<Campaign>, Status> {
+        if def.request_id.is_empty() {
+            return Err(Status::invalid_argument("request id is required"));
+        }
+        let Some(campaign) = self
+            .load_campaign_by_request(def.ws_id, &def.request_id)
+            .await?
+        else {
+            return Ok(None);
+        };
+        if campaign.kind != def.kind {
+            return Err(Status::already_exists(format!(
+                "request id {} was used by another campaign",
+                def.request_id
+            )));
+        }
+        info!(
+            "Request {} already started campaign {}",
+            def.request_id, campaign.id
+        );
+        Ok(Some(self.campaigns.refresh(campaign)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::FutureExt;
+
+    use super::*;
+    use crate::{abi::campaign::Outcome, pb::CampaignKind};
+
+    #[tokio::test]
+    async fn retried_request_should_return_started_campaign() -> anyhow::Result<()> {
+        let (_tdb, svc) = CrmService::new_for_test().await?;
+        let launch = |kind: CampaignKind, ws_id| {
+            let def = Campaign {
+                kind: kind as _,
+                request_id: "r1".to_string(),
+                ws_id,
+                ..Default::default()
+            };
+            svc.launch(def, false, futures::future::pending().boxed())
+        };
+        let Outcome::Started(c1) = launch(CampaignKind::Welcome, 1).await? else {
+            panic!("campaign should be started");
+        };
+
+        // a server without the first campaign in memory finds it in the database
+        svc.campaigns.remove(&c1.id);
+        let Outcome::Started(c2) = launch(CampaignKind::Welcome, 1).await? else {
+            panic!("campaign should be started");
+        };
+        assert_eq!(c2.id, c1.id);
+
+        let ret = launch(CampaignKind::Recall, 1).await;
+        assert_eq!(ret.unwrap_err().code(), tonic::Code::AlreadyExists);
+        // request ids are picked by the callers, so they are only unique within a workspace
+        let Outcome::Started(c3) = launch(CampaignKind::Welcome, 2).await? else {
+            panic!("campaign should be started");
+        };
+        assert_ne!(c3.id, c1.id);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn campaign_without_request_id_should_be_rejected() -> anyhow::Result<()> {
+        let (_tdb, svc) = CrmService::new_for_test().await?;
+        let def = Campaign {
+            kind: CampaignKind::Welcome as _,
+            ..Default::default()
+        };
+        let ret = svc
+            .launch(def, false, futures::future::pending().boxed())
+            .await;
+        assert_eq!(ret.unwrap_err().code(), tonic::Code::InvalidArgument);
+        Ok(())
+    }
+}
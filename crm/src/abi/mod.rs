@@ -1,33 +1,258 @@
-pub mod auth;
-use std::sync::Arc;
+mod backend;
+mod campaign;
+mod delivery;
+mod idempotency;
+mod rate_limit;
+mod records;
+mod schedule;
+use std::{
+    collections::{HashMap, HashSet},
+    future::Future,
+    sync::Arc,
+};
 
-use chrono::{Duration, Utc};
+use chrono::{DateTime, Duration, Utc};
 use crm_metadata::pb::{Content, MaterializeRequest};
 use crm_send::pb::SendRequest;
-use futures::StreamExt;
+use futures::{FutureExt, Stream, StreamExt};
+use prost_types::Timestamp;
 use tokio::sync::mpsc;
 use tokio_stream::wrappers::ReceiverStream;
 use tonic::{Response, Status};
-use tracing::warn;
-use user_stat::QueryRequest;
+use tracing::{warn, Instrument};
+use user_stat::{NotificationChannel, QueryRequest, TimeQuery, User};
+
+pub use backend::{Backend, Readiness};
+pub use campaign::CampaignStore;
+use campaign::Rendered;
+pub use rate_limit::RateLimiter;
+
+/// campaigns are sent as email for now
+const CHANNEL: NotificationChannel = NotificationChannel::Email;
 
 use crate::{
-    pb::{WelcomeRequest, WelcomeResponse},
+    pb::{
+        Campaign, CampaignKind, RecallRequest, RecallResponse, RemindRequest, RemindResponse,
+        WelcomeRequest, WelcomeResponse,
+    },
     CrmService,
 };
 
 impl CrmService {
-    pub async fn welcome(&self, req: WelcomeRequest) -> Result<Response<WelcomeResponse>, Status> {
-        let request_id = req.id;
-        let d1 = Utc::now() - Duration::days(req.interval as _);
+    pub async fn welcome(
+        &self,
+        ws_id: i64,
+        req: WelcomeRequest,
+    ) -> Result<Response<WelcomeResponse>, Status> {
+        let def = Campaign {
+            request_id: req.id.clone(),
+            kind: CampaignKind::Welcome as _,
+            ws_id,
+            interval: req.interval,
+            content_ids: req.content_ids.clone(),
+            ..Default::default()
+        };
+        let render = self
+            .clone()
+            .render_campaign(
+                ws_id,
+                "Welcome",
+                "created_at",
+                req.interval,
+                req.content_ids,
+            )
+            .boxed();
+        let (campaign, preview) = self.launch(def, req.dry_run, render).await?.into_parts();
+
+        Ok(Response::new(WelcomeResponse {
+            id: req.id,
+            preview,
+            campaign,
+        }))
+    }
+
+    pub async fn recall(
+        &self,
+        ws_id: i64,
+        req: RecallRequest,
+    ) -> Result<Response<RecallResponse>, Status> {
+        let def = Campaign {
+            request_id: req.id.clone(),
+            kind: CampaignKind::Recall as _,
+            ws_id,
+            interval: req.last_visit_interval,
+            content_ids: req.content_ids.clone(),
+            ..Default::default()
+        };
+        let render = self
+            .clone()
+            .render_campaign(
+                ws_id,
+                "Recall",
+                "last_visited_at",
+                req.last_visit_interval,
+                req.content_ids,
+            )
+            .boxed();
+        let (campaign, preview) = self.launch(def, req.dry_run, render).await?.into_parts();
+
+        Ok(Response::new(RecallResponse {
+            id: req.id,
+            preview,
+            campaign,
+        }))
+    }
+
+    pub async fn remind(
+        &self,
+        ws_id: i64,
+        req: RemindRequest,
+    ) -> Result<Response<RemindResponse>, Status> {
+        let def = Campaign {
+            request_id: req.id.clone(),
+            kind: CampaignKind::Remind as _,
+            ws_id,
+            interval: req.last_visit_interval,
+            ..Default::default()
+        };
+        let render = self
+            .clone()
+            .render_remind(ws_id, req.last_visit_interval)
+            .boxed();
+        let (campaign, preview) = self.launch(def, req.dry_run, render).await?.into_parts();
+
+        Ok(Response::new(RemindResponse {
+            id: req.id,
+            preview,
+            campaign,
+        }))
+    }
+
+    /// render the same contents for the users of the workspace whose `column` falls
+    /// `interval` days ago
+    async fn render_campaign(
+        self,
+        ws_id: i64,
+        subject: &'static str,
+        column: &'static str,
+        interval: u32,
+        content_ids: Vec<u32>,
+    ) -> Result<Rendered, Status> {
+        let query = self.campaign_query(ws_id, column, interval);
+        let res_user_stats = self.user_stats.clone().query(query).await?.into_inner();
+
+        let contents = self.materialize(&content_ids).await?;
+        let (reqs, matched) = self.render_contents(subject, res_user_stats, contents);
+        Ok((reqs.boxed(), matched.boxed()))
+    }
+
+    /// render each user's own unfinished contents as the users stream in. Contents are
+    /// materialized the first time a user needs them and reused for the next users
+    async fn render_remind(self, ws_id: i64, interval: u32) -> Result<Rendered, Status> {
+        let query = self.campaign_query(ws_id, "last_watched_at", interval);
+        let mut users = self.user_stats.clone().query(query).await?.into_inner();
+
+        let (tx, rx) = mpsc::channel(1024);
+        let sender = self.config.server.sender_email.clone();
+
+        let handle = tokio::spawn(
+            async move {
+                let mut matched = 0;
+                let mut error = None;
+                let mut materialized = HashMap::new();
+                while let Some(user) = users.next().await {
+                    let user = match user {
+                        Ok(user) => user,
+                        Err(e) => {
+                            warn!("Error fetching user: {}", e);
+                            error = Some(e.message().to_string());
+                            break;
+                        }
+                    };
+                    // each content once, in the order the user started them
+                    let mut seen = HashSet::new();
+                    let ids = user
+                        .started_but_not_finished
+                        .iter()
+                        .map(|id| *id as u32)
+                        .filter(|id| seen.insert(*id))
+                        .collect::<Vec<_>>();
+                    if ids.is_empty() {
+                        continue;
+                    }
+
+                    let missing = ids
+                        .iter()
+                        .filter(|id| !materialized.contains_key(*id))
+                        .copied()
+                        .collect::<Vec<_>>();
+                    if !missing.is_empty() {
+                        match self.materialize(&missing).await {
+                            Ok(contents) => materialized
+                                .extend(contents.into_iter().map(|content| (content.id, content))),
+                            Err(e) => {
+                                warn!("Error materializing contents: {}", e);
+                                error = Some(e.message().to_string());
+                                break;
+                            }
+                        }
+                    }
+                    matched += 1;
+
+                    let contents = ids
+                        .iter()
+                        .filter_map(|id| materialized.get(id).cloned())
+                        .collect::<Vec<_>>();
+                    let req = SendRequest::new(
+                        "Remind".to_string(),
+                        sender.clone(),
+                        &[user.email],
+                        &contents,
+                    );
+                    if let Err(e) = tx.send(req).await {
+                        // the campaign was cancelled or its delivery failed
+                        warn!("Error sending email: {}", e);
+                        break;
+                    }
+                }
+                (matched, error)
+            }
+            .in_current_span(),
+        );
+
+        let matched = async move {
+            handle
+                .await
+                .unwrap_or_else(|e| (0, Some(format!("render task failed: {}", e))))
+        };
+        Ok((ReceiverStream::new(rx).boxed(), matched.boxed()))
+    }
+
+    /// users of the workspace whose `name` column falls on the day `interval` days ago,
+    /// leaving out the ones that hit the frequency cap of the campaign channel
+    fn campaign_query(&self, ws_id: i64, name: &str, interval: u32) -> QueryRequest {
+        let now = Utc::now();
+        let d1 = now - Duration::days(interval as _);
         let d2 = d1 + Duration::days(1);
-        let query = QueryRequest::new_with_dt("created_at", d1, d2);
-        let mut res_user_stats = self.user_stats.clone().query(query).await?.into_inner();
+        let mut query = QueryRequest::new_with_dt(name, d1, d2);
+        query.ws_id = ws_id;
 
+        if let (Some(column), Some(cap)) =
+            (CHANNEL.column(), self.config.frequency_cap.get(CHANNEL))
+        {
+            query
+                .timestamps
+                .insert(column.to_string(), TimeQuery::null_or_before(now - cap));
+        }
+        query
+    }
+
+    /// materialize the given content ids through the metadata service
+    async fn materialize(&self, ids: &[u32]) -> Result<Vec<Content>, Status> {
         let contents = self
             .metadata
             .clone()
-            .materialize(MaterializeRequest::new_with_ids(&req.content_ids))
+            .materialize(MaterializeRequest::new_with_ids(ids))
             .await?
             .into_inner();
 
@@ -35,25 +260,55 @@ impl CrmService {
             .filter_map(|v| async move { v.ok() })
             .collect::<Vec<Content>>()
             .await;
+        Ok(contents)
+    }
 
+    /// render the same contents for every user in the stream. The returned future resolves to
+    /// the number of matched users and the error that stopped the user stream, if any
+    fn render_contents(
+        &self,
+        subject: &str,
+        mut users: impl Stream<Item = Result<User, Status>> + Send + Unpin + 'static,
+        contents: Vec<Content>,
+    ) -> (
+        ReceiverStream<SendRequest>,
+        impl Future<Output = (u32, Option<String>)>,
+    ) {
         let contents = Arc::new(contents);
 
         let (tx, rx) = mpsc::channel(1024);
         let sender = self.config.server.sender_email.clone();
+        let subject = subject.to_string();
 
-        tokio::spawn(async move {
-            while let Some(Ok(user)) = res_user_stats.next().await {
-                let contents = contents.clone();
-                let sender = sender.clone();
-                let tx = tx.clone();
+        let handle = tokio::spawn(
+            async move {
+                let mut matched = 0;
+                let mut error = None;
+                while let Some(user) = users.next().await {
+                    let user = match user {
+                        Ok(user) => user,
+                        Err(e) => {
+                            warn!("Error fetching user: {}", e);
+                            error = Some(e.message().to_string());
+                            break;
+                        }
+                    };
+                    matched += 1;
+                    let contents = contents.clone();
+                    let sender = sender.clone();
+                    let tx = tx.clone();
 
-                let req = SendRequest::new("Welcome".to_string(), sender, &[user.email], &contents);
-                if let Err(e) = tx.send(req).await {
-                    warn!("Error sending email: {}", e);
+                    let req = SendRequest::new(subject.clone(), sender, &[user.email], &contents);
+                    if let Err(e) = tx.send(req).await {
+                        // the campaign was cancelled or its delivery failed
+                        warn!("Error sending email: {}", e);
+                        break;
+                    }
                 }
+                (matched, error)
             }
-        });
-        let reqs = ReceiverStream::new(rx);
+            .in_current_span(),
+        );
 
         // NOTE: this is an alternative solution
         // let sender = self.config.server.sender_email.clone();
@@ -66,8 +321,47 @@ impl CrmService {
         //     }
         // });
 
-        self.notification.clone().send(reqs).await?;
+        let matched = async move {
+            handle
+                .await
+                .unwrap_or_else(|e| (0, Some(format!("render task failed: {}", e))))
+        };
+        (ReceiverStream::new(rx), matched)
+    }
+}
+
+fn to_ts(dt: DateTime<Utc>) -> Timestamp {
+    Timestamp {
+        seconds: dt.timestamp(),
+        nanos: dt.timestamp_subsec_nanos() as i32,
+    }
+}
+
+fn from_ts(ts: Timestamp) -> DateTime<Utc> {
+    DateTime::from_timestamp(ts.seconds, ts.nanos as _).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Timelike;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn recall_query_should_match_the_day_of_last_visit() -> anyhow::Result<()> {
+        let (_tdb, svc) = CrmService::new_for_test().await?;
+        let before = Utc::now();
+        let query = svc.campaign_query(1, "last_visited_at", 7);
+        let after = Utc::now();
 
-        Ok(Response::new(WelcomeResponse { id: request_id }))
+        assert_eq!(query.ws_id, 1);
+        let tq = &query.timestamps["last_visited_at"];
+        let lower = from_ts(tq.lower.unwrap());
+        let upper = from_ts(tq.upper.unwrap());
+        assert!(lower >= (before - Duration::days(7)).with_nanosecond(0).unwrap());
+        assert!(lower <= after - Duration::days(7));
+        assert_eq!(upper - lower, Duration::days(1));
+        assert!(!tq.include_null);
+        Ok(())
     }
 }
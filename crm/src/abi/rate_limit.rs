@@ -0,0 +1,197 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use tonic::{service::Interceptor, Request, Status};
+
+use crate::{
+    auth::{Method, User},
+    config::{BucketConfig, RateLimitConfig},
+};
+
+/// buckets kept before the full ones are dropped
+const MAX_BUCKETS: usize = 10_000;
+
+/// token buckets limiting the requests of each user and of each workspace. It runs after the
+/// auth interceptor, requests of services are not limited
+#[derive(Debug, Clone)]
+pub struct RateLimiter {
+    config: Arc<RateLimitConfig>,
+    buckets: Arc<Mutex<HashMap<Key, Bucket>>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Key {
+    User(i64),
+    Workspace(i64),
+}
+
+#[derive(Debug)]
+struct Bucket {
+    tokens: f64,
+    updated: Instant,
+}
+
+impl RateLimiter {
+    pub fn new(config: RateLimitConfig) -> Self {
+        Self {
+            config: Arc::new(config),
+            buckets: Default::default(),
+        }
+    }
+
+    /// take a token from the buckets of the user and of its workspace, or tell how long to
+    /// wait for one. No token is taken unless both buckets have one
+    fn acquire(&self, user: &User, now: Instant) -> Result<(), Duration> {
+        let limits = [
+            (Key::User(user.id), self.config.user.as_ref()),
+            (Key::Workspace(user.ws_id), self.config.workspace.as_ref()),
+        ];
+        let mut buckets = self.buckets.lock().unwrap();
+        if buckets.len() > MAX_BUCKETS {
+            buckets.retain(|key, bucket| {
+                let config = match key {
+                    Key::User(_) => self.config.user.as_ref(),
+                    Key::Workspace(_) => self.config.workspace.as_ref(),
+                };
+                config.is_some_and(|config| bucket.refill(config, now) < config.burst as f64)
+            });
+        }
+
+        let mut wait = Duration::ZERO;
+        for (key, config) in limits {
+            let Some(config) = config else {
+                continue;
+            };
+            let bucket = buckets
+                .entry(key)
+                .or_insert_with(|| Bucket::full(config, now));
+            let tokens = bucket.refill(config, now);
+            if tokens < 1.0 {
+                // a bucket that never refills has no retry time
+                let secs = (1.0 - tokens) / config.rate;
+                wait = wait.max(Duration::try_from_secs_f64(secs).unwrap_or(Duration::MAX));
+            }
+        }
+        if !wait.is_zero() {
+            return Err(wait);
+        }
+
+        for (key, config) in limits {
+            if config.is_some() {
+                if let Some(bucket) = buckets.get_mut(&key) {
+                    bucket.tokens -= 1.0;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn is_limited(&self, method: Option<&Method>) -> bool {
+        self.config.methods.is_empty()
+            || method.is_some_and(|method| self.config.methods.contains(&method.0))
+    }
+}
+
+impl Bucket {
+    fn full(config: &BucketConfig, now: Instant) -> Self {
+        Self {
+            tokens: config.burst as f64,
+            updated: now,
+        }
+    }
+
+    /// add the tokens earned since the last update, up to the burst
+    fn refill(&mut self, config: &BucketConfig, now: Instant) -> f64 {
+        let elapsed = now.saturating_duration_since(self.updated).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * config.rate).min(config.burst as f64);
+        self.updated = now;
+        self.tokens
+    }
+}
+
+impl Interceptor for RateLimiter {
+    fn call(&mut self, request: Request<()>) -> Result<Request<()>, Status> {
+        let Some(user) = request.extensions().get::<User>() else {
+            return Ok(request);
+        };
+        if !self.is_limited(request.extensions().get()) {
+            return Ok(request);
+        }
+        if let Err(wait) = self.acquire(user, Instant::now()) {
+            // whole seconds, as in the retry-after http header
+            let secs = wait
+                .as_secs()
+                .saturating_add(u64::from(wait.subsec_nanos() > 0));
+            let mut status = Status::resource_exhausted(format!(
+                "too many requests, retry after {} seconds",
+                secs
+            ));
+            status
+                .metadata_mut()
+                .insert("retry-after", secs.to_string().parse().unwrap());
+            return Err(status);
+        }
+        Ok(request)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn user(id: i64, ws_id: i64) -> User {
+        User {
+            id,
+            ws_id,
+            fullname: "Tyr Chen".to_string(),
+            email: "tchen@acme.org".to_string(),
+            password_hash: String::new(),
+            created_at: "2024-07-01T00:00:00Z".to_string(),
+            scopes: vec![],
+        }
+    }
+
+    #[test]
+    fn rate_limiter_should_limit_user_and_workspace() {
+        let limiter = RateLimiter::new(RateLimitConfig {
+            user: Some(BucketConfig {
+                rate: 1.0,
+                burst: 2,
+            }),
+            workspace: Some(BucketConfig {
+                rate: 0.5,
+                burst: 3,
+            }),
+            methods: vec![],
+        });
+        let now = Instant::now();
+        let (u1, u2) = (user(1, 1), user(2, 1));
+
+        assert!(limiter.acquire(&u1, now).is_ok());
+        assert!(limiter.acquire(&u1, now).is_ok());
+        assert_eq!(limiter.acquire(&u1, now), Err(Duration::from_secs(1)));
+        // the workspace has one request left
+        assert!(limiter.acquire(&u2, now).is_ok());
+        assert_eq!(limiter.acquire(&u2, now), Err(Duration::from_secs(2)));
+
+        // the user bucket refilled but not the workspace one
+        let later = now + Duration::from_secs(1);
+        assert_eq!(limiter.acquire(&u1, later), Err(Duration::from_secs(1)));
+        let later = now + Duration::from_secs(2);
+        assert!(limiter.acquire(&u1, later).is_ok());
+    }
+
+    #[test]
+    fn rate_limiter_should_only_limit_given_methods() {
+        let limiter = RateLimiter::new(RateLimitConfig {
+            user: None,
+            workspace: None,
+            methods: vec!["Welcome".to_string()],
+        });
+        assert!(limiter.is_limited(Some(&Method("Welcome".to_string()))));
+        assert!(!limiter.is_limited(Some(&Method("GetCampaign".to_string()))));
+    }
+}
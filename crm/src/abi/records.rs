@@ -0,0 +1,504 @@
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+use tonic::Status;
+use tracing::info;
+
+use super::{from_ts, to_ts};
+use crate::{
+    pb::{Campaign, CampaignKind, CampaignStatus, DeliverySummary, ScheduleRun, ScheduleRunStatus},
+    CrmService,
+};
+
+/// max number of rows returned by a list query
+const MAX_LISTED_ROWS: i64 = 1000;
+
+const CAMPAIGN_COLUMNS: &str = "id, ws_id, request_id, kind::text AS kind, \
+    status::text AS status, interval, content_ids, matched, accepted, failed, errors, error, created_at, finished_at";
+const SCHEDULE_RUN_COLUMNS: &str = "schedule, ws_id, kind::text AS kind, status::text AS status, \
+    campaign_id, error, scheduled_at, fired_at";
+
+/// delivery status of a recipient, as stored in the campaign_recipients table
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RecipientStatus {
+    Accepted,
+    Failed,
+}
+
+#[derive(Debug, FromRow)]
+struct CampaignRow {
+    id: String,
+    ws_id: i64,
+    request_id: String,
+    kind: String,
+    status: String,
+    interval: i32,
+    content_ids: Vec<i32>,
+    matched: i32,
+    accepted: i32,
+    failed: i32,
+    errors: Vec<String>,
+    error: Option<String>,
+    created_at: DateTime<Utc>,
+    finished_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, FromRow)]
+struct ScheduleRunRow {
+    schedule: String,
+    ws_id: i64,
+    kind: String,
+    status: String,
+    campaign_id: Option<String>,
+    error: Option<String>,
+    scheduled_at: DateTime<Utc>,
+    fired_at: Option<DateTime<Utc>>,
+}
+
+impl CrmService {
+    /// record a new campaign, false if its request id already started one in the workspace
+    pub(crate) async fn insert_campaign(&self, campaign: &Campaign) -> Result<bool, Status> {
+        let sql = "INSERT INTO campaigns(id, request_id, kind, status, interval, content_ids, \
+            created_at, ws_id) VALUES ($1, $2, $3::campaign_kind, $4::campaign_status, $5, $6, \
+            $7, $8) ON CONFLICT (ws_id, request_id) DO NOTHING";
+        let ret = sqlx::query(sql)
+            .bind(&campaign.id)
+            .bind(&campaign.request_id)
+            .bind(to_db(campaign.kind().as_str_name(), KIND_PREFIX))
+            .bind(to_db(campaign.status().as_str_name(), STATUS_PREFIX))
+            .bind(campaign.interval as i32)
+            .bind(ids_to_db(&campaign.content_ids))
+            .bind(campaign.created_at.map(from_ts).unwrap_or_else(Utc::now))
+            .bind(campaign.ws_id)
+            .execute(&self.pool)
+            .await
+            .map_err(db_error)?;
+        Ok(ret.rows_affected() > 0)
+    }
+
+    /// save the status and the delivery summary of the campaign
+    pub(crate) async fn save_campaign(&self, campaign: &Campaign) -> Result<(), Status> {
+        let summary = campaign.summary.clone().unwrap_or_default();
+        let sql =
+            "UPDATE campaigns SET status = $2::campaign_status, matched = $3, accepted = $4, \
+            failed = $5, errors = $6, error = $7, finished_at = $8 WHERE id = $1";
+        sqlx::query(sql)
+            .bind(&campaign.id)
+            .bind(to_db(campaign.status().as_str_name(), STATUS_PREFIX))
+            .bind(summary.matched as i32)
+            .bind(summary.accepted as i32)
+            .bind(summary.failed as i32)
+            .bind(&summary.errors)
+            .bind((!campaign.error.is_empty()).then_some(&campaign.error))
+            .bind(campaign.finished_at.map(from_ts))
+            .execute(&self.pool)
+            .await
+            .map_err(db_error)?;
+        Ok(())
+    }
+
+    pub(crate) async fn load_campaign(&self, id: &str) -> Result<Option<Campaign>, Status> {
+        let sql = format!("SELECT {} FROM campaigns WHERE id = $1", CAMPAIGN_COLUMNS);
+        let row = sqlx::query_as::<_, CampaignRow>(&sql)
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(db_error)?;
+        Ok(row.map(Into::into))
+    }
+
+    /// the campaign started by the request id in the workspace
+    pub(crate) async fn load_campaign_by_request(
+        &self,
+        ws_id: i64,
+        request_id: &str,
+    ) -> Result<Option<Campaign>, Status> {
+        let sql = format!(
+            "SELECT {} FROM campaigns WHERE ws_id = $1 AND request_id = $2",
+            CAMPAIGN_COLUMNS
+        );
+        let row = sqlx::query_as::<_, CampaignRow>(&sql)
+            .bind(ws_id)
+            .bind(request_id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(db_error)?;
+        Ok(row.map(Into::into))
+    }
+
+    /// campaigns of the workspace in the given status, newest first, all campaigns of the
+    /// workspace if the status is unspecified
+    pub(crate) async fn load_campaigns(
+        &self,
+        ws_id: i64,
+        status: CampaignStatus,
+    ) -> Result<Vec<Campaign>, Status> {
+        let status = (status != CampaignStatus::Unspecified)
+            .then(|| to_db(status.as_str_name(), STATUS_PREFIX));
+        let sql = format!(
+            "SELECT {} FROM campaigns \
+            WHERE ws_id = $1 AND ($2::campaign_status IS NULL OR status = $2::campaign_status) \
+            ORDER BY created_at DESC LIMIT $3",
+            CAMPAIGN_COLUMNS
+        );
+        let rows = sqlx::query_as::<_, CampaignRow>(&sql)
+            .bind(ws_id)
+            .bind(status)
+            .bind(MAX_LISTED_ROWS)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(db_error)?;
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    /// renew the leases of the given running campaigns
+    pub(crate) async fn renew_leases(&self, ids: &[String]) -> Result<(), sqlx::Error> {
+        if ids.is_empty() {
+            return Ok(());
+        }
+        let sql = "UPDATE campaigns SET heartbeat_at = now() WHERE id = ANY($1) \
+            AND status = 'running'";
+        sqlx::query(sql).bind(ids).execute(&self.pool).await?;
+        Ok(())
+    }
+
+    /// running campaigns whose lease expired won't make progress anymore, the server delivering
+    /// them stopped. Those of the other servers are left to them
+    pub(crate) async fn fail_expired_campaigns(
+        &self,
+        lease: std::time::Duration,
+    ) -> Result<(), sqlx::Error> {
+        let sql = "UPDATE campaigns SET status = 'failed', error = 'interrupted, its server \
+            stopped', finished_at = now() WHERE status = 'running' \
+            AND heartbeat_at < now() - make_interval(secs => $1)";
+        let ret = sqlx::query(sql)
+            .bind(lease.as_secs_f64())
+            .execute(&self.pool)
+            .await?;
+        if ret.rows_affected() > 0 {
+            info!(
+                "{} interrupted campaigns marked as failed",
+                ret.rows_affected()
+            );
+        }
+        Ok(())
+    }
+
+    /// record the outcome of the messages sent by a campaign, as (email, message id) pairs
+    pub(crate) async fn insert_recipients(
+        &self,
+        campaign_id: &str,
+        recipients: &[(String, String)],
+        status: RecipientStatus,
+        error: Option<&str>,
+    ) -> Result<(), Status> {
+        if recipients.is_empty() {
+            return Ok(());
+        }
+        let (emails, message_ids): (Vec<_>, Vec<_>) = recipients.iter().cloned().unzip();
+        let status = match status {
+            RecipientStatus::Accepted => "accepted",
+            RecipientStatus::Failed => "failed",
+        };
+        let sql = "INSERT INTO campaign_recipients(campaign_id, email, message_id, status, error) \
+            SELECT $1, email, message_id, $4::delivery_status, $5 \
+            FROM UNNEST($2::varchar[], $3::varchar[]) AS t(email, message_id) \
+            ON CONFLICT DO NOTHING";
+        sqlx::query(sql)
+            .bind(campaign_id)
+            .bind(emails)
+            .bind(message_ids)
+            .bind(status)
+            .bind(error)
+            .execute(&self.pool)
+            .await
+            .map_err(db_error)?;
+        Ok(())
+    }
+
+    /// record a run of a schedule, false if the run was already recorded, e.g. by another server
+    pub(crate) async fn insert_schedule_run(&self, run: &ScheduleRun) -> Result<bool, Status> {
+        let sql = "INSERT INTO schedule_runs(schedule, kind, status, campaign_id, error, \
+            scheduled_at, fired_at, ws_id) VALUES ($1, $2::campaign_kind, \
+            $3::schedule_run_status, $4, $5, $6, $7, $8) \
+            ON CONFLICT (schedule, scheduled_at) DO NOTHING";
+        let ret = sqlx::query(sql)
+            .bind(&run.schedule)
+            .bind(to_db(run.kind().as_str_name(), KIND_PREFIX))
+            .bind(to_db(run.status().as_str_name(), RUN_STATUS_PREFIX))
+            .bind((!run.campaign_id.is_empty()).then_some(&run.campaign_id))
+            .bind((!run.error.is_empty()).then_some(&run.error))
+            .bind(run.scheduled_at.map(from_ts).unwrap_or_else(Utc::now))
+            .bind(run.fired_at.map(from_ts))
+            .bind(run.ws_id)
+            .execute(&self.pool)
+            .await
+            .map_err(db_error)?;
+        Ok(ret.rows_affected() > 0)
+    }
+
+    /// save the outcome of a recorded run of a schedule
+    pub(crate) async fn save_schedule_run(&self, run: &ScheduleRun) -> Result<(), Status> {
+        let sql = "UPDATE schedule_runs SET status = $3::schedule_run_status, campaign_id = $4, \
+            error = $5 WHERE schedule = $1 AND scheduled_at = $2";
+        sqlx::query(sql)
+            .bind(&run.schedule)
+            .bind(run.scheduled_at.map(from_ts).unwrap_or_else(Utc::now))
+            .bind(to_db(run.status().as_str_name(), RUN_STATUS_PREFIX))
+            .bind((!run.campaign_id.is_empty()).then_some(&run.campaign_id))
+            .bind((!run.error.is_empty()).then_some(&run.error))
+            .execute(&self.pool)
+            .await
+            .map_err(db_error)?;
+        Ok(())
+    }
+
+    /// runs of the given schedule of the workspace in the given status, newest first. An empty
+    /// schedule or an unspecified status matches all runs of the workspace
+    pub(crate) async fn load_schedule_runs(
+        &self,
+        ws_id: i64,
+        schedule: &str,
+        status: ScheduleRunStatus,
+    ) -> Result<Vec<ScheduleRun>, Status> {
+        let schedule = (!schedule.is_empty()).then_some(schedule);
+        let status = (status != ScheduleRunStatus::Unspecified)
+            .then(|| to_db(status.as_str_name(), RUN_STATUS_PREFIX));
+        let sql = format!(
+            "SELECT {} FROM schedule_runs WHERE ws_id = $1 \
+            AND ($2::varchar IS NULL OR schedule = $2) \
+            AND ($3::schedule_run_status IS NULL OR status = $3::schedule_run_status) \
+            ORDER BY scheduled_at DESC, id DESC LIMIT $4",
+            SCHEDULE_RUN_COLUMNS
+        );
+        let rows = sqlx::query_as::<_, ScheduleRunRow>(&sql)
+            .bind(ws_id)
+            .bind(schedule)
+            .bind(status)
+            .bind(MAX_LISTED_ROWS)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(db_error)?;
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    /// when the last recorded run of the schedule was due
+    pub(crate) async fn last_scheduled_at(
+        &self,
+        schedule: &str,
+    ) -> Result<Option<DateTime<Utc>>, Status> {
+        let sql = "SELECT max(scheduled_at) FROM schedule_runs WHERE schedule = $1";
+        sqlx::query_scalar(sql)
+            .bind(schedule)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(db_error)
+    }
+}
+
+/// enum values are stored without their prefix, in lowercase, e.g. CAMPAIGN_KIND_WELCOME as
+/// 'welcome'
+const KIND_PREFIX: &str = "CAMPAIGN_KIND_";
+const STATUS_PREFIX: &str = "CAMPAIGN_STATUS_";
+const RUN_STATUS_PREFIX: &str = "SCHEDULE_RUN_STATUS_";
+
+fn to_db(name: &str, prefix: &str) -> String {
+    name.trim_start_matches(prefix).to_lowercase()
+}
+
+fn from_db(value: &str, prefix: &str) -> String {
+    format!("{}{}", prefix, value.to_uppercase())
+}
+
+fn ids_to_db(ids: &[u32]) -> Vec<i32> {
+    ids.iter().map(|id| *id as i32).collect()
+}
+
+fn db_error(e: sqlx::Error) -> Status {
+    Status::internal(format!("database error: {}", e))
+}
+
+impl From<CampaignRow> for Campaign {
+    fn from(row: CampaignRow) -> Self {
+        let kind = CampaignKind::from_str_name(&from_db(&row.kind, KIND_PREFIX));
+        let status = CampaignStatus::from_str_name(&from_db(&row.status, STATUS_PREFIX));
+        Campaign {
+            id: row.id,
+            request_id: row.request_id,
+            kind: kind.unwrap_or_default() as _,
+            status: status.unwrap_or_default() as _,
+            summary: Some(DeliverySummary {
+                matched: row.matched as _,
+                accepted: row.accepted as _,
+                failed: row.failed as _,
+                errors: row.errors,
+            }),
+            error: row.error.unwrap_or_default(),
+            created_at: Some(to_ts(row.created_at)),
+            finished_at: row.finished_at.map(to_ts),
+            interval: row.interval as _,
+            content_ids: row.content_ids.into_iter().map(|id| id as _).collect(),
+            ws_id: row.ws_id,
+        }
+    }
+}
+
+impl From<ScheduleRunRow> for ScheduleRun {
+    fn from(row: ScheduleRunRow) -> Self {
+        let kind = CampaignKind::from_str_name(&from_db(&row.kind, KIND_PREFIX));
+        let status = ScheduleRunStatus::from_str_name(&from_db(&row.status, RUN_STATUS_PREFIX));
+        ScheduleRun {
+            schedule: row.schedule,
+            kind: kind.unwrap_or_default() as _,
+            status: status.unwrap_or_default() as _,
+            campaign_id: row.campaign_id.unwrap_or_default(),
+            error: row.error.unwrap_or_default(),
+            scheduled_at: Some(to_ts(row.scheduled_at)),
+            fired_at: row.fired_at.map(to_ts),
+            ws_id: row.ws_id,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Result;
+    use chrono::{Duration, TimeZone};
+
+    #[tokio::test]
+    async fn campaign_should_be_persisted() -> Result<()> {
+        let (_tdb, svc) = CrmService::new_for_test().await?;
+        let mut campaign = Campaign {
+            id: "c1".to_string(),
+            request_id: "r1".to_string(),
+            kind: CampaignKind::Welcome as _,
+            status: CampaignStatus::Running as _,
+            summary: Some(DeliverySummary::default()),
+            created_at: Some(to_ts(Utc.with_ymd_and_hms(2024, 7, 1, 0, 0, 0).unwrap())),
+            interval: 30,
+            content_ids: vec![1, 2, 3],
+            ws_id: 1,
+            ..Default::default()
+        };
+        svc.insert_campaign(&campaign).await?;
+        svc.insert_recipients(
+            &campaign.id,
+            &[("tyr@acme.org".to_string(), "m1".to_string())],
+            RecipientStatus::Accepted,
+            None,
+        )
+        .await?;
+
+        campaign.set_status(CampaignStatus::Succeeded);
+        campaign.summary = Some(DeliverySummary {
+            matched: 2,
+            accepted: 1,
+            failed: 1,
+            errors: vec!["rejected".to_string()],
+        });
+        campaign.finished_at = Some(to_ts(Utc.with_ymd_and_hms(2024, 7, 1, 0, 1, 0).unwrap()));
+        svc.save_campaign(&campaign).await?;
+
+        assert_eq!(svc.load_campaign("c1").await?, Some(campaign));
+        assert_eq!(
+            svc.load_campaigns(1, CampaignStatus::Succeeded)
+                .await?
+                .len(),
+            1
+        );
+        assert!(svc
+            .load_campaigns(1, CampaignStatus::Running)
+            .await?
+            .is_empty());
+        // campaigns of other workspaces are not listed
+        assert!(svc
+            .load_campaigns(2, CampaignStatus::Unspecified)
+            .await?
+            .is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn schedule_runs_should_be_persisted() -> Result<()> {
+        let (_tdb, svc) = CrmService::new_for_test().await?;
+        let at = Utc.with_ymd_and_hms(2024, 7, 1, 1, 0, 0).unwrap();
+        for (i, status) in [ScheduleRunStatus::Missed, ScheduleRunStatus::Failed]
+            .into_iter()
+            .enumerate()
+        {
+            let run = ScheduleRun {
+                schedule: "daily-welcome".to_string(),
+                kind: CampaignKind::Welcome as _,
+                status: status as _,
+                ws_id: 1,
+                scheduled_at: Some(to_ts(at + Duration::days(i as _))),
+                ..Default::default()
+            };
+            svc.insert_schedule_run(&run).await?;
+        }
+
+        // a run recorded by another server is not recorded again
+        let mut run = ScheduleRun {
+            schedule: "daily-welcome".to_string(),
+            kind: CampaignKind::Welcome as _,
+            status: ScheduleRunStatus::Fired as _,
+            ws_id: 1,
+            scheduled_at: Some(to_ts(at + Duration::days(1))),
+            ..Default::default()
+        };
+        assert!(!svc.insert_schedule_run(&run).await?);
+        run.error = "failed again".to_string();
+        run.set_status(ScheduleRunStatus::Failed);
+        svc.save_schedule_run(&run).await?;
+
+        let runs = svc
+            .load_schedule_runs(1, "daily-welcome", ScheduleRunStatus::Unspecified)
+            .await?;
+        assert_eq!(runs.len(), 2);
+        assert_eq!(runs[0].status(), ScheduleRunStatus::Failed);
+        assert_eq!(runs[0].error, "failed again");
+        assert_eq!(runs[0].ws_id, 1);
+        assert!(svc
+            .load_schedule_runs(2, "", ScheduleRunStatus::Unspecified)
+            .await?
+            .is_empty());
+        assert_eq!(
+            svc.last_scheduled_at("daily-welcome").await?,
+            Some(at + Duration::days(1))
+        );
+        assert_eq!(svc.last_scheduled_at("other").await?, None);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn only_campaigns_with_expired_lease_should_fail() -> Result<()> {
+        let (_tdb, svc) = CrmService::new_for_test().await?;
+        for id in ["live", "expired"] {
+            let campaign = Campaign {
+                id: id.to_string(),
+                request_id: id.to_string(),
+                kind: CampaignKind::Welcome as _,
+                status: CampaignStatus::Running as _,
+                ws_id: 1,
+                ..Default::default()
+            };
+            svc.insert_campaign(&campaign).await?;
+        }
+        // the server running it stopped renewing its lease a while ago
+        sqlx::query("UPDATE campaigns SET heartbeat_at = now() - interval '1 hour'")
+            .execute(&svc.pool)
+            .await?;
+        svc.renew_leases(&["live".to_string()]).await?;
+
+        svc.fail_expired_campaigns(std::time::Duration::from_secs(60))
+            .await?;
+        for (id, status) in [
+            ("live", CampaignStatus::Running),
+            ("expired", CampaignStatus::Failed),
+        ] {
+            let campaign = svc.load_campaign(id).await?.unwrap();
+            assert_eq!(campaign.status(), status, "{}", id);
+        }
+        Ok(())
+    }
+}
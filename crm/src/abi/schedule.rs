@@ -0,0 +1,260 @@
+use std::str::FromStr;
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Duration, Utc};
+use chrono_tz::Tz;
+use cron::Schedule;
+use tokio::time::sleep;
+use tonic::{Response, Status};
+use tracing::{info, info_span, warn, Instrument};
+
+use super::to_ts;
+use crate::{
+    config::{ScheduleConfig, ScheduleKind},
+    pb::{
+        CampaignKind, ListScheduleRunsResponse, RecallRequest, RemindRequest, ScheduleRun,
+        ScheduleRunStatus, WelcomeRequest,
+    },
+    CrmService,
+};
+
+/// how late a run may fire before it counts as missed
+const MISSED_AFTER_SECS: i64 = 60;
+
+impl CrmService {
+    /// fire the campaigns scheduled in the config, in the background
+    pub fn start_scheduler(&self) -> Result<()> {
+        let mut schedules = Vec::with_capacity(self.config.schedules.len());
+        for config in &self.config.schedules {
+            let schedule = Schedule::from_str(&config.cron)
+                .with_context(|| format!("invalid cron expression of schedule {}", config.name))?;
+            schedules.push((config.clone(), schedule));
+        }
+
+        for (config, schedule) in schedules {
+            info!(
+                "Schedule {} runs {:?} on \"{}\" ({})",
+                config.name, config.kind, config.cron, config.timezone
+            );
+            tokio::spawn(self.clone().run_schedule(config, schedule));
+        }
+        Ok(())
+    }
+
+    /// fire the runs of a schedule as they are due. Runs due while the server was down are
+    /// caught up from the last recorded run
+    async fn run_schedule(self, config: ScheduleConfig, schedule: Schedule) {
+        let mut last = match self.last_scheduled_at(&config.name).await {
+            Ok(Some(last)) => last,
+            Ok(None) => Utc::now(),
+            Err(e) => {
+                warn!("Failed to load last run of schedule {}: {}", config.name, e);
+                Utc::now()
+            }
+        };
+        loop {
+            let Some(next) = schedule.after(&last.with_timezone(&config.timezone)).next() else {
+                info!("Schedule {} has no upcoming run", config.name);
+                return;
+            };
+            if let Ok(wait) = (next.with_timezone(&Utc) - Utc::now()).to_std() {
+                sleep(wait).await;
+            }
+            if self.campaigns.is_closed() {
+                // the run is caught up as missed by the next server
+                info!("Schedule {} stopped", config.name);
+                return;
+            }
+
+            let now = Utc::now();
+            let (missed, due) = due_runs(&schedule, config.timezone, last, now);
+            for scheduled_at in missed {
+                warn!(
+                    "Schedule {} missed its run at {}",
+                    config.name, scheduled_at
+                );
+                self.record_run(ScheduleRun {
+                    schedule: config.name.clone(),
+                    kind: CampaignKind::from(config.kind) as _,
+                    status: ScheduleRunStatus::Missed as _,
+                    ws_id: config.ws_id,
+                    scheduled_at: Some(to_ts(scheduled_at)),
+                    ..Default::default()
+                })
+                .await;
+            }
+            if let Some(scheduled_at) = due {
+                // each run is the root of its own trace
+                let span = info_span!(parent: None, "schedule run", schedule = %config.name);
+                self.fire(&config, scheduled_at).instrument(span).await;
+            }
+            last = now;
+        }
+    }
+
+    /// start the campaign of a schedule run. The run is recorded first, so that only the server
+    /// recording it fires it when several servers share the schedule. The request id is derived
+    /// from the run, so that the same run never starts two campaigns
+    async fn fire(&self, config: &ScheduleConfig, scheduled_at: DateTime<Utc>) {
+        let mut run = ScheduleRun {
+            schedule: config.name.clone(),
+            kind: CampaignKind::from(config.kind) as _,
+            status: ScheduleRunStatus::Fired as _,
+            ws_id: config.ws_id,
+            scheduled_at: Some(to_ts(scheduled_at)),
+            fired_at: Some(to_ts(Utc::now())),
+            ..Default::default()
+        };
+        match self.insert_schedule_run(&run).await {
+            Ok(true) => {}
+            Ok(false) => {
+                info!(
+                    "Schedule {} run at {} was fired by another server",
+                    config.name, scheduled_at
+                );
+                return;
+            }
+            Err(e) => {
+                warn!(
+                    "Schedule {} skipped its run at {}, it can't be recorded: {}",
+                    config.name, scheduled_at, e
+                );
+                return;
+            }
+        }
+
+        let id = format!("schedule:{}:{}", config.name, scheduled_at.timestamp());
+        let ret = match config.kind {
+            ScheduleKind::Welcome => {
+                let req = WelcomeRequest {
+                    id: id.clone(),
+                    interval: config.interval,
+                    content_ids: config.content_ids.clone(),
+                    dry_run: false,
+                };
+                let res = self.welcome(config.ws_id, req).await;
+                res.map(|res| res.into_inner().campaign)
+            }
+            ScheduleKind::Recall => {
+                let req = RecallRequest {
+                    id: id.clone(),
+                    last_visit_interval: config.interval,
+                    content_ids: config.content_ids.clone(),
+                    dry_run: false,
+                };
+                let res = self.recall(config.ws_id, req).await;
+                res.map(|res| res.into_inner().campaign)
+            }
+            ScheduleKind::Remind => {
+                let req = RemindRequest {
+                    id: id.clone(),
+                    last_visit_interval: config.interval,
+                    dry_run: false,
+                };
+                let res = self.remind(config.ws_id, req).await;
+                res.map(|res| res.into_inner().campaign)
+            }
+        };
+
+        match ret {
+            Ok(campaign) => {
+                info!("Schedule {} fired its run at {}", config.name, scheduled_at);
+                run.campaign_id = campaign.map(|c| c.id).unwrap_or_default();
+            }
+            Err(e) => {
+                warn!(
+                    "Schedule {} failed its run at {}: {}",
+                    config.name, scheduled_at, e
+                );
+                run.set_status(ScheduleRunStatus::Failed);
+                run.error = e.message().to_string();
+            }
+        }
+        if let Err(e) = self.save_schedule_run(&run).await {
+            warn!("Failed to save run of schedule {}: {}", run.schedule, e);
+        }
+    }
+
+    pub async fn list_schedule_runs(
+        &self,
+        ws_id: i64,
+        schedule: &str,
+        status: ScheduleRunStatus,
+    ) -> Result<Response<ListScheduleRunsResponse>, Status> {
+        let runs = self.load_schedule_runs(ws_id, schedule, status).await?;
+        Ok(Response::new(ListScheduleRunsResponse { runs }))
+    }
+
+    /// record a run, unless another server already recorded it
+    async fn record_run(&self, run: ScheduleRun) {
+        if let Err(e) = self.insert_schedule_run(&run).await {
+            warn!("Failed to record run of schedule {}: {}", run.schedule, e);
+        }
+    }
+}
+
+impl From<ScheduleKind> for CampaignKind {
+    fn from(kind: ScheduleKind) -> Self {
+        match kind {
+            ScheduleKind::Welcome => CampaignKind::Welcome,
+            ScheduleKind::Recall => CampaignKind::Recall,
+            ScheduleKind::Remind => CampaignKind::Remind,
+        }
+    }
+}
+
+/// the runs due in (last, now]: the latest one fires unless it is too late, the others were
+/// missed while the scheduler was behind
+fn due_runs(
+    schedule: &Schedule,
+    tz: Tz,
+    last: DateTime<Utc>,
+    now: DateTime<Utc>,
+) -> (Vec<DateTime<Utc>>, Option<DateTime<Utc>>) {
+    let mut missed = schedule
+        .after(&last.with_timezone(&tz))
+        .map(|at| at.with_timezone(&Utc))
+        .take_while(|at| *at <= now)
+        .collect::<Vec<_>>();
+    match missed.last() {
+        Some(at) if now - *at <= Duration::seconds(MISSED_AFTER_SECS) => {
+            let due = missed.pop();
+            (missed, due)
+        }
+        _ => (missed, None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn due_runs_should_fire_latest_and_miss_others() {
+        // every day at 9am in Shanghai, i.e. 1am UTC
+        let schedule = Schedule::from_str("0 0 9 * * *").unwrap();
+        let tz = chrono_tz::Asia::Shanghai;
+        let last = Utc.with_ymd_and_hms(2024, 7, 1, 0, 0, 0).unwrap();
+
+        let now = Utc.with_ymd_and_hms(2024, 7, 3, 1, 0, 10).unwrap();
+        let (missed, due) = due_runs(&schedule, tz, last, now);
+        assert_eq!(
+            missed,
+            vec![
+                Utc.with_ymd_and_hms(2024, 7, 1, 1, 0, 0).unwrap(),
+                Utc.with_ymd_and_hms(2024, 7, 2, 1, 0, 0).unwrap(),
+            ]
+        );
+        assert_eq!(
+            due,
+            Some(Utc.with_ymd_and_hms(2024, 7, 3, 1, 0, 0).unwrap())
+        );
+
+        // too late to fire
+        let now = Utc.with_ymd_and_hms(2024, 7, 1, 2, 0, 0).unwrap();
+        let (missed, due) = due_runs(&schedule, tz, last, now);
+        assert_eq!(missed.len(), 1);
+        assert_eq!(due, None);
+    }
+}
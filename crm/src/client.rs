@@ -1,31 +1,60 @@
-use anyhow::Result;
-use crm::pb::{crm_client::CrmClient, WelcomeRequestBuilder};
+use std::env;
+
+use anyhow::{bail, Result};
+use crm::{
+    auth::{Pem, ServiceToken},
+    pb::{crm_client::CrmClient, WelcomeRequestBuilder},
+};
 use tonic::{
-    metadata::MetadataValue,
     transport::{Certificate, Channel, ClientTlsConfig},
     Request,
 };
 use uuid::Uuid;
 
+const USAGE: &str = "Usage: client [--url <crm url>] [--ca <CA PEM or file>] \
+[--domain <name in the server certificate>]
+
+Starts a welcome campaign. The url defaults to http://[::1]:50000, an https url is verified \
+against --ca, and against the host of the url unless --domain is given";
+
 #[tokio::main]
 async fn main() -> Result<()> {
-    let pem = include_str!("../../fixtures/rootCA.pem");
-    let tls = ClientTlsConfig::new()
-        .ca_certificate(Certificate::from_pem(pem))
-        .domain_name("localhost");
-
-    let channel = Channel::from_static("http://[::1]:50000")
-        .tls_config(tls)?
-        .connect()
-        .await?;
-
-    let token = include_str!("../../fixtures/token");
-    let token: MetadataValue<_> = format!("Bearer {}", token).parse()?;
-
-    let mut client = CrmClient::with_interceptor(channel, move |mut req: Request<()>| {
-        req.metadata_mut().insert("authorization", token.clone());
-        Ok(req)
-    });
+    let mut url = "http://[::1]:50000".to_string();
+    let mut ca = None;
+    let mut domain = None;
+
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        if arg == "-h" || arg == "--help" {
+            println!("{}", USAGE);
+            return Ok(());
+        }
+        let Some(value) = args.next() else {
+            bail!("missing value of {}\n\n{}", arg, USAGE);
+        };
+        match arg.as_str() {
+            "--url" => url = value,
+            "--ca" => ca = Some(Pem(value)),
+            "--domain" => domain = Some(value),
+            _ => bail!("unknown argument {}\n\n{}", arg, USAGE),
+        }
+    }
+
+    let mut endpoint = Channel::from_shared(url)?;
+    if ca.is_some() || domain.is_some() {
+        let mut tls = ClientTlsConfig::new();
+        if let Some(ca) = ca {
+            tls = tls.ca_certificate(Certificate::from_pem(ca.load()?));
+        }
+        if let Some(domain) = domain {
+            tls = tls.domain_name(domain);
+        }
+        endpoint = endpoint.tls_config(tls)?;
+    }
+    let channel = endpoint.connect().await?;
+
+    let token = ServiceToken::new(Some(include_str!("../../fixtures/token").trim()))?;
+    let mut client = CrmClient::with_interceptor(channel, token);
 
     let req = WelcomeRequestBuilder::default()
         .id(Uuid::new_v4().to_string())
@@ -1,46 +1,281 @@
-use anyhow::{bail, Result};
+use anyhow::Result;
+use chrono::Duration;
+use chrono_tz::Tz;
+use crm_auth::{AuthConfig, ClientTls};
+use crm_bootstrap::{Config, TelemetryConfig};
+use crm_config::{Errors, Source, Validate};
+use cron::Schedule;
 use serde::{Deserialize, Serialize};
-use std::{env, fs::File};
+use std::str::FromStr;
+use tonic::transport::{ClientTlsConfig, Endpoint};
+use user_stat::NotificationChannel;
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct AppConfig {
     pub server: ServerConfig,
     pub auth: AuthConfig,
+    #[serde(flatten)]
+    pub telemetry: TelemetryConfig,
+    #[serde(default)]
+    pub frequency_cap: FrequencyCapConfig,
+    #[serde(default)]
+    pub schedules: Vec<ScheduleConfig>,
+    #[serde(default)]
+    pub rate_limit: RateLimitConfig,
+    #[serde(default)]
+    pub backend: BackendConfig,
 }
-#[derive(Debug, Serialize, Deserialize)]
-pub struct TlsConfig {
-    pub cert: String,
-    pub key: String,
+
+/// min hours between two notifications to the same user, per channel. Campaigns are only sent
+/// as email, so the other channels are rejected rather than ignored
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct FrequencyCapConfig {
+    pub email: Option<u32>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
-pub struct AuthConfig {
-    pub pk: String,
+/// token buckets limiting the requests of each user and of each workspace, no limit if unset
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RateLimitConfig {
+    pub user: Option<BucketConfig>,
+    pub workspace: Option<BucketConfig>,
+    /// methods limited, e.g. Welcome, all of them if empty
+    #[serde(default)]
+    pub methods: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BucketConfig {
+    /// requests per second
+    pub rate: f64,
+    /// requests allowed at once
+    pub burst: u32,
+}
+
+/// how crm talks to user-stat, crm-metadata and crm-send, in milliseconds. They are connected on
+/// first use, so crm starts whether they are up or not
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BackendConfig {
+    pub connect_timeout: u64,
+    /// deadline of each request, until the response headers for streams
+    pub request_timeout: u64,
+    /// wait before reconnecting to a backend that failed, doubled on each failure up to
+    /// max_backoff
+    pub min_backoff: u64,
+    pub max_backoff: u64,
+    /// interval between two probes of a ready backend
+    pub probe_interval: u64,
+    pub tls: BackendTls,
+}
+
+/// TLS of each backend over https, a backend without it is verified with the CA of the server
+/// TLS
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct BackendTls {
+    pub user_stats: Option<ClientTls>,
+    pub metadata: Option<ClientTls>,
+    pub notification: Option<ClientTls>,
+}
+
+/// a campaign fired by the server on a cron schedule
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScheduleConfig {
+    pub name: String,
+    /// workspace whose users the campaign is sent to
+    pub ws_id: i64,
+    pub kind: ScheduleKind,
+    /// days since the user registered for welcome, since the last visit for recall and remind
+    pub interval: u32,
+    /// contents to send, remind sends each user's unfinished contents instead
+    #[serde(default)]
+    pub content_ids: Vec<u32>,
+    /// cron expression with seconds, e.g. "0 0 9 * * *" for every day at 9am
+    pub cron: String,
+    #[serde(default = "default_timezone")]
+    pub timezone: Tz,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ScheduleKind {
+    Welcome,
+    Recall,
+    Remind,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ServerConfig {
-    pub port: u16,
+    #[serde(flatten)]
+    pub base: crm_bootstrap::ServerConfig,
+    pub db_url: String,
     pub sender_email: String,
     pub metadata: String,
     pub user_stats: String,
     pub notification: String,
-    pub tls: Option<TlsConfig>,
+    /// token crm authenticates with to the services above
+    #[serde(default)]
+    pub service_token: Option<String>,
 }
 
-impl AppConfig {
-    pub fn load() -> Result<Self> {
-        // read from  ./app.yml, or /etc/config/app.yml, or from env CHAT_CONFIG
-        let ret = match (
-            File::open("crm.yml"),
-            File::open("/etc/config/crm.yml"),
-            env::var("CRM_CONFIG"),
-        ) {
-            (Ok(reader), _, _) => serde_yaml::from_reader(reader),
-            (_, Ok(reader), _) => serde_yaml::from_reader(reader),
-            (_, _, Ok(path)) => serde_yaml::from_reader(File::open(path)?),
-            _ => bail!("Config file not found"),
+impl FrequencyCapConfig {
+    pub fn get(&self, channel: NotificationChannel) -> Option<Duration> {
+        let hours = match channel {
+            NotificationChannel::Email => self.email,
+            _ => None,
         };
-        Ok(ret?)
+        hours.map(|h| Duration::hours(h as _))
+    }
+}
+
+impl Default for BackendConfig {
+    fn default() -> Self {
+        Self {
+            connect_timeout: 3_000,
+            request_timeout: 30_000,
+            min_backoff: 100,
+            max_backoff: 30_000,
+            probe_interval: 10_000,
+            tls: BackendTls::default(),
+        }
+    }
+}
+
+fn default_timezone() -> Tz {
+    Tz::UTC
+}
+
+impl AppConfig {
+    /// TLS of the connections to a backend, its own or the CA of the server TLS
+    pub fn backend_tls(&self, own: Option<&ClientTls>) -> Result<Option<ClientTlsConfig>> {
+        match (own, &self.server.base.tls) {
+            (Some(own), _) => Ok(Some(own.client_config()?)),
+            (None, Some(tls)) => Ok(Some(tls.client_config()?)),
+            (None, None) => Ok(None),
+        }
+    }
+
+    /// the config of the tests, with a token for the backends
+    #[cfg(feature = "test_utils")]
+    pub fn load_for_test() -> Result<Self> {
+        let test = (
+            "CRM_SERVER__SERVICE_TOKEN".to_string(),
+            "crm-test-token".to_string(),
+        );
+        crm_config::load_from(&Self::SOURCE, std::env::vars().chain([test]))
+    }
+}
+
+impl Config for AppConfig {
+    /// read from ./crm.yml or /etc/config/crm.yml, then from the file in CRM_CONFIG, and override
+    /// single fields with env vars like CRM_SERVER__PORT
+    const SOURCE: Source = Source {
+        file: "crm.yml",
+        prefix: "CRM",
+    };
+
+    fn server(&self) -> &crm_bootstrap::ServerConfig {
+        &self.server.base
+    }
+
+    fn telemetry(&self) -> &TelemetryConfig {
+        &self.telemetry
+    }
+}
+
+impl Validate for AppConfig {
+    fn validate(&self, errors: &mut Errors) {
+        errors.field("server", &self.server);
+        errors.field("auth", &self.auth);
+        if self.server.service_token.is_none() {
+            errors.add(
+                "server.service_token",
+                "must be set with CRM_SERVER__SERVICE_TOKEN",
+            );
+        }
+        self.telemetry.validate(errors);
+        errors.field("schedules", &self.schedules);
+        for (i, schedule) in self.schedules.iter().enumerate() {
+            if self.schedules[..i].iter().any(|s| s.name == schedule.name) {
+                errors.add(&format!("schedules[{}].name", i), "is not unique");
+            }
+        }
+        errors.field("rate_limit", &self.rate_limit);
+        errors.field("backend", &self.backend);
+    }
+}
+
+impl Validate for ServerConfig {
+    fn validate(&self, errors: &mut Errors) {
+        self.base.validate(errors);
+        if self.db_url.is_empty() {
+            errors.add("db_url", "must not be empty");
+        }
+        for (name, url) in [
+            ("metadata", &self.metadata),
+            ("user_stats", &self.user_stats),
+            ("notification", &self.notification),
+        ] {
+            if let Err(e) = Endpoint::from_shared(url.clone()) {
+                errors.add(name, e);
+            }
+        }
+    }
+}
+
+impl Validate for ScheduleConfig {
+    fn validate(&self, errors: &mut Errors) {
+        if let Err(e) = Schedule::from_str(&self.cron) {
+            errors.add("cron", e);
+        }
+        if self.ws_id <= 0 {
+            errors.add("ws_id", "must be a workspace id");
+        }
+    }
+}
+
+impl Validate for RateLimitConfig {
+    fn validate(&self, errors: &mut Errors) {
+        errors.field("user", &self.user);
+        errors.field("workspace", &self.workspace);
+    }
+}
+
+impl Validate for BucketConfig {
+    fn validate(&self, errors: &mut Errors) {
+        if !(self.rate.is_finite() && self.rate > 0.0) {
+            errors.add("rate", "must be a positive number");
+        }
+        if self.burst == 0 {
+            errors.add("burst", "must be at least 1");
+        }
+    }
+}
+
+impl Validate for BackendConfig {
+    fn validate(&self, errors: &mut Errors) {
+        for (name, ms) in [
+            ("connect_timeout", self.connect_timeout),
+            ("request_timeout", self.request_timeout),
+            ("min_backoff", self.min_backoff),
+            ("probe_interval", self.probe_interval),
+        ] {
+            if ms == 0 {
+                errors.add(name, "must not be 0");
+            }
+        }
+        if self.max_backoff < self.min_backoff {
+            errors.add("max_backoff", "must not be less than min_backoff");
+        }
+        errors.field("tls", &self.tls);
+    }
+}
+
+impl Validate for BackendTls {
+    fn validate(&self, errors: &mut Errors) {
+        errors.field("user_stats", &self.user_stats);
+        errors.field("metadata", &self.metadata);
+        errors.field("notification", &self.notification);
     }
 }
@@ -11,12 +11,20 @@ pub struct WelcomeRequest {
     #[prost(uint32, repeated, tag = "3")]
     #[builder(setter(each(name = "content_id", into)))]
     pub content_ids: ::prost::alloc::vec::Vec<u32>,
+    /// render the campaign without sending it
+    #[prost(bool, tag = "4")]
+    pub dry_run: bool,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct WelcomeResponse {
     #[prost(string, tag = "1")]
     pub id: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "2")]
+    pub preview: ::core::option::Option<CampaignPreview>,
+    /// the campaign started in the background, not set in dry-run mode
+    #[prost(message, optional, tag = "3")]
+    pub campaign: ::core::option::Option<Campaign>,
 }
 #[derive(derive_builder::Builder)]
 #[builder(setter(into, strip_option), default)]
@@ -29,12 +37,20 @@ pub struct RecallRequest {
     pub last_visit_interval: u32,
     #[prost(uint32, repeated, tag = "3")]
     pub content_ids: ::prost::alloc::vec::Vec<u32>,
+    /// render the campaign without sending it
+    #[prost(bool, tag = "4")]
+    pub dry_run: bool,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct RecallResponse {
     #[prost(string, tag = "1")]
     pub id: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "2")]
+    pub preview: ::core::option::Option<CampaignPreview>,
+    /// the campaign started in the background, not set in dry-run mode
+    #[prost(message, optional, tag = "3")]
+    pub campaign: ::core::option::Option<Campaign>,
 }
 #[derive(derive_builder::Builder)]
 #[builder(setter(into, strip_option), default)]
@@ -45,12 +61,250 @@ pub struct RemindRequest {
     pub id: ::prost::alloc::string::String,
     #[prost(uint32, tag = "2")]
     pub last_visit_interval: u32,
+    /// render the campaign without sending it
+    #[prost(bool, tag = "3")]
+    pub dry_run: bool,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct RemindResponse {
     #[prost(string, tag = "1")]
     pub id: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "2")]
+    pub preview: ::core::option::Option<CampaignPreview>,
+    /// the campaign started in the background, not set in dry-run mode
+    #[prost(message, optional, tag = "3")]
+    pub campaign: ::core::option::Option<Campaign>,
+}
+/// how a campaign was delivered to the notification service
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DeliverySummary {
+    /// number of users matched by the campaign
+    #[prost(uint32, tag = "1")]
+    pub matched: u32,
+    /// number of messages accepted by the notification service
+    #[prost(uint32, tag = "2")]
+    pub accepted: u32,
+    /// number of messages rejected so far, then of the matched users whose message was not
+    /// accepted once the campaign is finished
+    #[prost(uint32, tag = "3")]
+    pub failed: u32,
+    /// a sample of the failure reasons
+    #[prost(string, repeated, tag = "4")]
+    pub errors: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+/// what a campaign would send, returned in dry-run mode
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CampaignPreview {
+    /// number of users the campaign would be sent to
+    #[prost(uint32, tag = "1")]
+    pub audience: u32,
+    /// a sample of the rendered messages
+    #[prost(message, repeated, tag = "2")]
+    pub samples: ::prost::alloc::vec::Vec<::crm_send::pb::SendRequest>,
+}
+/// a campaign running or ran in the background
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Campaign {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// id of the request that started the campaign
+    #[prost(string, tag = "2")]
+    pub request_id: ::prost::alloc::string::String,
+    #[prost(enumeration = "CampaignKind", tag = "3")]
+    pub kind: i32,
+    #[prost(enumeration = "CampaignStatus", tag = "4")]
+    pub status: i32,
+    /// delivery progress, final once the campaign is no longer running
+    #[prost(message, optional, tag = "5")]
+    pub summary: ::core::option::Option<DeliverySummary>,
+    /// why the campaign failed
+    #[prost(string, tag = "6")]
+    pub error: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "7")]
+    pub created_at: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(message, optional, tag = "8")]
+    pub finished_at: ::core::option::Option<::prost_types::Timestamp>,
+    /// interval of the campaign request
+    #[prost(uint32, tag = "9")]
+    pub interval: u32,
+    /// contents of the campaign request, empty for remind
+    #[prost(uint32, repeated, tag = "10")]
+    pub content_ids: ::prost::alloc::vec::Vec<u32>,
+    /// workspace the campaign belongs to
+    #[prost(int64, tag = "11")]
+    pub ws_id: i64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetCampaignRequest {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListCampaignsRequest {
+    /// only list campaigns in this status, all campaigns if unspecified
+    #[prost(enumeration = "CampaignStatus", tag = "1")]
+    pub status: i32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListCampaignsResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub campaigns: ::prost::alloc::vec::Vec<Campaign>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CancelCampaignRequest {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+}
+/// a run of a campaign scheduled in the crm config
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ScheduleRun {
+    /// name of the schedule in the config
+    #[prost(string, tag = "1")]
+    pub schedule: ::prost::alloc::string::String,
+    #[prost(enumeration = "CampaignKind", tag = "2")]
+    pub kind: i32,
+    #[prost(enumeration = "ScheduleRunStatus", tag = "3")]
+    pub status: i32,
+    /// id of the campaign started by the run
+    #[prost(string, tag = "4")]
+    pub campaign_id: ::prost::alloc::string::String,
+    /// why the campaign could not be started
+    #[prost(string, tag = "5")]
+    pub error: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "6")]
+    pub scheduled_at: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(message, optional, tag = "7")]
+    pub fired_at: ::core::option::Option<::prost_types::Timestamp>,
+    /// workspace the schedule belongs to
+    #[prost(int64, tag = "8")]
+    pub ws_id: i64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListScheduleRunsRequest {
+    /// only list runs of this schedule, all runs if empty
+    #[prost(string, tag = "1")]
+    pub schedule: ::prost::alloc::string::String,
+    /// only list runs in this status, all runs if unspecified
+    #[prost(enumeration = "ScheduleRunStatus", tag = "2")]
+    pub status: i32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListScheduleRunsResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub runs: ::prost::alloc::vec::Vec<ScheduleRun>,
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum CampaignKind {
+    Unspecified = 0,
+    Welcome = 1,
+    Recall = 2,
+    Remind = 3,
+}
+impl CampaignKind {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            CampaignKind::Unspecified => "CAMPAIGN_KIND_UNSPECIFIED",
+            CampaignKind::Welcome => "CAMPAIGN_KIND_WELCOME",
+            CampaignKind::Recall => "CAMPAIGN_KIND_RECALL",
+            CampaignKind::Remind => "CAMPAIGN_KIND_REMIND",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "CAMPAIGN_KIND_UNSPECIFIED" => Some(Self::Unspecified),
+            "CAMPAIGN_KIND_WELCOME" => Some(Self::Welcome),
+            "CAMPAIGN_KIND_RECALL" => Some(Self::Recall),
+            "CAMPAIGN_KIND_REMIND" => Some(Self::Remind),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum CampaignStatus {
+    Unspecified = 0,
+    Running = 1,
+    Succeeded = 2,
+    Failed = 3,
+    Cancelled = 4,
+}
+impl CampaignStatus {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            CampaignStatus::Unspecified => "CAMPAIGN_STATUS_UNSPECIFIED",
+            CampaignStatus::Running => "CAMPAIGN_STATUS_RUNNING",
+            CampaignStatus::Succeeded => "CAMPAIGN_STATUS_SUCCEEDED",
+            CampaignStatus::Failed => "CAMPAIGN_STATUS_FAILED",
+            CampaignStatus::Cancelled => "CAMPAIGN_STATUS_CANCELLED",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "CAMPAIGN_STATUS_UNSPECIFIED" => Some(Self::Unspecified),
+            "CAMPAIGN_STATUS_RUNNING" => Some(Self::Running),
+            "CAMPAIGN_STATUS_SUCCEEDED" => Some(Self::Succeeded),
+            "CAMPAIGN_STATUS_FAILED" => Some(Self::Failed),
+            "CAMPAIGN_STATUS_CANCELLED" => Some(Self::Cancelled),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ScheduleRunStatus {
+    Unspecified = 0,
+    /// the campaign was started
+    Fired = 1,
+    /// the campaign could not be started
+    Failed = 2,
+    /// the server was down or behind when the run was due
+    Missed = 3,
+}
+impl ScheduleRunStatus {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            ScheduleRunStatus::Unspecified => "SCHEDULE_RUN_STATUS_UNSPECIFIED",
+            ScheduleRunStatus::Fired => "SCHEDULE_RUN_STATUS_FIRED",
+            ScheduleRunStatus::Failed => "SCHEDULE_RUN_STATUS_FAILED",
+            ScheduleRunStatus::Missed => "SCHEDULE_RUN_STATUS_MISSED",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "SCHEDULE_RUN_STATUS_UNSPECIFIED" => Some(Self::Unspecified),
+            "SCHEDULE_RUN_STATUS_FIRED" => Some(Self::Fired),
+            "SCHEDULE_RUN_STATUS_FAILED" => Some(Self::Failed),
+            "SCHEDULE_RUN_STATUS_MISSED" => Some(Self::Missed),
+            _ => None,
+        }
+    }
 }
 /// Generated client implementations.
 pub mod crm_client {
@@ -187,6 +441,80 @@ pub mod crm_client {
                 .insert(GrpcMethod::new("crm.Crm", "Remind"));
             self.inner.unary(req, path, codec).await
         }
+        /// get a campaign with its delivery progress
+        pub async fn get_campaign(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetCampaignRequest>,
+        ) -> std::result::Result<tonic::Response<super::Campaign>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/crm.Crm/GetCampaign");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("crm.Crm", "GetCampaign"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// list campaigns, newest first
+        pub async fn list_campaigns(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListCampaignsRequest>,
+        ) -> std::result::Result<tonic::Response<super::ListCampaignsResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/crm.Crm/ListCampaigns");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("crm.Crm", "ListCampaigns"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// stop a running campaign
+        pub async fn cancel_campaign(
+            &mut self,
+            request: impl tonic::IntoRequest<super::CancelCampaignRequest>,
+        ) -> std::result::Result<tonic::Response<super::Campaign>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/crm.Crm/CancelCampaign");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("crm.Crm", "CancelCampaign"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// list runs of the scheduled campaigns, newest first
+        pub async fn list_schedule_runs(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListScheduleRunsRequest>,
+        ) -> std::result::Result<tonic::Response<super::ListScheduleRunsResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/crm.Crm/ListScheduleRuns");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("crm.Crm", "ListScheduleRuns"));
+            self.inner.unary(req, path, codec).await
+        }
     }
 }
 /// Generated server implementations.
@@ -211,6 +539,26 @@ pub mod crm_server {
             &self,
             request: tonic::Request<super::RemindRequest>,
         ) -> std::result::Result<tonic::Response<super::RemindResponse>, tonic::Status>;
+        /// get a campaign with its delivery progress
+        async fn get_campaign(
+            &self,
+            request: tonic::Request<super::GetCampaignRequest>,
+        ) -> std::result::Result<tonic::Response<super::Campaign>, tonic::Status>;
+        /// list campaigns, newest first
+        async fn list_campaigns(
+            &self,
+            request: tonic::Request<super::ListCampaignsRequest>,
+        ) -> std::result::Result<tonic::Response<super::ListCampaignsResponse>, tonic::Status>;
+        /// stop a running campaign
+        async fn cancel_campaign(
+            &self,
+            request: tonic::Request<super::CancelCampaignRequest>,
+        ) -> std::result::Result<tonic::Response<super::Campaign>, tonic::Status>;
+        /// list runs of the scheduled campaigns, newest first
+        async fn list_schedule_runs(
+            &self,
+            request: tonic::Request<super::ListScheduleRunsRequest>,
+        ) -> std::result::Result<tonic::Response<super::ListScheduleRunsResponse>, tonic::Status>;
     }
     #[derive(Debug)]
     pub struct CrmServer<T: Crm> {
@@ -402,6 +750,165 @@ pub mod crm_server {
                     };
                     Box::pin(fut)
                 }
+                "/crm.Crm/GetCampaign" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetCampaignSvc<T: Crm>(pub Arc<T>);
+                    impl<T: Crm> tonic::server::UnaryService<super::GetCampaignRequest> for GetCampaignSvc<T> {
+                        type Response = super::Campaign;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetCampaignRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut =
+                                async move { <T as Crm>::get_campaign(&inner, request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetCampaignSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/crm.Crm/ListCampaigns" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListCampaignsSvc<T: Crm>(pub Arc<T>);
+                    impl<T: Crm> tonic::server::UnaryService<super::ListCampaignsRequest> for ListCampaignsSvc<T> {
+                        type Response = super::ListCampaignsResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListCampaignsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut =
+                                async move { <T as Crm>::list_campaigns(&inner, request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ListCampaignsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/crm.Crm/CancelCampaign" => {
+                    #[allow(non_camel_case_types)]
+                    struct CancelCampaignSvc<T: Crm>(pub Arc<T>);
+                    impl<T: Crm> tonic::server::UnaryService<super::CancelCampaignRequest> for CancelCampaignSvc<T> {
+                        type Response = super::Campaign;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::CancelCampaignRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut =
+                                async move { <T as Crm>::cancel_campaign(&inner, request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = CancelCampaignSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/crm.Crm/ListScheduleRuns" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListScheduleRunsSvc<T: Crm>(pub Arc<T>);
+                    impl<T: Crm> tonic::server::UnaryService<super::ListScheduleRunsRequest>
+                        for ListScheduleRunsSvc<T>
+                    {
+                        type Response = super::ListScheduleRunsResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListScheduleRunsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Crm>::list_schedule_runs(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ListScheduleRunsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 _ => Box::pin(async move {
                     Ok(http::Response::builder()
                         .status(200)
@@ -1,3 +1,6 @@
 mod crm;
 
 pub use crm::*;
+
+/// descriptors of the protos, for the reflection service
+pub const FILE_DESCRIPTOR_SET: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/crm.bin"));
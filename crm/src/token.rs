@@ -0,0 +1,75 @@
+use std::{env, fs};
+
+use anyhow::{bail, Context, Result};
+use chrono::{Duration, Utc};
+use crm::auth::{EncodingKey, User};
+
+const USAGE: &str = "Usage: token --key <private key pem> [--kid <key id>] [--id <user id>] \
+[--ws-id <workspace id>] [--fullname <name>] [--email <email>] [--expires-in <secs>] \
+[--issuer <issuer>] [--audience <audience>] [--scope <scope>]...
+
+Prints a token of the user signed with the Ed25519 private key, e.g. the dev key \
+fixtures/ed25519.pem that the configs of the repo accept. A negative --expires-in gives an \
+expired token, the default is a day. --scope can be repeated, e.g. \
+--scope campaign:preview --scope campaign:run";
+
+fn main() -> Result<()> {
+    let mut key = None;
+    let mut kid = None;
+    let mut issuer = None;
+    let mut audience = None;
+    let mut expires_in = 24 * 3600;
+    let mut user = User {
+        id: 1,
+        ws_id: 1,
+        fullname: "Tyr Chen".to_string(),
+        email: "tchen@acme.org".to_string(),
+        password_hash: String::new(),
+        created_at: Utc::now().to_rfc3339(),
+        scopes: Vec::new(),
+    };
+
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        if arg == "-h" || arg == "--help" {
+            println!("{}", USAGE);
+            return Ok(());
+        }
+        let Some(value) = args.next() else {
+            bail!("missing value of {}\n\n{}", arg, USAGE);
+        };
+        match arg.as_str() {
+            "--key" => key = Some(value),
+            "--kid" => kid = Some(value),
+            "--id" => user.id = value.parse().context("invalid --id")?,
+            "--ws-id" => user.ws_id = value.parse().context("invalid --ws-id")?,
+            "--fullname" => user.fullname = value,
+            "--email" => user.email = value,
+            "--scope" => user.scopes.push(value),
+            "--expires-in" => expires_in = value.parse().context("invalid --expires-in")?,
+            "--issuer" => issuer = Some(value),
+            "--audience" => audience = Some(value),
+            _ => bail!("unknown argument {}\n\n{}", arg, USAGE),
+        }
+    }
+
+    let Some(path) = key else {
+        bail!("missing --key\n\n{}", USAGE);
+    };
+    let pem = fs::read_to_string(&path).with_context(|| format!("failed to read {}", path))?;
+    let mut ek = EncodingKey::load(&pem)?;
+    if let Some(kid) = kid {
+        ek = ek.with_key_id(&kid);
+    }
+    if let Some(issuer) = issuer {
+        ek = ek.with_issuer(issuer);
+    }
+    if let Some(audience) = audience {
+        ek = ek.with_audience(audience);
+    }
+
+    let expires_in = Duration::try_seconds(expires_in).context("invalid --expires-in")?;
+    let token = ek.sign(user, expires_in)?;
+    println!("{}", token);
+    Ok(())
+}
@@ -0,0 +1,26 @@
+#!/bin/sh
+# certificates of the mutual TLS tests: a CA, the certificate of the services on localhost and
+# the client certificate crm presents to them
+set -e
+cd "$(dirname "$0")"
+
+openssl req -x509 -newkey ec -pkeyopt ec_paramgen_curve:P-256 -nodes -days 36500 \
+  -subj "/CN=crm test CA" -keyout ca.key -out ca.pem \
+  -addext "basicConstraints=critical,CA:TRUE" -addext "keyUsage=critical,keyCertSign,cRLSign"
+
+leaf() {
+  openssl req -newkey ec -pkeyopt ec_paramgen_curve:P-256 -nodes -subj "/CN=$1" \
+    -keyout "$1.key" -out "$1.csr"
+  openssl x509 -req -in "$1.csr" -CA ca.pem -CAkey ca.key -CAcreateserial -days 36500 \
+    -out "$1.pem" -extfile /dev/stdin <<EXT
+basicConstraints=critical,CA:FALSE
+keyUsage=critical,digitalSignature
+extendedKeyUsage=$2
+$3
+EXT
+  rm "$1.csr"
+}
+
+leaf server serverAuth "subjectAltName=DNS:localhost,IP:127.0.0.1,IP:::1"
+leaf crm clientAuth ""
+rm -f ca.srl ca.key
@@ -1,5 +1,5 @@
 use proto_builder_trait::tonic::BuilderAttributes;
-use std::{fs, process::Command};
+use std::{env, fs, path::PathBuf, process::Command};
 
 use anyhow::Result;
 fn main() -> Result<()> {
@@ -8,6 +8,7 @@ fn main() -> Result<()> {
     let builder = tonic_build::configure();
     builder
         .out_dir("src/pb")
+        .file_descriptor_set_path(PathBuf::from(env::var("OUT_DIR")?).join("user_stats.bin"))
         .with_serde(
             &["User"],
             true,
@@ -22,6 +23,7 @@ fn main() -> Result<()> {
                 "RowQueryRequest",
                 "TimeQuery",
                 "IdQuery",
+                "UpdateNotificationRequest",
             ],
             None,
         )
@@ -29,6 +31,7 @@ fn main() -> Result<()> {
             &["User.email", "User.name", "RawQueryRequest.query"],
             &[r#"#[builder(setter(into))]"#],
         )
+        .with_field_attributes(&["User.started_but_not_finished"], &[r#"#[sqlx(default)]"#])
         .with_field_attributes(
             &["TimeQuery.before", "TimeQuery.after"],
             &[r#"#[builder(setter(into, strip_option))]"#],
@@ -37,6 +40,10 @@ fn main() -> Result<()> {
             &["QueryRequest.timestamps"],
             &[r#"#[builder(setter(each(name="timestamp", into)))]"#],
         )
+        .with_field_attributes(
+            &["UpdateNotificationRequest.emails"],
+            &[r#"#[builder(setter(each(name="email", into)))]"#],
+        )
         .with_field_attributes(
             &["QueryRequest.ids"],
             &[r#"#[builder(setter(each(name="id", into)))]"#],
@@ -51,7 +58,7 @@ fn main() -> Result<()> {
         .unwrap();
     Command::new("cargo")
         .arg("fmt")
-        .spawn()
+        .status()
         .expect("executing cargo fmt failed");
     Ok(())
 }
@@ -1,347 +1,347 @@
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('adolph.02ts3f95@example.org', '贾紫芳', 'unknown', '2024-01-01 00:00:14.641548-08', '2024-04-29 19:07:14.641548-07', '2024-03-24 04:58:14.641548-07', '{128888,167569,102464,173548,119340,142615,172772,126920,108288,122816,104831,109677,135126,161001,144887,192683,156251,113393,133734,121914,191362,142882,128601,144073,167123,167520,159611,122138,109946,106058,104438,158113,155732,121820,168752,170090,161640,197226,188182,137774,143797,160451,148269,199508,162225}', '{201148,296436,211614,214337,201939,254140,277791,277305,291164,230882,285544,209517,269463,291211,203712,204522,213237,206191,218790,231501}', '{307917,376812,362200,314149,395800,304535,374662,305470,392878,343619,389799}', '{488449,484464,446386,468524,453695,476374,441716,481013,480226,428477,403904,400670,455364,480333,419933,425483,415440,434367,427097,499172,474755,430613,406484,419226,402572,433756,482107,445488}', '2024-03-21 19:26:14.64155-07', '2024-04-22 05:22:14.64155-07', '2024-04-12 10:02:14.64155-07');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('adolph.02ts3f95@example.org', '贾紫芳', 'unknown', '2024-01-01 00:00:14.641548-08', '2024-04-29 19:07:14.641548-07', '2024-03-24 04:58:14.641548-07', '{128888,167569,102464,173548,119340,142615,172772,126920,108288,122816,104831,109677,135126,161001,144887,192683,156251,113393,133734,121914,191362,142882,128601,144073,167123,167520,159611,122138,109946,106058,104438,158113,155732,121820,168752,170090,161640,197226,188182,137774,143797,160451,148269,199508,162225}', '{201148,296436,211614,214337,201939,254140,277791,277305,291164,230882,285544,209517,269463,291211,203712,204522,213237,206191,218790,231501}', '{307917,376812,362200,314149,395800,304535,374662,305470,392878,343619,389799}', '{488449,484464,446386,468524,453695,476374,441716,481013,480226,428477,403904,400670,455364,480333,419933,425483,415440,434367,427097,499172,474755,430613,406484,419226,402572,433756,482107,445488}', '2024-03-21 19:26:14.64155-07', '2024-04-22 05:22:14.64155-07', '2024-04-12 10:02:14.64155-07', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('christophe.a8fjajtu@example.org', '李沛菡', 'unknown', '2024-01-01 00:00:49.258877-08', '2024-04-15 07:55:49.258878-07', '2024-02-24 10:27:49.258878-08', '{154188,110090,175563,139188,137230,170743,111259,178660,169480,185116,144187,172606,109946,180172,101814,128244,104271,196986,108904,157886,198099,173274,147943,136843,188951,153914,130311,162490,147262,185194,131401,141113,174028,130936,188602,138138,194143,178071,149252,163920}', '{219234,200062,251840,256034,246051,250340,297953,226015,259007,238566,269597,256550,292034,281875,288786,222033,215060,267856,290124,289946,254837,225688,246507}', '{335534,381386,382273,354259,338204,352797}', '{467743,434435,429663,419630,475534,435382,428440,499594,427938,408030,422005,440977,495115,467377,492702,405225,498925,494920,478213,480009,448063,412987,436321,496129,424853}', '2024-03-28 16:37:49.258879-07', '2024-04-27 11:26:49.258879-07', '2024-03-13 16:17:49.258879-07');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('christophe.a8fjajtu@example.org', '李沛菡', 'unknown', '2024-01-01 00:00:49.258877-08', '2024-04-15 07:55:49.258878-07', '2024-02-24 10:27:49.258878-08', '{154188,110090,175563,139188,137230,170743,111259,178660,169480,185116,144187,172606,109946,180172,101814,128244,104271,196986,108904,157886,198099,173274,147943,136843,188951,153914,130311,162490,147262,185194,131401,141113,174028,130936,188602,138138,194143,178071,149252,163920}', '{219234,200062,251840,256034,246051,250340,297953,226015,259007,238566,269597,256550,292034,281875,288786,222033,215060,267856,290124,289946,254837,225688,246507}', '{335534,381386,382273,354259,338204,352797}', '{467743,434435,429663,419630,475534,435382,428440,499594,427938,408030,422005,440977,495115,467377,492702,405225,498925,494920,478213,480009,448063,412987,436321,496129,424853}', '2024-03-28 16:37:49.258879-07', '2024-04-27 11:26:49.258879-07', '2024-03-13 16:17:49.258879-07', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('henriette.3uq8kcng@example.net', '罗宇灿', 'unknown', '2024-01-01 00:01:13.292714-08', '2024-04-19 21:54:13.292714-07', '2024-04-30 06:45:13.292714-07', '{101856,188148,154310,116539,183409,142154,172505,166063,180044,189763,170262,103498,120896,104001,194082,165915,135435,197236,175785,178189,134224,155461,167845,157874,163548,179672,104148,173768}', '{264145,288737,258163,242206}', '{300650,357870,352681,309304,332657,358467,344708,301869,313261,303477,319851,330202,302420,373180,363282,335421,376464,354497,358425,308131,357976,373521,388732,376543,389541,374136,384200,306787,335857,319120,380053,336975,333815,393407,323322,357846,392853,302606,337967,300736,364052}', '{455463,443956,473372,406116,404636,489582,494353,473723,493510,412283,403254,420093,479054,462577,489006,490163,432754,444178,470068,452431}', '2024-03-24 23:11:13.292716-07', '2024-05-03 17:12:13.292716-07', '2024-03-10 21:11:13.292716-07');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('henriette.3uq8kcng@example.net', '罗宇灿', 'unknown', '2024-01-01 00:01:13.292714-08', '2024-04-19 21:54:13.292714-07', '2024-04-30 06:45:13.292714-07', '{101856,188148,154310,116539,183409,142154,172505,166063,180044,189763,170262,103498,120896,104001,194082,165915,135435,197236,175785,178189,134224,155461,167845,157874,163548,179672,104148,173768}', '{264145,288737,258163,242206}', '{300650,357870,352681,309304,332657,358467,344708,301869,313261,303477,319851,330202,302420,373180,363282,335421,376464,354497,358425,308131,357976,373521,388732,376543,389541,374136,384200,306787,335857,319120,380053,336975,333815,393407,323322,357846,392853,302606,337967,300736,364052}', '{455463,443956,473372,406116,404636,489582,494353,473723,493510,412283,403254,420093,479054,462577,489006,490163,432754,444178,470068,452431}', '2024-03-24 23:11:13.292716-07', '2024-05-03 17:12:13.292716-07', '2024-03-10 21:11:13.292716-07', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('zander.6whgtea7@example.net', '谢花萍', 'unknown', '2024-01-01 00:01:32.78823-08', '2024-04-20 11:24:32.788231-07', '2024-04-23 17:20:32.788231-07', '{}', '{262398,229603,243187,242705,280402,219087,274107,203015,283384,204155}', '{350422,375625,344354,385056,310560,383063,397906,381984}', '{445927,476062,461979,400789,468155,426415,460350,464186,472951,479566,431322}', '2024-05-04 11:04:32.788232-07', '2024-04-25 23:51:32.788232-07', '2024-02-07 23:25:32.788232-08');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('zander.6whgtea7@example.net', '谢花萍', 'unknown', '2024-01-01 00:01:32.78823-08', '2024-04-20 11:24:32.788231-07', '2024-04-23 17:20:32.788231-07', '{}', '{262398,229603,243187,242705,280402,219087,274107,203015,283384,204155}', '{350422,375625,344354,385056,310560,383063,397906,381984}', '{445927,476062,461979,400789,468155,426415,460350,464186,472951,479566,431322}', '2024-05-04 11:04:32.788232-07', '2024-04-25 23:51:32.788232-07', '2024-02-07 23:25:32.788232-08', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('ara.6uuqv27l@example.net', '许昌云', 'unknown', '2024-01-01 00:01:36.292909-08', '2024-04-21 06:44:36.292909-07', '2024-02-17 09:07:36.29291-08', '{184667,170912,125002,198716,116875,142424,136404,154779,195282}', '{296528,270119,222613,234534,227361,217164,231783,212656,235617,273529,257531,297501,295135,276529,284177,240725,290383,200166,297925,282707,290227,256581,238666,229818,240331,245398,208371,246172,209930,211771,221726,220897,240725,249021,206083,289545,287526,296106,202034,226123,221757,258797}', '{318190,329867,326282,368274,363060,341694,349806,313663,399868,314714,326538,387823,391991,371986,333569,390842,318098,346940,358023,378363,383579,343049,334093,366541,396876,349924,349920,326348,390500,382726,379725,397939,399134,312558,391448,399583,390931,339756,390779,305906}', '{452356,433035,492529,453848,423855,451084,421692,411969,453949,498178,469349,434400,440845,452381,466607,408993,417925,450396,428688,424596,428765}', '2024-04-29 08:08:36.292912-07', '2024-05-02 11:01:36.292912-07', '2024-04-06 17:26:36.292912-07');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('ara.6uuqv27l@example.net', '许昌云', 'unknown', '2024-01-01 00:01:36.292909-08', '2024-04-21 06:44:36.292909-07', '2024-02-17 09:07:36.29291-08', '{184667,170912,125002,198716,116875,142424,136404,154779,195282}', '{296528,270119,222613,234534,227361,217164,231783,212656,235617,273529,257531,297501,295135,276529,284177,240725,290383,200166,297925,282707,290227,256581,238666,229818,240331,245398,208371,246172,209930,211771,221726,220897,240725,249021,206083,289545,287526,296106,202034,226123,221757,258797}', '{318190,329867,326282,368274,363060,341694,349806,313663,399868,314714,326538,387823,391991,371986,333569,390842,318098,346940,358023,378363,383579,343049,334093,366541,396876,349924,349920,326348,390500,382726,379725,397939,399134,312558,391448,399583,390931,339756,390779,305906}', '{452356,433035,492529,453848,423855,451084,421692,411969,453949,498178,469349,434400,440845,452381,466607,408993,417925,450396,428688,424596,428765}', '2024-04-29 08:08:36.292912-07', '2024-05-02 11:01:36.292912-07', '2024-04-06 17:26:36.292912-07', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('richard.dpunmq50@example.net', '丁韵杰', 'unknown', '2024-01-01 00:01:51.413632-08', '2024-04-30 14:05:51.413633-07', '2024-02-17 20:58:51.413633-08', '{180973,119581,114305,129499,125324,155539,140582,132955,157104,140768,137070,160683,177416,178636,174306,116211,100855,113776,126071,132493,134504,185105,157604,160337,114593,162654,125818,153608,149738,183543}', '{274875,236288,289260,255583,282820,216095,204026,290708,202613,298309,285079,219404,248434,203191,249614,275792,289381,274052,258058,253696,226286,223117,255145,206420,297270,217717,247209,286543,201117,288527,235889,269129,260030,231498,229845,290665,257672,222461,256493,238483,227499,275579,278025}', '{399157,338526,376657,396262,364716,379359,397963,382335,337252,310626,316890,369981,358418,344650,314618,364695,318708,372905,376666,321124,374572}', '{484344,483031,472675,475776,453197,476825,432784,446749,449897,447831,413035,494288,432568,456767,424537,439223,439679,429034,432401,443103,467810,465345,469823,434213,433886,434011,413554,471080,452760,426067,405901,464951,447846,445752,400659,414658,445969,483243,420342,417173}', '2024-04-30 05:25:51.413634-07', '2024-04-22 18:36:51.413634-07', '2024-02-06 12:41:51.413635-08');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('richard.dpunmq50@example.net', '丁韵杰', 'unknown', '2024-01-01 00:01:51.413632-08', '2024-04-30 14:05:51.413633-07', '2024-02-17 20:58:51.413633-08', '{180973,119581,114305,129499,125324,155539,140582,132955,157104,140768,137070,160683,177416,178636,174306,116211,100855,113776,126071,132493,134504,185105,157604,160337,114593,162654,125818,153608,149738,183543}', '{274875,236288,289260,255583,282820,216095,204026,290708,202613,298309,285079,219404,248434,203191,249614,275792,289381,274052,258058,253696,226286,223117,255145,206420,297270,217717,247209,286543,201117,288527,235889,269129,260030,231498,229845,290665,257672,222461,256493,238483,227499,275579,278025}', '{399157,338526,376657,396262,364716,379359,397963,382335,337252,310626,316890,369981,358418,344650,314618,364695,318708,372905,376666,321124,374572}', '{484344,483031,472675,475776,453197,476825,432784,446749,449897,447831,413035,494288,432568,456767,424537,439223,439679,429034,432401,443103,467810,465345,469823,434213,433886,434011,413554,471080,452760,426067,405901,464951,447846,445752,400659,414658,445969,483243,420342,417173}', '2024-04-30 05:25:51.413634-07', '2024-04-22 18:36:51.413634-07', '2024-02-06 12:41:51.413635-08', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('hiram.hjew91d5@example.com', '毛弘靖', 'unknown', '2024-01-01 00:02:16.844298-08', '2024-04-23 17:40:16.844298-07', '2024-04-12 15:36:16.844298-07', '{133125,174714,138627,138796,121327,150075,158418,117072,192050,171763,188854,108640,150431,128780,139076,110782,149844,129214,107098,197889,191958,139166,142366,148311,149792,156205,178997,132250}', '{234049,294739,275146,216454,244838}', '{325998,384064,351601,301547,355540,301942,361018}', '{487599,485627,409709,485730,473962,407386,475744,487197,456258,434213,433794,432062,403556,469486,454306,445088,467073,449325,494947,443239,457559,452302,430917,472264,475354,483345,442631,440816,448588,432509,474223,467847,469984}', '2024-03-24 19:58:16.8443-07', '2024-04-23 01:07:16.8443-07', '2024-04-02 18:16:16.8443-07');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('hiram.hjew91d5@example.com', '毛弘靖', 'unknown', '2024-01-01 00:02:16.844298-08', '2024-04-23 17:40:16.844298-07', '2024-04-12 15:36:16.844298-07', '{133125,174714,138627,138796,121327,150075,158418,117072,192050,171763,188854,108640,150431,128780,139076,110782,149844,129214,107098,197889,191958,139166,142366,148311,149792,156205,178997,132250}', '{234049,294739,275146,216454,244838}', '{325998,384064,351601,301547,355540,301942,361018}', '{487599,485627,409709,485730,473962,407386,475744,487197,456258,434213,433794,432062,403556,469486,454306,445088,467073,449325,494947,443239,457559,452302,430917,472264,475354,483345,442631,440816,448588,432509,474223,467847,469984}', '2024-03-24 19:58:16.8443-07', '2024-04-23 01:07:16.8443-07', '2024-04-02 18:16:16.8443-07', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('amelie.p9gdkhmt@example.com', '付晓玫', 'unknown', '2024-01-01 00:02:41.120747-08', '2024-05-01 16:37:41.120747-07', '2024-02-07 14:15:41.120747-08', '{104570,163778,162968,158173,182014,181075,138621,119403,107357,191272,104090,134673,113239,191539,150138,120743,197327,120193,196070,100589,162862,185931,167541,131431,119749,197992,111757}', '{218777,249581,210511,241810,210487,203232,213672,221288,212165,275005,277306,262626,275121,257421,220039,279195,216926,202886,218562,221900,295744,211626,262460,244323,219648,292129}', '{389590,304639,378397,318549,332769,336625,338531,384871,342472,369859,313763,392874,305216,357365,301222,367832,332685,317991,352171,397287,323574,382824,328259,348066,315599,382721,317044,384076,396106,308836,396533,346056,384322}', '{428812,437292,491710,441998,488477,423123,496230,459624,455731,425489,497009}', '2024-03-22 15:13:41.120749-07', '2024-04-22 23:40:41.120749-07', '2024-04-28 16:53:41.120749-07');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('amelie.p9gdkhmt@example.com', '付晓玫', 'unknown', '2024-01-01 00:02:41.120747-08', '2024-05-01 16:37:41.120747-07', '2024-02-07 14:15:41.120747-08', '{104570,163778,162968,158173,182014,181075,138621,119403,107357,191272,104090,134673,113239,191539,150138,120743,197327,120193,196070,100589,162862,185931,167541,131431,119749,197992,111757}', '{218777,249581,210511,241810,210487,203232,213672,221288,212165,275005,277306,262626,275121,257421,220039,279195,216926,202886,218562,221900,295744,211626,262460,244323,219648,292129}', '{389590,304639,378397,318549,332769,336625,338531,384871,342472,369859,313763,392874,305216,357365,301222,367832,332685,317991,352171,397287,323574,382824,328259,348066,315599,382721,317044,384076,396106,308836,396533,346056,384322}', '{428812,437292,491710,441998,488477,423123,496230,459624,455731,425489,497009}', '2024-03-22 15:13:41.120749-07', '2024-04-22 23:40:41.120749-07', '2024-04-28 16:53:41.120749-07', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('clovis.ao4yf987@example.net', '曾博远', 'unknown', '2024-01-01 00:03:33.846712-08', '2024-04-10 11:13:33.846712-07', '2024-04-01 11:49:33.846712-07', '{134638,152869,132323,151928,116209,113423,124036,169074,103919,137378,120678,100637,199242,130201,119161,186638,176227,136790,186587,161544,116456,172753,163386,100885,135664,185433,194465,162799,173556,131064,198408,158197,105854,156421,189701,148688,182996,192791,155975,158892,139094,157660,182678,194330}', '{278672,239844,206423,220121,225159,262085,223081,275958,299787,237262,267917,293014,234689,299418,207363,257159,247133,201921,288120}', '{301255,349904,348499}', '{428844,457275,416157,444250,470485,421688,409593,474984,451551,409691,412513,425067,454446,433859,474598,443588,404047,474838,444602,436336,418001,459480,494673,422320,477230,459630,461306,463237,416192,473109,435044,496489,450545,477462,487108,471109,435344,421528,439512,458946}', '2024-04-11 18:51:33.846714-07', '2024-04-24 09:24:33.846715-07', '2024-02-07 13:24:33.846715-08');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('clovis.ao4yf987@example.net', '曾博远', 'unknown', '2024-01-01 00:03:33.846712-08', '2024-04-10 11:13:33.846712-07', '2024-04-01 11:49:33.846712-07', '{134638,152869,132323,151928,116209,113423,124036,169074,103919,137378,120678,100637,199242,130201,119161,186638,176227,136790,186587,161544,116456,172753,163386,100885,135664,185433,194465,162799,173556,131064,198408,158197,105854,156421,189701,148688,182996,192791,155975,158892,139094,157660,182678,194330}', '{278672,239844,206423,220121,225159,262085,223081,275958,299787,237262,267917,293014,234689,299418,207363,257159,247133,201921,288120}', '{301255,349904,348499}', '{428844,457275,416157,444250,470485,421688,409593,474984,451551,409691,412513,425067,454446,433859,474598,443588,404047,474838,444602,436336,418001,459480,494673,422320,477230,459630,461306,463237,416192,473109,435044,496489,450545,477462,487108,471109,435344,421528,439512,458946}', '2024-04-11 18:51:33.846714-07', '2024-04-24 09:24:33.846715-07', '2024-02-07 13:24:33.846715-08', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('wilber.sh6d3x92@example.com', '崔志辉', 'unknown', '2024-01-01 00:03:34.214689-08', '2024-04-21 16:17:34.214689-07', '2024-02-16 02:24:34.214689-08', '{109297,167623,117708,107050,161548,131975,153704,117546,157242,172096,132038,155342,100420,170645,161702,179849,192355,164167,181217,176598,179826,106012,151919,188601,109165,113280,119283,189753,186147,114247,116112,157241,129036,114229,176790,104468,160748,187569,122857,186458,103694,196776,159352,187279,153215,104716,178011,121372,158078}', '{267505}', '{343680,323638,346533,350198,324596,322629,397744,342471,398467,359068,355019,391982,388335}', '{472343,453039,466820,472388,467925,469886,479641,452904,487172,402142,477633,434939,470514,410092,456078,424027,473857,401171,425250,467973,454217,473336,420493,435960,473976,418053,441568,436581,425595,482383,490390,470356,475272,492156,466908,419575,455095}', '2024-04-01 04:17:34.214691-07', '2024-05-02 20:25:34.214691-07', '2024-03-22 00:50:34.214691-07');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('wilber.sh6d3x92@example.com', '崔志辉', 'unknown', '2024-01-01 00:03:34.214689-08', '2024-04-21 16:17:34.214689-07', '2024-02-16 02:24:34.214689-08', '{109297,167623,117708,107050,161548,131975,153704,117546,157242,172096,132038,155342,100420,170645,161702,179849,192355,164167,181217,176598,179826,106012,151919,188601,109165,113280,119283,189753,186147,114247,116112,157241,129036,114229,176790,104468,160748,187569,122857,186458,103694,196776,159352,187279,153215,104716,178011,121372,158078}', '{267505}', '{343680,323638,346533,350198,324596,322629,397744,342471,398467,359068,355019,391982,388335}', '{472343,453039,466820,472388,467925,469886,479641,452904,487172,402142,477633,434939,470514,410092,456078,424027,473857,401171,425250,467973,454217,473336,420493,435960,473976,418053,441568,436581,425595,482383,490390,470356,475272,492156,466908,419575,455095}', '2024-04-01 04:17:34.214691-07', '2024-05-02 20:25:34.214691-07', '2024-03-22 00:50:34.214691-07', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('jocelyn.fetasiqs@example.com', '韦杰宏', 'unknown', '2024-01-01 00:06:21.229157-08', '2024-04-06 14:29:21.229157-07', '2024-04-06 19:50:21.229158-07', '{110799,195614,192420,154297,173843,112938,110951,186950,145127,137569,119310,175541,146099,119281,196280,134806,103440,125714,109814,182213,123309,149387,154889,172678,140218,181467,152922,142295,102892,162569,140676,100181,149858,127038,122032,151011,192240,183152,106371,115078,160282,139478,150283}', '{293341,256053,224409,270373,223965,205639,246587,283628,240447,248428,233579,250588,291340,219065,289065,242963,281054,224607,230357,280822,277007,293750,248846,253018,224285,237875,242233,241948,202812,211495,299462,295482,293891,221629,256016,262160,272773,276250,261105,233007}', '{321424,353507,312154,356542,358370,359083,339313,370559,325606,381858,388008,329656,356731,320704,345814,348698,364548}', '{459758,419421,428286,471965,425288,433417,486566,489558,456818,440036,425135,467963,454044,469250,471651,464482,467989,446346,467224,483242,457950,458733,441760,434420,452669,408820,420876,440108}', '2024-05-04 21:19:21.229162-07', '2024-04-27 13:12:21.229163-07', '2024-02-19 19:50:21.229163-08');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('jocelyn.fetasiqs@example.com', '韦杰宏', 'unknown', '2024-01-01 00:06:21.229157-08', '2024-04-06 14:29:21.229157-07', '2024-04-06 19:50:21.229158-07', '{110799,195614,192420,154297,173843,112938,110951,186950,145127,137569,119310,175541,146099,119281,196280,134806,103440,125714,109814,182213,123309,149387,154889,172678,140218,181467,152922,142295,102892,162569,140676,100181,149858,127038,122032,151011,192240,183152,106371,115078,160282,139478,150283}', '{293341,256053,224409,270373,223965,205639,246587,283628,240447,248428,233579,250588,291340,219065,289065,242963,281054,224607,230357,280822,277007,293750,248846,253018,224285,237875,242233,241948,202812,211495,299462,295482,293891,221629,256016,262160,272773,276250,261105,233007}', '{321424,353507,312154,356542,358370,359083,339313,370559,325606,381858,388008,329656,356731,320704,345814,348698,364548}', '{459758,419421,428286,471965,425288,433417,486566,489558,456818,440036,425135,467963,454044,469250,471651,464482,467989,446346,467224,483242,457950,458733,441760,434420,452669,408820,420876,440108}', '2024-05-04 21:19:21.229162-07', '2024-04-27 13:12:21.229163-07', '2024-02-19 19:50:21.229163-08', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('raphael.5tsg6rf6@example.com', '莫水瑶', 'unknown', '2024-01-01 00:06:48.162605-08', '2024-04-22 23:16:48.162606-07', '2024-04-06 14:31:48.162606-07', '{162097,161519,159870,199082,154930,117976,175896,178664,148409,194044,145983,138763,156945,166760,196214,107066,138113,115644,142523,156702,126567,102498,126757,182531,151395,160427,110174,191532,112081,177736,108101,197407,153812,108766,186000,104372,193616,143532,197525,103400,123795,120579,186590,155929}', '{208979,252142,265636,295520,233536,221792,200366,256100,254455,239527,222633,286017,200436,219801,240656,274630,260881,294721,241813,217853,274128,216880,206703,270242,293898,201282,274782,259114,223984,285624,261346,292166,272652,203435,222353,250838,254218,244302,207806,204118,243864,289488,202063,219741,267007,282755,206317}', '{345236,373454,320049,370334,374174,391182,330917,305423,398004,328350,367509,388540,330481,319767,321342,388840,346103,368505,341072,396486,340362,334825,369686,359749,357253,376359,335103,392398,317043,350898,381960,383781,339899,388413,392426,397740}', '{459929,448309,413824,416467,452299,416329,423307,497125,403486,423291,430740,487722,411071,481519,419432,428470,484600,424198,443038,414206,466666,492222,479688,496794,475567,488117,474149,469299,438418,495379,426092,475223,457127,423028,494253,414224,401186,439653,415160,477576,493400,464268,410052,410219}', '2024-05-05 06:45:48.162608-07', '2024-04-29 20:37:48.162608-07', '2024-03-26 08:11:48.162608-07');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('raphael.5tsg6rf6@example.com', '莫水瑶', 'unknown', '2024-01-01 00:06:48.162605-08', '2024-04-22 23:16:48.162606-07', '2024-04-06 14:31:48.162606-07', '{162097,161519,159870,199082,154930,117976,175896,178664,148409,194044,145983,138763,156945,166760,196214,107066,138113,115644,142523,156702,126567,102498,126757,182531,151395,160427,110174,191532,112081,177736,108101,197407,153812,108766,186000,104372,193616,143532,197525,103400,123795,120579,186590,155929}', '{208979,252142,265636,295520,233536,221792,200366,256100,254455,239527,222633,286017,200436,219801,240656,274630,260881,294721,241813,217853,274128,216880,206703,270242,293898,201282,274782,259114,223984,285624,261346,292166,272652,203435,222353,250838,254218,244302,207806,204118,243864,289488,202063,219741,267007,282755,206317}', '{345236,373454,320049,370334,374174,391182,330917,305423,398004,328350,367509,388540,330481,319767,321342,388840,346103,368505,341072,396486,340362,334825,369686,359749,357253,376359,335103,392398,317043,350898,381960,383781,339899,388413,392426,397740}', '{459929,448309,413824,416467,452299,416329,423307,497125,403486,423291,430740,487722,411071,481519,419432,428470,484600,424198,443038,414206,466666,492222,479688,496794,475567,488117,474149,469299,438418,495379,426092,475223,457127,423028,494253,414224,401186,439653,415160,477576,493400,464268,410052,410219}', '2024-05-05 06:45:48.162608-07', '2024-04-29 20:37:48.162608-07', '2024-03-26 08:11:48.162608-07', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('abdiel.5b945jbb@example.com', '苏清云', 'unknown', '2024-01-01 00:07:20.909179-08', '2024-04-18 08:53:20.909179-07', '2024-04-20 01:57:20.90918-07', '{109761,122904,141229,150218,138588,177149,116899,186060,121092,163459,106013,185315,151559,109553,113994,170322,190544,157003,196480,142234,105412,110554,177732,158732,107344,169592,106668,100172,139137,159558,140788,194345,141587,175604,190634,108103,125013}', '{224338,236352,213205,208855,263689,242526,297137,291674,280380,259380,238053,252120,233234,250000,242735,202935,214434,284611,269521,228773,234144,210434,264520,213499,232583,267843,285487,222133,282786,254772,212263,206609,263883,214805,241464,230813,201140,221131,267189,297260,278835,209842,210952,213263,279984,239189}', '{310140,330294,315337,321068,309702,392717,323361,338589,380114,354403,309757,387208,371360,360904,337684,331972,307650,338900,359648,324717,372138,399296,315173,315855,307437,367462,368277,391716,357589}', '{429886,486663,420077,432324,478371,425536,405883,487738,408961,475686}', '2024-03-29 10:16:20.909182-07', '2024-05-04 16:30:20.909182-07', '2024-04-24 11:00:20.909183-07');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('abdiel.5b945jbb@example.com', '苏清云', 'unknown', '2024-01-01 00:07:20.909179-08', '2024-04-18 08:53:20.909179-07', '2024-04-20 01:57:20.90918-07', '{109761,122904,141229,150218,138588,177149,116899,186060,121092,163459,106013,185315,151559,109553,113994,170322,190544,157003,196480,142234,105412,110554,177732,158732,107344,169592,106668,100172,139137,159558,140788,194345,141587,175604,190634,108103,125013}', '{224338,236352,213205,208855,263689,242526,297137,291674,280380,259380,238053,252120,233234,250000,242735,202935,214434,284611,269521,228773,234144,210434,264520,213499,232583,267843,285487,222133,282786,254772,212263,206609,263883,214805,241464,230813,201140,221131,267189,297260,278835,209842,210952,213263,279984,239189}', '{310140,330294,315337,321068,309702,392717,323361,338589,380114,354403,309757,387208,371360,360904,337684,331972,307650,338900,359648,324717,372138,399296,315173,315855,307437,367462,368277,391716,357589}', '{429886,486663,420077,432324,478371,425536,405883,487738,408961,475686}', '2024-03-29 10:16:20.909182-07', '2024-05-04 16:30:20.909182-07', '2024-04-24 11:00:20.909183-07', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('lamont.9ytsh90z@example.net', '徐醉蝶', 'unknown', '2024-01-01 00:07:37.063686-08', '2024-04-09 16:23:37.063686-07', '2024-03-11 06:57:37.063686-07', '{131971,184136,149537,111797,132535,184712,172115,194491,191675,141285,113383,109165,153417,101074,195254,189931,138038,129913,138922,122442,112667,135833,121151,170977,194064,150192,121249,126551,148943,173271,182183,125415,180246,121407,123343,167982,178007,153148,130978,194242,140636,153859,130663,141320,156148}', '{292015,282433,210965,274902,279051,272010,234787,293491,244725}', '{328965,358928,392410,365320,383842,366769,382637,316328,357387}', '{421037,421887,416646,485554,420984,484731,409583,403265,413077,457016,431084,433239,494408,418414,452666,480615,407962,412000,468466,447671,471025,493569,416384,408914,485043,484840,471315,437692,427379,443590,436250,450712,425158,425861,487823,442022,479184,406601,436902,452243,494804,454617,463258,416904,431673,488605,427829}', '2024-04-21 03:49:37.063688-07', '2024-04-25 09:10:37.063688-07', '2024-02-21 14:27:37.063689-08');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('lamont.9ytsh90z@example.net', '徐醉蝶', 'unknown', '2024-01-01 00:07:37.063686-08', '2024-04-09 16:23:37.063686-07', '2024-03-11 06:57:37.063686-07', '{131971,184136,149537,111797,132535,184712,172115,194491,191675,141285,113383,109165,153417,101074,195254,189931,138038,129913,138922,122442,112667,135833,121151,170977,194064,150192,121249,126551,148943,173271,182183,125415,180246,121407,123343,167982,178007,153148,130978,194242,140636,153859,130663,141320,156148}', '{292015,282433,210965,274902,279051,272010,234787,293491,244725}', '{328965,358928,392410,365320,383842,366769,382637,316328,357387}', '{421037,421887,416646,485554,420984,484731,409583,403265,413077,457016,431084,433239,494408,418414,452666,480615,407962,412000,468466,447671,471025,493569,416384,408914,485043,484840,471315,437692,427379,443590,436250,450712,425158,425861,487823,442022,479184,406601,436902,452243,494804,454617,463258,416904,431673,488605,427829}', '2024-04-21 03:49:37.063688-07', '2024-04-25 09:10:37.063688-07', '2024-02-21 14:27:37.063689-08', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('thea.2w8b603j@example.net', '陶乐丹', 'unknown', '2024-01-01 00:07:37.339857-08', '2024-04-30 01:21:37.339857-07', '2024-03-06 12:27:37.339857-08', '{188299,108662,191579,186466,187104,144952,135995,179894,103435,116700,108898,134178,119032,132181,179408,112448,193562,134043,142087,143458,106073,113748,153201,148480,113605,183832,170657,161497,178187,142793,114610,178564,151031,114641,143031,191265,150870,194689,184402,185952,172211}', '{239369,212142,220852,259366,278530,260344,247158,276097,258006,289433,261671,233772,251109,223493,217403,289892,280379,209757,228224,276176,212148,245040,208774,256184,275377,273385,268766,221818,266740,254959,242402,237586,231582,219448}', '{373104,333351,381152,326625,332358,318238,320565,314209,393433,325397,307451,321814,312418,348246,373049,385233,390200,322640,314469,388703,345029,304412,375869,390584,361023,363976,303702}', '{449110,409005,460625,447976,471457,409489,416725,421395,405532,475390,426079,448676,439910,422763,467076,403003,405232,457360,414183,444140,424627,443971}', '2024-04-15 13:43:37.339859-07', '2024-05-05 06:59:37.339859-07', '2024-03-04 23:30:37.339859-08');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('thea.2w8b603j@example.net', '陶乐丹', 'unknown', '2024-01-01 00:07:37.339857-08', '2024-04-30 01:21:37.339857-07', '2024-03-06 12:27:37.339857-08', '{188299,108662,191579,186466,187104,144952,135995,179894,103435,116700,108898,134178,119032,132181,179408,112448,193562,134043,142087,143458,106073,113748,153201,148480,113605,183832,170657,161497,178187,142793,114610,178564,151031,114641,143031,191265,150870,194689,184402,185952,172211}', '{239369,212142,220852,259366,278530,260344,247158,276097,258006,289433,261671,233772,251109,223493,217403,289892,280379,209757,228224,276176,212148,245040,208774,256184,275377,273385,268766,221818,266740,254959,242402,237586,231582,219448}', '{373104,333351,381152,326625,332358,318238,320565,314209,393433,325397,307451,321814,312418,348246,373049,385233,390200,322640,314469,388703,345029,304412,375869,390584,361023,363976,303702}', '{449110,409005,460625,447976,471457,409489,416725,421395,405532,475390,426079,448676,439910,422763,467076,403003,405232,457360,414183,444140,424627,443971}', '2024-04-15 13:43:37.339859-07', '2024-05-05 06:59:37.339859-07', '2024-03-04 23:30:37.339859-08', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('noelia.or3zn7qf@example.net', '董俊帆', 'unknown', '2024-01-01 00:07:54.433881-08', '2024-04-30 21:08:54.433882-07', '2024-04-23 21:29:54.433882-07', '{111966,166222,136616,180680,133766,193816,197153,188331,118004,118588,110009,172689,119005,107173,101129,195873,195408,195495,173341,159541,197073,103694,112497,111707}', '{240789,291380,239843,269179,233790,236679,256596,284227}', '{398382,315015,388666,312111,370086,330918,392452,349350,308129,359230,307012,340331,329610,372673,391798,355199,356525,364966,330189,336012,359107,344864,326238,369620,325041,376289,313037,391214,392206,307840,397395,310634}', '{470418,419266,403089,408116,463960,487692,474867,415712,499102,412722,442176,491301,460772,464285,480190,443327,406174,486605,429250,453285,432308,493306,447105,438160,466121}', '2024-03-23 02:48:54.433883-07', '2024-04-27 09:03:54.433883-07', '2024-04-25 01:29:54.433884-07');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('noelia.or3zn7qf@example.net', '董俊帆', 'unknown', '2024-01-01 00:07:54.433881-08', '2024-04-30 21:08:54.433882-07', '2024-04-23 21:29:54.433882-07', '{111966,166222,136616,180680,133766,193816,197153,188331,118004,118588,110009,172689,119005,107173,101129,195873,195408,195495,173341,159541,197073,103694,112497,111707}', '{240789,291380,239843,269179,233790,236679,256596,284227}', '{398382,315015,388666,312111,370086,330918,392452,349350,308129,359230,307012,340331,329610,372673,391798,355199,356525,364966,330189,336012,359107,344864,326238,369620,325041,376289,313037,391214,392206,307840,397395,310634}', '{470418,419266,403089,408116,463960,487692,474867,415712,499102,412722,442176,491301,460772,464285,480190,443327,406174,486605,429250,453285,432308,493306,447105,438160,466121}', '2024-03-23 02:48:54.433883-07', '2024-04-27 09:03:54.433883-07', '2024-04-25 01:29:54.433884-07', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('felipa.bfqbdff0@example.net', '范伦博', 'unknown', '2024-01-01 00:08:25.659987-08', '2024-04-14 08:15:25.659987-07', '2024-03-05 22:06:25.659987-08', '{120012,102989,164272,124694,169711,148272,135505,140122,126269,115753,161399,147774,144932,195040,127826,157340,117198,142453,155164,159579,136129,109710,104345,198318,115070,195824,196429,164483,108670,179748,154265}', '{258390,282812,298257,263082,296489,277852,260647,227394,201885,241540,291997,223601,294826,203341,284141,276080,295547,237863,212073,249398,203176,236612,219383,286043,262547,264671}', '{372918,316464,303398,313435,358202,376208,356176,378029,386193,346052,335083,354297,369847,399040,305837,332245,315891,389142,325984,377022,345876,306004,317252,329597,341169,361186,362255,331170,369096,306329,312431,309273,300133,371633,331837,397157,337229,369176,305135,333715,341613,359650,301988,361913}', '{491754,486969,486005,462379,497941,438980,447100,471358,419600,431843,409998,408537,418457,428916}', '2024-05-05 03:26:25.659989-07', '2024-05-04 03:40:25.659989-07', '2024-02-26 06:43:25.659989-08');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('felipa.bfqbdff0@example.net', '范伦博', 'unknown', '2024-01-01 00:08:25.659987-08', '2024-04-14 08:15:25.659987-07', '2024-03-05 22:06:25.659987-08', '{120012,102989,164272,124694,169711,148272,135505,140122,126269,115753,161399,147774,144932,195040,127826,157340,117198,142453,155164,159579,136129,109710,104345,198318,115070,195824,196429,164483,108670,179748,154265}', '{258390,282812,298257,263082,296489,277852,260647,227394,201885,241540,291997,223601,294826,203341,284141,276080,295547,237863,212073,249398,203176,236612,219383,286043,262547,264671}', '{372918,316464,303398,313435,358202,376208,356176,378029,386193,346052,335083,354297,369847,399040,305837,332245,315891,389142,325984,377022,345876,306004,317252,329597,341169,361186,362255,331170,369096,306329,312431,309273,300133,371633,331837,397157,337229,369176,305135,333715,341613,359650,301988,361913}', '{491754,486969,486005,462379,497941,438980,447100,471358,419600,431843,409998,408537,418457,428916}', '2024-05-05 03:26:25.659989-07', '2024-05-04 03:40:25.659989-07', '2024-02-26 06:43:25.659989-08', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('maxwell.2vvcrnmz@example.org', '秦杰宏', 'unknown', '2024-01-01 00:09:51.027892-08', '2024-04-26 06:11:51.027893-07', '2024-04-15 17:29:51.027893-07', '{189841,104084,128618,193853,186614,101576,111756,108457,124151,136126,100282,123583}', '{216845,262763,258971,220180,238341,200190,295830,204241,234233,256112,215803,220990,218174,229364,208451,201994,210186,208794,228334,271789}', '{361056,323308,384881,394562}', '{498671}', '2024-04-13 06:47:51.027894-07', '2024-04-29 18:31:51.027894-07', '2024-04-28 09:04:51.027894-07');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('maxwell.2vvcrnmz@example.org', '秦杰宏', 'unknown', '2024-01-01 00:09:51.027892-08', '2024-04-26 06:11:51.027893-07', '2024-04-15 17:29:51.027893-07', '{189841,104084,128618,193853,186614,101576,111756,108457,124151,136126,100282,123583}', '{216845,262763,258971,220180,238341,200190,295830,204241,234233,256112,215803,220990,218174,229364,208451,201994,210186,208794,228334,271789}', '{361056,323308,384881,394562}', '{498671}', '2024-04-13 06:47:51.027894-07', '2024-04-29 18:31:51.027894-07', '2024-04-28 09:04:51.027894-07', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('florian.ptzekbng@example.org', '史香菱', 'unknown', '2024-01-01 00:10:11.553535-08', '2024-04-22 06:12:11.553535-07', '2024-02-07 13:13:11.553535-08', '{181945,185396,175808,120564,151033,190807,183882}', '{287222,207724,232522,278514,289717,224591,233463,208797,282961,266074,241469,277997,252887,281148,298350,291607,240914,291749,202441,219904,248728,227274,222395,228028,247274,236449,275153,232681,278837,219447,276867,264208,250977,288005,208342,280848,282660,255565,243859,211495,250974,201414,230235,268641,288535}', '{351452,367304,391084,337671,311170,385889,388911,326354,382017,357706,385264,388802,305878}', '{477182,493131,430838,447489,448040,409838,440284}', '2024-04-14 17:02:11.553537-07', '2024-04-25 15:43:11.553537-07', '2024-04-25 18:05:11.553537-07');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('florian.ptzekbng@example.org', '史香菱', 'unknown', '2024-01-01 00:10:11.553535-08', '2024-04-22 06:12:11.553535-07', '2024-02-07 13:13:11.553535-08', '{181945,185396,175808,120564,151033,190807,183882}', '{287222,207724,232522,278514,289717,224591,233463,208797,282961,266074,241469,277997,252887,281148,298350,291607,240914,291749,202441,219904,248728,227274,222395,228028,247274,236449,275153,232681,278837,219447,276867,264208,250977,288005,208342,280848,282660,255565,243859,211495,250974,201414,230235,268641,288535}', '{351452,367304,391084,337671,311170,385889,388911,326354,382017,357706,385264,388802,305878}', '{477182,493131,430838,447489,448040,409838,440284}', '2024-04-14 17:02:11.553537-07', '2024-04-25 15:43:11.553537-07', '2024-04-25 18:05:11.553537-07', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('alvena.c0z4cqwt@example.com', '叶仁顺', 'unknown', '2024-01-01 00:11:42.025648-08', '2024-04-24 14:56:42.025648-07', '2024-03-24 12:08:42.025649-07', '{125737,127606,157805,153331,133658,163341,149283,158588,187010,123048,105579,172485,158792,103832,188425,174014,160770,107735,178358,147684,140122,126358,199802,118955,188187,161682,137743,190424,103631,129141,102923,162163,183408,155604}', '{221712}', '{317429,346904}', '{428962,486180,414265,420129,423277,458416,420744,479782,477599,467152,442080,487460,487913,440791,437843,490314,425389,464176,403559,400491,409972}', '2024-04-07 19:19:42.02565-07', '2024-04-25 04:01:42.02565-07', '2024-02-21 00:54:42.02565-08');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('alvena.c0z4cqwt@example.com', '叶仁顺', 'unknown', '2024-01-01 00:11:42.025648-08', '2024-04-24 14:56:42.025648-07', '2024-03-24 12:08:42.025649-07', '{125737,127606,157805,153331,133658,163341,149283,158588,187010,123048,105579,172485,158792,103832,188425,174014,160770,107735,178358,147684,140122,126358,199802,118955,188187,161682,137743,190424,103631,129141,102923,162163,183408,155604}', '{221712}', '{317429,346904}', '{428962,486180,414265,420129,423277,458416,420744,479782,477599,467152,442080,487460,487913,440791,437843,490314,425389,464176,403559,400491,409972}', '2024-04-07 19:19:42.02565-07', '2024-04-25 04:01:42.02565-07', '2024-02-21 00:54:42.02565-08', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('maribel.0z29xoms@example.org', '闫丹华', 'unknown', '2024-01-01 00:12:05.250245-08', '2024-04-09 22:16:05.250246-07', '2024-05-04 04:34:05.250246-07', '{127293,153979,195980,147426,154080,133448,139755,162249,103975,168992,193316,122183,181862,123068,199283,106603,191415,194182,109447,107988,172133,101046,118864,116061,157318,161511,112944,143352,133147,161710,131956,139131,199485,114932,168209,175467,135463,179949,149131,118322,134310}', '{284100,270766,231564,264469,226955,288670,228912,225180,215233,229392,297549,264682,213966,272596,293525,285042,217360,237132,240662,209726,207209,287439,259560,252129}', '{397393}', '{487101,415760,426711,444436,421825,449624,415594,463790,412501,457936,479862,497438,499364,450493,439000,490014,435708,497891,419329,437116,498482,412195,481844,448256,446339,428938,428210,412363,439563,436828,434588,437166,457883,472677,477877,471212,482338,452177,490546,465107,424432,479898,467828,420718,492388,457435,471595}', '2024-05-02 14:48:05.250248-07', '2024-04-25 10:12:05.250248-07', '2024-04-19 21:33:05.250248-07');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('maribel.0z29xoms@example.org', '闫丹华', 'unknown', '2024-01-01 00:12:05.250245-08', '2024-04-09 22:16:05.250246-07', '2024-05-04 04:34:05.250246-07', '{127293,153979,195980,147426,154080,133448,139755,162249,103975,168992,193316,122183,181862,123068,199283,106603,191415,194182,109447,107988,172133,101046,118864,116061,157318,161511,112944,143352,133147,161710,131956,139131,199485,114932,168209,175467,135463,179949,149131,118322,134310}', '{284100,270766,231564,264469,226955,288670,228912,225180,215233,229392,297549,264682,213966,272596,293525,285042,217360,237132,240662,209726,207209,287439,259560,252129}', '{397393}', '{487101,415760,426711,444436,421825,449624,415594,463790,412501,457936,479862,497438,499364,450493,439000,490014,435708,497891,419329,437116,498482,412195,481844,448256,446339,428938,428210,412363,439563,436828,434588,437166,457883,472677,477877,471212,482338,452177,490546,465107,424432,479898,467828,420718,492388,457435,471595}', '2024-05-02 14:48:05.250248-07', '2024-04-25 10:12:05.250248-07', '2024-04-19 21:33:05.250248-07', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('geovanni.5vzv95m0@example.net', '彭妍芳', 'unknown', '2024-01-01 00:12:37.116597-08', '2024-04-29 17:45:37.116598-07', '2024-04-27 16:34:37.116598-07', '{189528,147032,140705,103083,175340,118971,129264,158263,139518,151782}', '{297585,262683,273542,282734,279557,270108,227974,280816,298923,235088,298434,290197,264491,293036,214243,286040,250275,287465,297554,212668,228284,229960,223384,253119,250362,225315,217561,220838,229174,276197,294355,286183,268909,254694,277701,282131,211357,214646}', '{342512,340695,325191,303748,385119,375850,349599,387749,373816,394806,397983,397897,365329,387752,394989,325002,345460,326310,341583,361305,348486,339553,369531,395275,305683,391877,356869,380703,326362,358023,344479,387764,376585,349835,389953,387280}', '{478867,442670,446218,484374,494199,444382,446399,425173,426755,495658,484456,440191,451559,490126,482470,444729,489559,463206,412587,457012,442888,477654,420532,429374,487589,452919,409702,451355,468505,491294,455748,467927,471587,475080,414713,488057,492803,497814,461177,493927,494783,453196,472992,488331,455163,446365,484445,430986,449364}', '2024-04-08 23:24:37.1166-07', '2024-04-24 09:32:37.1166-07', '2024-04-18 12:46:37.1166-07');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('geovanni.5vzv95m0@example.net', '彭妍芳', 'unknown', '2024-01-01 00:12:37.116597-08', '2024-04-29 17:45:37.116598-07', '2024-04-27 16:34:37.116598-07', '{189528,147032,140705,103083,175340,118971,129264,158263,139518,151782}', '{297585,262683,273542,282734,279557,270108,227974,280816,298923,235088,298434,290197,264491,293036,214243,286040,250275,287465,297554,212668,228284,229960,223384,253119,250362,225315,217561,220838,229174,276197,294355,286183,268909,254694,277701,282131,211357,214646}', '{342512,340695,325191,303748,385119,375850,349599,387749,373816,394806,397983,397897,365329,387752,394989,325002,345460,326310,341583,361305,348486,339553,369531,395275,305683,391877,356869,380703,326362,358023,344479,387764,376585,349835,389953,387280}', '{478867,442670,446218,484374,494199,444382,446399,425173,426755,495658,484456,440191,451559,490126,482470,444729,489559,463206,412587,457012,442888,477654,420532,429374,487589,452919,409702,451355,468505,491294,455748,467927,471587,475080,414713,488057,492803,497814,461177,493927,494783,453196,472992,488331,455163,446365,484445,430986,449364}', '2024-04-08 23:24:37.1166-07', '2024-04-24 09:32:37.1166-07', '2024-04-18 12:46:37.1166-07', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('roslyn.id0gy5vg@example.com', '方世康', 'unknown', '2024-01-01 00:12:53.288832-08', '2024-04-12 17:14:53.288832-07', '2024-02-24 18:46:53.288833-08', '{117310,147540,163557,135017,165459,147011,129250,104392,114720,168674,126891,132565,134209,199126}', '{224403,228100,238005,262566,232601,220965,269226,269361}', '{303906,387718,359335,323801,381601,368187,307122,389200,324180,327822,340389,371561,323426,393693,327885,381024,376187}', '{489852,417986,436374,409544,405710,408231,487933,455617,453594}', '2024-04-17 05:36:53.288834-07', '2024-05-03 20:24:53.288834-07', '2024-02-29 16:37:53.288834-08');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('roslyn.id0gy5vg@example.com', '方世康', 'unknown', '2024-01-01 00:12:53.288832-08', '2024-04-12 17:14:53.288832-07', '2024-02-24 18:46:53.288833-08', '{117310,147540,163557,135017,165459,147011,129250,104392,114720,168674,126891,132565,134209,199126}', '{224403,228100,238005,262566,232601,220965,269226,269361}', '{303906,387718,359335,323801,381601,368187,307122,389200,324180,327822,340389,371561,323426,393693,327885,381024,376187}', '{489852,417986,436374,409544,405710,408231,487933,455617,453594}', '2024-04-17 05:36:53.288834-07', '2024-05-03 20:24:53.288834-07', '2024-02-29 16:37:53.288834-08', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('keegan.ukwgj7ir@example.com', '蔡月霞', 'unknown', '2024-01-01 00:13:36.258637-08', '2024-05-03 09:17:36.258637-07', '2024-03-23 04:18:36.258637-07', '{176496,122909,116639,152857,190060,194872,195683,134411,135284,149743,159313,120426,158380,105287,196840,162655,185165,116493,168984,187858,170756,129550,171959,139831,126009,135431,185053,148837,125709,175344,107762,182441,147032,184247,171606,126255}', '{278193,200893,234538,259952,284633,256601,294147,248888,224750,270910,241959,240099,254646,292077,285690,218553,249816,264189,256075,222679,238315,266658,271045,205473,268302,224298,280262,261867,288715,277060,280648,234792,200806,237923,251600,260075}', '{377738,312312,331489,321249,366427,361570,358717,321352,378384,380895}', '{432972,408822,420474,455735,403825,489004,493741,459654,482438,453882,401536,409383,492560,409034,407104,493176,478973,486459,464898,444936,494755,459755,444037,439052,474863,405651,480710,467767,417182,407671,407081}', '2024-04-12 22:22:36.25864-07', '2024-04-25 12:45:36.25864-07', '2024-04-28 19:43:36.258641-07');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('keegan.ukwgj7ir@example.com', '蔡月霞', 'unknown', '2024-01-01 00:13:36.258637-08', '2024-05-03 09:17:36.258637-07', '2024-03-23 04:18:36.258637-07', '{176496,122909,116639,152857,190060,194872,195683,134411,135284,149743,159313,120426,158380,105287,196840,162655,185165,116493,168984,187858,170756,129550,171959,139831,126009,135431,185053,148837,125709,175344,107762,182441,147032,184247,171606,126255}', '{278193,200893,234538,259952,284633,256601,294147,248888,224750,270910,241959,240099,254646,292077,285690,218553,249816,264189,256075,222679,238315,266658,271045,205473,268302,224298,280262,261867,288715,277060,280648,234792,200806,237923,251600,260075}', '{377738,312312,331489,321249,366427,361570,358717,321352,378384,380895}', '{432972,408822,420474,455735,403825,489004,493741,459654,482438,453882,401536,409383,492560,409034,407104,493176,478973,486459,464898,444936,494755,459755,444037,439052,474863,405651,480710,467767,417182,407671,407081}', '2024-04-12 22:22:36.25864-07', '2024-04-25 12:45:36.25864-07', '2024-04-28 19:43:36.258641-07', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('elvera.0qb4waop@example.org', '林杰翔', 'unknown', '2024-01-01 00:14:14.404997-08', '2024-04-08 00:20:14.404997-07', '2024-04-02 17:25:14.404997-07', '{141463,168897,184782,141442,116021,198383,122736,147652,106764,116845,113953,111977,105133,120053,100557,121436,199639,103728,165813,195817,114637,195982,122899,191215,177658,152918,159930,105762,162631,187804,103171,171958,176708,130437,174035,120774}', '{230222,248838,251263,277141,292760,231285,276049,269900,280331,243864,271219,296027,208099,279597,219166,240949,262549,299923,230117,202130,208610,282471,263084,217600,230604,292764,254454,241097,253114,282791,296684,246461,246668,255874,272044,241444,285097,258850,234952,215256,200227,296847,241143}', '{312014,394757,350318,378104,352975,312032,382730,364718,305513,392240,352860,351671,353752,320319,334242,338215,377215,350146,314645,386455,357891,381620,328779,348138,365215,316360,388926,373916,326185}', '{460970,454075,426985,430296,406465,445917,487715,490805,454185,419308,417082,431263,499680}', '2024-04-06 14:50:14.404999-07', '2024-04-23 15:23:14.404999-07', '2024-04-08 11:12:14.404999-07');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('elvera.0qb4waop@example.org', '林杰翔', 'unknown', '2024-01-01 00:14:14.404997-08', '2024-04-08 00:20:14.404997-07', '2024-04-02 17:25:14.404997-07', '{141463,168897,184782,141442,116021,198383,122736,147652,106764,116845,113953,111977,105133,120053,100557,121436,199639,103728,165813,195817,114637,195982,122899,191215,177658,152918,159930,105762,162631,187804,103171,171958,176708,130437,174035,120774}', '{230222,248838,251263,277141,292760,231285,276049,269900,280331,243864,271219,296027,208099,279597,219166,240949,262549,299923,230117,202130,208610,282471,263084,217600,230604,292764,254454,241097,253114,282791,296684,246461,246668,255874,272044,241444,285097,258850,234952,215256,200227,296847,241143}', '{312014,394757,350318,378104,352975,312032,382730,364718,305513,392240,352860,351671,353752,320319,334242,338215,377215,350146,314645,386455,357891,381620,328779,348138,365215,316360,388926,373916,326185}', '{460970,454075,426985,430296,406465,445917,487715,490805,454185,419308,417082,431263,499680}', '2024-04-06 14:50:14.404999-07', '2024-04-23 15:23:14.404999-07', '2024-04-08 11:12:14.404999-07', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('berta.92jen5yq@example.net', '冯梅雪', 'unknown', '2024-01-01 00:14:17.756747-08', '2024-04-15 11:10:17.756748-07', '2024-02-29 04:35:17.756748-08', '{169731,190389,134336,131700,108119,149116,195826,162518,108202,122224,110712,191274,153853,132481,124446,128769,193229,166642,135613,172420,113772,162454,137349,179358,145763,135893,177170,180909,124054,140364,144405,119095,166259,110583,126694,128464,118564,134666,157053,158651,135556,111477}', '{257236,281293,221966,273486,291551,221535,266135,247409,246921,280461,278477,247983,207709,250171,252905,251764,260715,262729,207664,293693,283971,220174,201763,218494,242285,283048,283928,204137,291654,288640}', '{341514,337082,345719,321738,316689,389379,363579}', '{441889,428955,457784,446656,421687,451934,477179,481795,486811,400964,461070,428723,454537,475385,478030,411831,495962,465711}', '2024-03-28 18:49:17.756754-07', '2024-04-27 04:59:17.756754-07', '2024-05-02 09:56:17.756754-07');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('berta.92jen5yq@example.net', '冯梅雪', 'unknown', '2024-01-01 00:14:17.756747-08', '2024-04-15 11:10:17.756748-07', '2024-02-29 04:35:17.756748-08', '{169731,190389,134336,131700,108119,149116,195826,162518,108202,122224,110712,191274,153853,132481,124446,128769,193229,166642,135613,172420,113772,162454,137349,179358,145763,135893,177170,180909,124054,140364,144405,119095,166259,110583,126694,128464,118564,134666,157053,158651,135556,111477}', '{257236,281293,221966,273486,291551,221535,266135,247409,246921,280461,278477,247983,207709,250171,252905,251764,260715,262729,207664,293693,283971,220174,201763,218494,242285,283048,283928,204137,291654,288640}', '{341514,337082,345719,321738,316689,389379,363579}', '{441889,428955,457784,446656,421687,451934,477179,481795,486811,400964,461070,428723,454537,475385,478030,411831,495962,465711}', '2024-03-28 18:49:17.756754-07', '2024-04-27 04:59:17.756754-07', '2024-05-02 09:56:17.756754-07', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('clovis.92bi0gps@example.com', '邱翠竹', 'unknown', '2024-01-01 00:14:30.571935-08', '2024-05-01 01:34:30.571935-07', '2024-04-09 18:30:30.571936-07', '{162876,139424,115591,120480,131605,193921,167909,108864,101824,169233,184421,127799,140802,169561,149887,117415,119516,148566,151340,175017,173289,173142,101747,128269,107384,118743,107596,174488,104292,136008,117355,146894,153707,197089,145323,129499,126032,111810,182337,198624,101869,121717,182051,142412,145949,191252,185179,167723,194724}', '{233605,204996,265607,258041,222102,267264,212160,270236,207308,237972,295502,218082,262359,290721,223730,288643,234692,270227,268218,290668,265040,284981,244945,244949,260525,269322,289876,214008,200567,280155,278651,222432,287445,281012,216618,202404,200814,274456,288877,200691,288751,234669,297966,256357,281447}', '{391036,377537,378513,364501,363493,302100,339655,372284,356642,335494,343642,361579,387428,398363,399330,302767,354104,327221,325898,382516,304845,320047,358898,325557,391252,301092,379651,331082,371925,368458,314513,380544,365370,371323,399937,370941,366743,330075,304442,372850,358345,343105,394944,381815}', '{407401,441036,409121,419209,476003,428107,421551,453764,401309,495206,489460,433115,465574,444323,491723,495563,441904,437468,466408,470701,436600,445598,454517,475355,460989,406002,449409,492299}', '2024-04-12 00:17:30.571938-07', '2024-05-01 16:57:30.571938-07', '2024-04-29 06:23:30.571939-07');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('clovis.92bi0gps@example.com', '邱翠竹', 'unknown', '2024-01-01 00:14:30.571935-08', '2024-05-01 01:34:30.571935-07', '2024-04-09 18:30:30.571936-07', '{162876,139424,115591,120480,131605,193921,167909,108864,101824,169233,184421,127799,140802,169561,149887,117415,119516,148566,151340,175017,173289,173142,101747,128269,107384,118743,107596,174488,104292,136008,117355,146894,153707,197089,145323,129499,126032,111810,182337,198624,101869,121717,182051,142412,145949,191252,185179,167723,194724}', '{233605,204996,265607,258041,222102,267264,212160,270236,207308,237972,295502,218082,262359,290721,223730,288643,234692,270227,268218,290668,265040,284981,244945,244949,260525,269322,289876,214008,200567,280155,278651,222432,287445,281012,216618,202404,200814,274456,288877,200691,288751,234669,297966,256357,281447}', '{391036,377537,378513,364501,363493,302100,339655,372284,356642,335494,343642,361579,387428,398363,399330,302767,354104,327221,325898,382516,304845,320047,358898,325557,391252,301092,379651,331082,371925,368458,314513,380544,365370,371323,399937,370941,366743,330075,304442,372850,358345,343105,394944,381815}', '{407401,441036,409121,419209,476003,428107,421551,453764,401309,495206,489460,433115,465574,444323,491723,495563,441904,437468,466408,470701,436600,445598,454517,475355,460989,406002,449409,492299}', '2024-04-12 00:17:30.571938-07', '2024-05-01 16:57:30.571938-07', '2024-04-29 06:23:30.571939-07', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('jettie.4922mo5l@example.com', '沈冰婉', 'unknown', '2024-01-01 00:15:30.900188-08', '2024-04-21 08:47:30.900188-07', '2024-03-22 08:21:30.900189-07', '{198094,192563,170544,125256,175327,199576,156801,115711,146763,160545,105647,169472,155082,132908,111739,148509,163281,148668,102567,195647,154628,157085,152429,191436,189378,185762,198291,125648,152444,107234,176310,164432,112483,118896,188456,151511,123373,153130,105947,145088,169824,197814,162105,108032,174698,194740,147499,118098,142352}', '{244933,235320,216246,267330,238135,256634,245079,279145,233205,208241,257929,224240,266463,244624,288887,262641,234557,286427,213643,248973,238875,259587,231145,281496,273901,224426,292835,213699,206183,226386,211001,250122,255426,276295,277369,270969,203366,249880,208125,263888,274860}', '{384019,385944,376277,316913,330337,306516,311182,315514,313401,308265,327779,347357,351608}', '{461956,488406,401096,424642,458981,470544,473115}', '2024-04-11 15:01:30.900191-07', '2024-05-03 13:10:30.900191-07', '2024-05-05 11:21:30.900192-07');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('jettie.4922mo5l@example.com', '沈冰婉', 'unknown', '2024-01-01 00:15:30.900188-08', '2024-04-21 08:47:30.900188-07', '2024-03-22 08:21:30.900189-07', '{198094,192563,170544,125256,175327,199576,156801,115711,146763,160545,105647,169472,155082,132908,111739,148509,163281,148668,102567,195647,154628,157085,152429,191436,189378,185762,198291,125648,152444,107234,176310,164432,112483,118896,188456,151511,123373,153130,105947,145088,169824,197814,162105,108032,174698,194740,147499,118098,142352}', '{244933,235320,216246,267330,238135,256634,245079,279145,233205,208241,257929,224240,266463,244624,288887,262641,234557,286427,213643,248973,238875,259587,231145,281496,273901,224426,292835,213699,206183,226386,211001,250122,255426,276295,277369,270969,203366,249880,208125,263888,274860}', '{384019,385944,376277,316913,330337,306516,311182,315514,313401,308265,327779,347357,351608}', '{461956,488406,401096,424642,458981,470544,473115}', '2024-04-11 15:01:30.900191-07', '2024-05-03 13:10:30.900191-07', '2024-05-05 11:21:30.900192-07', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('leonor.ug37sohn@example.net', '于韵杰', 'unknown', '2024-01-01 00:15:48.160778-08', '2024-05-03 11:13:48.160778-07', '2024-04-20 01:14:48.160778-07', '{176732,189402}', '{298764,211638,273759,232468,251499,238365,230398,235888,220793,213329,256728}', '{379102,352421,300893,301502,315222,372068,340947,327928,380510,338979,387343,349587,364987,326677}', '{405171,419673,433328,420588,453492,466444,416174,439021,454558,437735,471421,412140,461772,472612,436990,443204,409750,406767,426326,444540,415287,448479,453473,469610,487812,412903,438438,432232,474931,437901,418327,428333,440155,491739,415100,453318,445638,448367,457778,453518,457799,419609,499146,468671,448504,420705,456267,468052}', '2024-04-28 07:31:48.16078-07', '2024-04-27 11:10:48.16078-07', '2024-02-25 17:53:48.16078-08');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('leonor.ug37sohn@example.net', '于韵杰', 'unknown', '2024-01-01 00:15:48.160778-08', '2024-05-03 11:13:48.160778-07', '2024-04-20 01:14:48.160778-07', '{176732,189402}', '{298764,211638,273759,232468,251499,238365,230398,235888,220793,213329,256728}', '{379102,352421,300893,301502,315222,372068,340947,327928,380510,338979,387343,349587,364987,326677}', '{405171,419673,433328,420588,453492,466444,416174,439021,454558,437735,471421,412140,461772,472612,436990,443204,409750,406767,426326,444540,415287,448479,453473,469610,487812,412903,438438,432232,474931,437901,418327,428333,440155,491739,415100,453318,445638,448367,457778,453518,457799,419609,499146,468671,448504,420705,456267,468052}', '2024-04-28 07:31:48.16078-07', '2024-04-27 11:10:48.16078-07', '2024-02-25 17:53:48.16078-08', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('dillan.jhgg341o@example.net', '李杰翔', 'unknown', '2024-01-01 00:16:20.049732-08', '2024-04-28 18:53:20.049732-07', '2024-04-14 06:04:20.049732-07', '{198560,156709,125326,181942,176368,165231,101544,176309,162475,195881,115327,194857,107966,198151,150620,120100,151832,139292,141602,163173}', '{278417,219828,215216,256328,294168,245955,201127,259284,200031,270573}', '{374943,363710,337430,336503,308095,371267,346764,337783,302623,319848,307966,387063,388872,330531,336802,348234,359451,316744,374620,372637,388397,344635,336731}', '{463640}', '2024-04-27 23:23:20.049733-07', '2024-04-22 17:08:20.049733-07', '2024-03-14 21:03:20.049733-07');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('dillan.jhgg341o@example.net', '李杰翔', 'unknown', '2024-01-01 00:16:20.049732-08', '2024-04-28 18:53:20.049732-07', '2024-04-14 06:04:20.049732-07', '{198560,156709,125326,181942,176368,165231,101544,176309,162475,195881,115327,194857,107966,198151,150620,120100,151832,139292,141602,163173}', '{278417,219828,215216,256328,294168,245955,201127,259284,200031,270573}', '{374943,363710,337430,336503,308095,371267,346764,337783,302623,319848,307966,387063,388872,330531,336802,348234,359451,316744,374620,372637,388397,344635,336731}', '{463640}', '2024-04-27 23:23:20.049733-07', '2024-04-22 17:08:20.049733-07', '2024-03-14 21:03:20.049733-07', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('ladarius.0xzt52tl@example.org', '姜德轩', 'unknown', '2024-01-01 00:16:28.79607-08', '2024-04-26 23:34:28.79607-07', '2024-03-24 19:54:28.796071-07', '{166419}', '{254469,228425,257447,262020,265453,214989,263713,299371,294224,221914,242466}', '{}', '{449860,472900,498407,496939,494364,448722,434247,408646,457976,435243,458600,406935,473538,418993,411346,444929,453868,424895,405041,488596,428460,457681,407609,426167,404343,463869,499308,440536,423786,410214,460082,479175,462564,415761,411756,495054,410460,452665,416178,441466,494552,477392,437122,455604,412620}', '2024-04-14 19:42:28.796073-07', '2024-04-20 21:20:28.796074-07', '2024-02-09 04:30:28.796074-08');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('ladarius.0xzt52tl@example.org', '姜德轩', 'unknown', '2024-01-01 00:16:28.79607-08', '2024-04-26 23:34:28.79607-07', '2024-03-24 19:54:28.796071-07', '{166419}', '{254469,228425,257447,262020,265453,214989,263713,299371,294224,221914,242466}', '{}', '{449860,472900,498407,496939,494364,448722,434247,408646,457976,435243,458600,406935,473538,418993,411346,444929,453868,424895,405041,488596,428460,457681,407609,426167,404343,463869,499308,440536,423786,410214,460082,479175,462564,415761,411756,495054,410460,452665,416178,441466,494552,477392,437122,455604,412620}', '2024-04-14 19:42:28.796073-07', '2024-04-20 21:20:28.796074-07', '2024-02-09 04:30:28.796074-08', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('lemuel.3a3uw37o@example.net', '邵弘靖', 'unknown', '2024-01-01 00:17:03.291011-08', '2024-04-10 20:53:03.291012-07', '2024-04-24 05:25:03.291012-07', '{194748,191366}', '{260785,209017,256125,245841,299604,248573,244244,214503,244921,261509,221695,274373,238884,283694,299038,255420,242577}', '{339460,321680,372420,375756,362394,363671,328848,327177,372189,397366,301956,337906,391241,373387,320339,383572,377856,370641,334632,379122,304916,379824,318597,386796,367860,356918,368091,333830,350456,388951,364431,395617,397801,373837,331419}', '{468547,430809,497637,439711,472510,469022,411953,427751,425389,488858,498732,463967,420588,426432,453045,481345,473328,451002,445149,462489,439565,473620,418502,461579,411455,486007,489408,458535,401744,465894,422257,401545,488974,403939,484333,461400,423166,446109,484890,458079,447116,421002,407965,445614,454429,404136,420187,442769,402400}', '2024-04-15 15:22:03.291013-07', '2024-04-30 10:52:03.291014-07', '2024-04-18 04:08:03.291014-07');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('lemuel.3a3uw37o@example.net', '邵弘靖', 'unknown', '2024-01-01 00:17:03.291011-08', '2024-04-10 20:53:03.291012-07', '2024-04-24 05:25:03.291012-07', '{194748,191366}', '{260785,209017,256125,245841,299604,248573,244244,214503,244921,261509,221695,274373,238884,283694,299038,255420,242577}', '{339460,321680,372420,375756,362394,363671,328848,327177,372189,397366,301956,337906,391241,373387,320339,383572,377856,370641,334632,379122,304916,379824,318597,386796,367860,356918,368091,333830,350456,388951,364431,395617,397801,373837,331419}', '{468547,430809,497637,439711,472510,469022,411953,427751,425389,488858,498732,463967,420588,426432,453045,481345,473328,451002,445149,462489,439565,473620,418502,461579,411455,486007,489408,458535,401744,465894,422257,401545,488974,403939,484333,461400,423166,446109,484890,458079,447116,421002,407965,445614,454429,404136,420187,442769,402400}', '2024-04-15 15:22:03.291013-07', '2024-04-30 10:52:03.291014-07', '2024-04-18 04:08:03.291014-07', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('nikolas.e8a857zc@example.net', '崔翰颜', 'unknown', '2024-01-01 00:17:25.008291-08', '2024-04-10 04:42:25.008292-07', '2024-02-26 12:07:25.008292-08', '{100303,166997,123755,128501,187498,195638,188257,123568,114842,108741,170536,119166,158961,169399,113172,162397,148544,102802,121768,128542,171533,135808,106067,179473,125376,192969,181439,180486,145526,177684,142380,167787}', '{230118,293437,237241,214353,219155,217328,200483,272401,246354,294969,235185,231762,252968,293738,206625,235961,253901,295859,218548,242540,261943,276578,257226,252521,298340,242185,293011,286085,262542,264589,296376,253333,213308,207204,241366,235688,203829,243017,220152,279452,291384,276328}', '{369464,381028,339600,362257,370968,380451,334745,391539,365244,332476,398594,389064,305793,396066,374672,376551,374918,306511,359126,318737,303348,354448,393845,373095}', '{467224,487944,475663,403892,477345,470490,448928,429854,486875,476992,477965,451346,425283,480965,419720,485769,494593,439614,494334,427124,415542,451423,471614,445773,485038,476438,463173,491989,450648,485563,477136,482539,401747,479774,450017,437211,474035,432487,459882,434899,477401,417787,481430,408758,417546}', '2024-04-01 05:15:25.008295-07', '2024-04-21 03:07:25.008295-07', '2024-03-09 06:52:25.008295-08');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('nikolas.e8a857zc@example.net', '崔翰颜', 'unknown', '2024-01-01 00:17:25.008291-08', '2024-04-10 04:42:25.008292-07', '2024-02-26 12:07:25.008292-08', '{100303,166997,123755,128501,187498,195638,188257,123568,114842,108741,170536,119166,158961,169399,113172,162397,148544,102802,121768,128542,171533,135808,106067,179473,125376,192969,181439,180486,145526,177684,142380,167787}', '{230118,293437,237241,214353,219155,217328,200483,272401,246354,294969,235185,231762,252968,293738,206625,235961,253901,295859,218548,242540,261943,276578,257226,252521,298340,242185,293011,286085,262542,264589,296376,253333,213308,207204,241366,235688,203829,243017,220152,279452,291384,276328}', '{369464,381028,339600,362257,370968,380451,334745,391539,365244,332476,398594,389064,305793,396066,374672,376551,374918,306511,359126,318737,303348,354448,393845,373095}', '{467224,487944,475663,403892,477345,470490,448928,429854,486875,476992,477965,451346,425283,480965,419720,485769,494593,439614,494334,427124,415542,451423,471614,445773,485038,476438,463173,491989,450648,485563,477136,482539,401747,479774,450017,437211,474035,432487,459882,434899,477401,417787,481430,408758,417546}', '2024-04-01 05:15:25.008295-07', '2024-04-21 03:07:25.008295-07', '2024-03-09 06:52:25.008295-08', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('joan.9vmzmfz6@example.org', '任思芳', 'unknown', '2024-01-01 00:18:03.777192-08', '2024-05-01 14:07:03.777192-07', '2024-04-18 05:45:03.777192-07', '{161189,153266,101040,158153,130100,141344,106998,189147,140173,144480,171689,192753,190220,146203,161837,167211,137548,119660,192843,109443,122139,107478,114860,147521}', '{238845,252250,208584,205902,287938,222288,287044,271336,219314}', '{398194,347025,353649,346502,306165,306862,388297,303275,329342,357450,392207,358259,319240,338752,346891,317812,314397,390873,352893,314571,347379,314611,358329,308100,365887,358408,327370,351126,339277,325591,344662,308946,350960,308205,369824,320556}', '{430010,416213,498016,411695,427185,455399,466805,419052,419620,452196,407600,419311,483774,456467,414983,467950,459603,415636,425453,448575,442140,496640,437796,453958,432925,415554,490467,459151,413476,416367}', '2024-04-13 07:56:03.777194-07', '2024-05-03 18:26:03.777194-07', '2024-04-11 14:27:03.777194-07');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('joan.9vmzmfz6@example.org', '任思芳', 'unknown', '2024-01-01 00:18:03.777192-08', '2024-05-01 14:07:03.777192-07', '2024-04-18 05:45:03.777192-07', '{161189,153266,101040,158153,130100,141344,106998,189147,140173,144480,171689,192753,190220,146203,161837,167211,137548,119660,192843,109443,122139,107478,114860,147521}', '{238845,252250,208584,205902,287938,222288,287044,271336,219314}', '{398194,347025,353649,346502,306165,306862,388297,303275,329342,357450,392207,358259,319240,338752,346891,317812,314397,390873,352893,314571,347379,314611,358329,308100,365887,358408,327370,351126,339277,325591,344662,308946,350960,308205,369824,320556}', '{430010,416213,498016,411695,427185,455399,466805,419052,419620,452196,407600,419311,483774,456467,414983,467950,459603,415636,425453,448575,442140,496640,437796,453958,432925,415554,490467,459151,413476,416367}', '2024-04-13 07:56:03.777194-07', '2024-05-03 18:26:03.777194-07', '2024-04-11 14:27:03.777194-07', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('bernadette.re4g3jgb@example.com', '谢翠竹', 'unknown', '2024-01-01 00:18:15.854668-08', '2024-04-28 09:39:15.854668-07', '2024-04-29 04:44:15.854668-07', '{139094,189780,183563,185949,181480,138651,160741,159998,162007,171192,181768,159376,141334,192270,136343,140269,160320,114095,109112,155790,134897,143154,189717,172786,165518}', '{269652,217405,285069,276707,268422,287146,218812,200266,269038,292415,260413,247440,210450,222784,239589,263620,250331,297942,223096,214271,265433,214987,277698,202196,223524,205297,275205,249936,247925,206412,219352,239662,282271,263155,257257,279970,290328,289178,219733,274846,285873,233067}', '{317905,399385,363875,314322,391051,322122,382162,346111,307778,359586,357461,307151,341532,317455,354968,380617,327248,377469,302275,342566,346625,360406,360866,367533,365314,318481,367362,348332,321938,325276,336373,317115,370139,326537,388217,356372,367559,388072,362315,339440,317503}', '{417966,407126,457023,479664,401367,469595,444937,471866,429101}', '2024-04-14 19:24:15.85467-07', '2024-04-22 00:41:15.85467-07', '2024-04-21 11:29:15.85467-07');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('bernadette.re4g3jgb@example.com', '谢翠竹', 'unknown', '2024-01-01 00:18:15.854668-08', '2024-04-28 09:39:15.854668-07', '2024-04-29 04:44:15.854668-07', '{139094,189780,183563,185949,181480,138651,160741,159998,162007,171192,181768,159376,141334,192270,136343,140269,160320,114095,109112,155790,134897,143154,189717,172786,165518}', '{269652,217405,285069,276707,268422,287146,218812,200266,269038,292415,260413,247440,210450,222784,239589,263620,250331,297942,223096,214271,265433,214987,277698,202196,223524,205297,275205,249936,247925,206412,219352,239662,282271,263155,257257,279970,290328,289178,219733,274846,285873,233067}', '{317905,399385,363875,314322,391051,322122,382162,346111,307778,359586,357461,307151,341532,317455,354968,380617,327248,377469,302275,342566,346625,360406,360866,367533,365314,318481,367362,348332,321938,325276,336373,317115,370139,326537,388217,356372,367559,388072,362315,339440,317503}', '{417966,407126,457023,479664,401367,469595,444937,471866,429101}', '2024-04-14 19:24:15.85467-07', '2024-04-22 00:41:15.85467-07', '2024-04-21 11:29:15.85467-07', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('scottie.yujsxg3e@example.org', '向翠竹', 'unknown', '2024-01-01 00:18:40.493019-08', '2024-04-14 13:08:40.49302-07', '2024-04-21 22:20:40.49302-07', '{164654,129697,149095,111641,182105,115461,174542,127265,104931,146055,132682,117776,136719,145242,135612,127197,178465,126718,182053,179052,198477,103114,134204,126326,179093,101524,121013,177711,157528,182824,151104,186098,172912,175782}', '{275785,289903,243643,230274,292747,274841,298192,256551,202808,251735,210894,255661,264522,269282,201946,295108,261625,230441,289074,266006,265454,226636,213177,231198,289594,248245,282825,206124,257238}', '{374660,352370,397275,397507,378063,324626,325146,343290,354356,307075,364219,345456,320773,346323,370322,380191,326303,334628,325260,354234,392491,323907,328723,334466,376509,306824,304884,361663,302806,331852,363580,399465,339718,304399,364252,385368,305157,367063,300884,301534,351646,321087,390930,343369,374529,378332,371304}', '{464585,453969,489650,449189,455430,469058,483884,439627,439923,421169,454238,418168,401287,480276,495106,441559,426255,413018,423804,456093,438216,457147,480993,447387,429716,476689,415995,433067,471240,481694,496845,490147,450163,428827,467143,452042,468471,467601,477131,476556}', '2024-03-27 07:49:40.493023-07', '2024-05-02 16:23:40.493024-07', '2024-04-26 15:35:40.493024-07');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('scottie.yujsxg3e@example.org', '向翠竹', 'unknown', '2024-01-01 00:18:40.493019-08', '2024-04-14 13:08:40.49302-07', '2024-04-21 22:20:40.49302-07', '{164654,129697,149095,111641,182105,115461,174542,127265,104931,146055,132682,117776,136719,145242,135612,127197,178465,126718,182053,179052,198477,103114,134204,126326,179093,101524,121013,177711,157528,182824,151104,186098,172912,175782}', '{275785,289903,243643,230274,292747,274841,298192,256551,202808,251735,210894,255661,264522,269282,201946,295108,261625,230441,289074,266006,265454,226636,213177,231198,289594,248245,282825,206124,257238}', '{374660,352370,397275,397507,378063,324626,325146,343290,354356,307075,364219,345456,320773,346323,370322,380191,326303,334628,325260,354234,392491,323907,328723,334466,376509,306824,304884,361663,302806,331852,363580,399465,339718,304399,364252,385368,305157,367063,300884,301534,351646,321087,390930,343369,374529,378332,371304}', '{464585,453969,489650,449189,455430,469058,483884,439627,439923,421169,454238,418168,401287,480276,495106,441559,426255,413018,423804,456093,438216,457147,480993,447387,429716,476689,415995,433067,471240,481694,496845,490147,450163,428827,467143,452042,468471,467601,477131,476556}', '2024-03-27 07:49:40.493023-07', '2024-05-02 16:23:40.493024-07', '2024-04-26 15:35:40.493024-07', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('vance.tdftx6fo@example.net', '陆明健', 'unknown', '2024-01-01 00:19:02.997479-08', '2024-04-21 04:28:02.99748-07', '2024-02-10 20:10:02.99748-08', '{100451,102490,189087,122445,183965,100238,113765,112868,195238,117860,115207,125539,133915,183207,199445,194914,134710,110345,157600,162762,133803,198115,127630,133007,194279,153708,181278,162411,172634}', '{217906,239334,273706,233045,284888,203706,232011,251159,283363,235672,202989,286014,261232,266517,222409,250562,214179,291655,206657,270557,230470,233353,218169,277572,252873,234256,256932,298330,282769,215229,243992,204288,281344,288740,270524,224807}', '{358002,365800,348340,382612,392574,375627,377948,344183,374062,373877,389413,355865,349259,301291,351774,333890}', '{483347,407316,490423,464645,419853,458110,448272,422199,425315,416171,446724,491240,441664,416600,407822,438841,430402,411398,470856,423252,470232}', '2024-04-28 15:27:02.997482-07', '2024-04-25 23:48:02.997482-07', '2024-02-13 06:19:02.997482-08');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('vance.tdftx6fo@example.net', '陆明健', 'unknown', '2024-01-01 00:19:02.997479-08', '2024-04-21 04:28:02.99748-07', '2024-02-10 20:10:02.99748-08', '{100451,102490,189087,122445,183965,100238,113765,112868,195238,117860,115207,125539,133915,183207,199445,194914,134710,110345,157600,162762,133803,198115,127630,133007,194279,153708,181278,162411,172634}', '{217906,239334,273706,233045,284888,203706,232011,251159,283363,235672,202989,286014,261232,266517,222409,250562,214179,291655,206657,270557,230470,233353,218169,277572,252873,234256,256932,298330,282769,215229,243992,204288,281344,288740,270524,224807}', '{358002,365800,348340,382612,392574,375627,377948,344183,374062,373877,389413,355865,349259,301291,351774,333890}', '{483347,407316,490423,464645,419853,458110,448272,422199,425315,416171,446724,491240,441664,416600,407822,438841,430402,411398,470856,423252,470232}', '2024-04-28 15:27:02.997482-07', '2024-04-25 23:48:02.997482-07', '2024-02-13 06:19:02.997482-08', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('stacey.851fab12@example.com', '覃俊龙', 'unknown', '2024-01-01 00:19:06.310779-08', '2024-04-27 10:53:06.31078-07', '2024-04-09 18:59:06.31078-07', '{152764,176831,182342,189149,185391,199861,139653,139170,171715,180584,175154,112329,128948,137245,120339,130196,185770,156529,173217,160934,186373,119385,187975,192337,140710,151967,121711,135256,137063,173874,174803,176926}', '{296555,210483}', '{328552,359933,363361}', '{490134,450142,498620,409696,430643,499484,422577,499855,448354,443019,426235,441458,437109,461083,445596,492792,424777,432026,466711,425283,456824,434519}', '2024-04-16 02:43:06.310787-07', '2024-04-23 05:12:06.310788-07', '2024-02-06 08:39:06.310788-08');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('stacey.851fab12@example.com', '覃俊龙', 'unknown', '2024-01-01 00:19:06.310779-08', '2024-04-27 10:53:06.31078-07', '2024-04-09 18:59:06.31078-07', '{152764,176831,182342,189149,185391,199861,139653,139170,171715,180584,175154,112329,128948,137245,120339,130196,185770,156529,173217,160934,186373,119385,187975,192337,140710,151967,121711,135256,137063,173874,174803,176926}', '{296555,210483}', '{328552,359933,363361}', '{490134,450142,498620,409696,430643,499484,422577,499855,448354,443019,426235,441458,437109,461083,445596,492792,424777,432026,466711,425283,456824,434519}', '2024-04-16 02:43:06.310787-07', '2024-04-23 05:12:06.310788-07', '2024-02-06 08:39:06.310788-08', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('jed.ang22k8q@example.com', '田文皓', 'unknown', '2024-01-01 00:19:42.571889-08', '2024-04-30 13:14:42.571889-07', '2024-04-29 13:32:42.571889-07', '{141921,156286,180220,185986,182286,145952,172292,152270,103122,126054,160152,170511,123488,130521,171454,106904,128199,105608,161402,148026,174204,131301,184616,125307,188824,164714,157020,153226,153149,126718,178396,159295,142313,134384,141010,149618,110634,182403,170784,149465,142701,146331,113058,193674}', '{216869,284179,240634,201044,250160,288485,228872,290500,266287,261956,279180,299193,250061,274284,260173,288511,266226,292609,221984,234304,285070,214571,226167,289042,200747,234251,236464,219643}', '{302647,394352,381176,321855,389223,360684,380025,333154,390180,362353,398008,395690,393699,328060,367766}', '{441871,408307,453207,485548,471606,406838,413367,428051,452200,435311,403932,439919,416698,412896,433270,409457,490458,496220,496593,433123,424122,487925,412164,431667,476827}', '2024-03-22 22:00:42.571891-07', '2024-05-02 19:17:42.571891-07', '2024-03-13 05:00:42.571891-07');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('jed.ang22k8q@example.com', '田文皓', 'unknown', '2024-01-01 00:19:42.571889-08', '2024-04-30 13:14:42.571889-07', '2024-04-29 13:32:42.571889-07', '{141921,156286,180220,185986,182286,145952,172292,152270,103122,126054,160152,170511,123488,130521,171454,106904,128199,105608,161402,148026,174204,131301,184616,125307,188824,164714,157020,153226,153149,126718,178396,159295,142313,134384,141010,149618,110634,182403,170784,149465,142701,146331,113058,193674}', '{216869,284179,240634,201044,250160,288485,228872,290500,266287,261956,279180,299193,250061,274284,260173,288511,266226,292609,221984,234304,285070,214571,226167,289042,200747,234251,236464,219643}', '{302647,394352,381176,321855,389223,360684,380025,333154,390180,362353,398008,395690,393699,328060,367766}', '{441871,408307,453207,485548,471606,406838,413367,428051,452200,435311,403932,439919,416698,412896,433270,409457,490458,496220,496593,433123,424122,487925,412164,431667,476827}', '2024-03-22 22:00:42.571891-07', '2024-05-02 19:17:42.571891-07', '2024-03-13 05:00:42.571891-07', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('eusebio.v84w6huz@example.com', '田采菡', 'unknown', '2024-01-01 00:20:00.427525-08', '2024-04-12 11:32:00.427526-07', '2024-04-30 12:05:00.427526-07', '{184993,169557,125476,143497,183463,181429,159705,140173,149340,156680,175988,185936,160819,191262,124798,118024,111640,163735,113474,178023,163242,144359,103787,192174,175781,172603,148654,163363,160230,199084,122616,120903}', '{254189,210284,239901,290256,295491,289404,225961,252176,272531,259057,251470,294051,230836,247866,205950,299467,280529,272847,204496}', '{345563,315197,385044,342053,368029,319724,361947,320840,351501,374974,366480,310936,340460,385570,373151,327038,360486,376127,376103,372875,336697,351801,330838,376930,344798,370483,340777,338981,372359,318512,330352}', '{451023,486721,480049,473371,486792,484517,466581,417201,491497,468616,476410}', '2024-04-09 05:17:00.427528-07', '2024-05-01 04:35:00.427528-07', '2024-03-04 16:39:00.427528-08');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('eusebio.v84w6huz@example.com', '田采菡', 'unknown', '2024-01-01 00:20:00.427525-08', '2024-04-12 11:32:00.427526-07', '2024-04-30 12:05:00.427526-07', '{184993,169557,125476,143497,183463,181429,159705,140173,149340,156680,175988,185936,160819,191262,124798,118024,111640,163735,113474,178023,163242,144359,103787,192174,175781,172603,148654,163363,160230,199084,122616,120903}', '{254189,210284,239901,290256,295491,289404,225961,252176,272531,259057,251470,294051,230836,247866,205950,299467,280529,272847,204496}', '{345563,315197,385044,342053,368029,319724,361947,320840,351501,374974,366480,310936,340460,385570,373151,327038,360486,376127,376103,372875,336697,351801,330838,376930,344798,370483,340777,338981,372359,318512,330352}', '{451023,486721,480049,473371,486792,484517,466581,417201,491497,468616,476410}', '2024-04-09 05:17:00.427528-07', '2024-05-01 04:35:00.427528-07', '2024-03-04 16:39:00.427528-08', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('lori.tllxx20m@example.net', '杜秋柔', 'unknown', '2024-01-01 00:22:00.818077-08', '2024-05-02 08:10:00.818077-07', '2024-04-26 03:48:00.818078-07', '{160122,114928,171463,156849,124238,198257,151590,107005,186652,179347,146908,188587}', '{283320,223622,264238,246416,293556,239844,293490,269489,267404,240240}', '{333764,300104,341871,303810,396170,335037,302259,361674,300320,307537,375358,358889,390981,397827,365381,368691,365786,338281,326074,331672,316883,323995,347066,339071,385486,345073,327574,353288,300948,343296,344847,398212,353944,385748,396109,337143,331123}', '{451490,485206,438284,433092,429684,493137,464582,455213,494042,498363,477883,455109,469989,403415,409798,444764,447643,444681,457072,442994,447877,400650,411862,447158,482538,498694,477343,454606,482365,436666,495993,401509,453461,479795,426511,456408,401385,482945}', '2024-04-02 23:46:00.818079-07', '2024-05-05 10:55:00.818079-07', '2024-05-01 04:42:00.81808-07');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('lori.tllxx20m@example.net', '杜秋柔', 'unknown', '2024-01-01 00:22:00.818077-08', '2024-05-02 08:10:00.818077-07', '2024-04-26 03:48:00.818078-07', '{160122,114928,171463,156849,124238,198257,151590,107005,186652,179347,146908,188587}', '{283320,223622,264238,246416,293556,239844,293490,269489,267404,240240}', '{333764,300104,341871,303810,396170,335037,302259,361674,300320,307537,375358,358889,390981,397827,365381,368691,365786,338281,326074,331672,316883,323995,347066,339071,385486,345073,327574,353288,300948,343296,344847,398212,353944,385748,396109,337143,331123}', '{451490,485206,438284,433092,429684,493137,464582,455213,494042,498363,477883,455109,469989,403415,409798,444764,447643,444681,457072,442994,447877,400650,411862,447158,482538,498694,477343,454606,482365,436666,495993,401509,453461,479795,426511,456408,401385,482945}', '2024-04-02 23:46:00.818079-07', '2024-05-05 10:55:00.818079-07', '2024-05-01 04:42:00.81808-07', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('joy.7ezndin3@example.org', '秦桦楠', 'unknown', '2024-01-01 00:22:04.079819-08', '2024-04-29 02:01:04.079819-07', '2024-02-10 04:48:04.079819-08', '{124539,172157,118425,134034,153242,196681}', '{242966,212848,253920,200681,296539,250050,237599,222176,224982,259777,268012,225238,202681,258069,228444,295095,218412,296653,253509,275312,210201,241996,290799,213751,265432,223684,230422,245649,278796,229836,209944,257766,292239,229896,214103}', '{318027,356199,364971,382455,309399,366384,358888,356357,351658,398965,371094,362281,323312,391144,309482,373912,332582,363601,353731,376353,317866,321194,388044,394000,339035,365283,349535,313181,307978,300237,310040,399509,337688,342423,352319,347989}', '{473116,408241,418501,430655,485533,491508,473892,425077,481780,481177,477214,458036,428052,403160,457864,479096,420303,408610,495422,465395,458940,416182,493309,428145,450722,427885,409972,425225,447948,434739,462702,418619,422956,440279,479312,462094,442339,405132}', '2024-04-18 21:13:04.079821-07', '2024-04-23 12:32:04.079821-07', '2024-03-30 14:02:04.079821-07');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('joy.7ezndin3@example.org', '秦桦楠', 'unknown', '2024-01-01 00:22:04.079819-08', '2024-04-29 02:01:04.079819-07', '2024-02-10 04:48:04.079819-08', '{124539,172157,118425,134034,153242,196681}', '{242966,212848,253920,200681,296539,250050,237599,222176,224982,259777,268012,225238,202681,258069,228444,295095,218412,296653,253509,275312,210201,241996,290799,213751,265432,223684,230422,245649,278796,229836,209944,257766,292239,229896,214103}', '{318027,356199,364971,382455,309399,366384,358888,356357,351658,398965,371094,362281,323312,391144,309482,373912,332582,363601,353731,376353,317866,321194,388044,394000,339035,365283,349535,313181,307978,300237,310040,399509,337688,342423,352319,347989}', '{473116,408241,418501,430655,485533,491508,473892,425077,481780,481177,477214,458036,428052,403160,457864,479096,420303,408610,495422,465395,458940,416182,493309,428145,450722,427885,409972,425225,447948,434739,462702,418619,422956,440279,479312,462094,442339,405132}', '2024-04-18 21:13:04.079821-07', '2024-04-23 12:32:04.079821-07', '2024-03-30 14:02:04.079821-07', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('rodrick.0oew7j06@example.org', '邵杰裕', 'unknown', '2024-01-01 00:23:36.209543-08', '2024-04-09 03:32:36.209543-07', '2024-03-02 00:02:36.209543-08', '{123379,101622,160211,125431,150539,161027,144783,147877,119412,152976,143262,111443,148710,108696,130003,159424,177799,147861,141810,173545,112245,173779,105163}', '{250312,229253,208963,243344,254507,258704,233984,246279,216324,265492,271311,227540,251156,208100,226630,245898,270016,261497,223457,213212,213269,298747,200827,283367,269806,207562,251785,229552,231975,266084,241418,220379,231176,211157}', '{361286,379618,357221,390621,322054,368199,324191,338171,380755,326964,320032,383722,301175,341814,396562,387444,383849,360184,341752,360889}', '{417818,415711,439104,465403,417252,463236,439416,428907,406815,428077,484499,472057,420483,462485,410067,436054,464915,478207,448639,453457,449309,431334,407365,499723,485400,452162,400810,498592,420534,421060,498247,476385,463149,453034,465603,452322,446098,435543,451009,449564,493472,406348,416230,492020,402690}', '2024-04-22 19:49:36.209546-07', '2024-04-21 03:03:36.209546-07', '2024-02-14 14:21:36.209546-08');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('rodrick.0oew7j06@example.org', '邵杰裕', 'unknown', '2024-01-01 00:23:36.209543-08', '2024-04-09 03:32:36.209543-07', '2024-03-02 00:02:36.209543-08', '{123379,101622,160211,125431,150539,161027,144783,147877,119412,152976,143262,111443,148710,108696,130003,159424,177799,147861,141810,173545,112245,173779,105163}', '{250312,229253,208963,243344,254507,258704,233984,246279,216324,265492,271311,227540,251156,208100,226630,245898,270016,261497,223457,213212,213269,298747,200827,283367,269806,207562,251785,229552,231975,266084,241418,220379,231176,211157}', '{361286,379618,357221,390621,322054,368199,324191,338171,380755,326964,320032,383722,301175,341814,396562,387444,383849,360184,341752,360889}', '{417818,415711,439104,465403,417252,463236,439416,428907,406815,428077,484499,472057,420483,462485,410067,436054,464915,478207,448639,453457,449309,431334,407365,499723,485400,452162,400810,498592,420534,421060,498247,476385,463149,453034,465603,452322,446098,435543,451009,449564,493472,406348,416230,492020,402690}', '2024-04-22 19:49:36.209546-07', '2024-04-21 03:03:36.209546-07', '2024-02-14 14:21:36.209546-08', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('cesar.cb9s7ybs@example.net', '覃青松', 'unknown', '2024-01-01 00:23:36.570062-08', '2024-04-14 01:51:36.570063-07', '2024-04-30 15:06:36.570063-07', '{155450}', '{259019,263090,200065,211308,287901,248811,218869,217884,291965,238302,269674,218412,207947,275275,236745,235623,261458,236926,244153,288517,245150,200232,247677,280660,229145,297704,235152,275545,270128}', '{377233,381806,364573,395628,325052,361325,394806,347150,344803,392801,334212,371727,319249,333553,301424,345470,323207,374127,347989,305068,317309,359733,382266,364576,379993,354970,397168,320092,384875,303696}', '{402039,457718,442969,473761,481824,470787,493666,418397,436712,414825,485625,464809,421544,403124,406086,425374,414776,444300,483041,406829,474860,478825}', '2024-04-28 15:36:36.570065-07', '2024-04-29 13:55:36.570066-07', '2024-02-18 05:51:36.570066-08');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('cesar.cb9s7ybs@example.net', '覃青松', 'unknown', '2024-01-01 00:23:36.570062-08', '2024-04-14 01:51:36.570063-07', '2024-04-30 15:06:36.570063-07', '{155450}', '{259019,263090,200065,211308,287901,248811,218869,217884,291965,238302,269674,218412,207947,275275,236745,235623,261458,236926,244153,288517,245150,200232,247677,280660,229145,297704,235152,275545,270128}', '{377233,381806,364573,395628,325052,361325,394806,347150,344803,392801,334212,371727,319249,333553,301424,345470,323207,374127,347989,305068,317309,359733,382266,364576,379993,354970,397168,320092,384875,303696}', '{402039,457718,442969,473761,481824,470787,493666,418397,436712,414825,485625,464809,421544,403124,406086,425374,414776,444300,483041,406829,474860,478825}', '2024-04-28 15:36:36.570065-07', '2024-04-29 13:55:36.570066-07', '2024-02-18 05:51:36.570066-08', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('bonnie.70o2o74t@example.org', '武香怡', 'unknown', '2024-01-01 00:23:49.562981-08', '2024-04-08 18:00:49.562981-07', '2024-02-27 08:03:49.562982-08', '{127949,197348,197623,162548,174976,158693,105005,118957,166414,192812,104453,158010,189032,193320,149277,164571,175407,192431,100346,106325,117807,137356,145734,157135,177386,103306,199897}', '{270086,257252,236679,280282,261122,224388,201647,298510,266902,249910,232100,217981,266025,213480,261221}', '{310319,316916,328753,383088,330575,394444,352704,397761,394946,304370,396638,318438,384263,387575,357414,383680,304854,312684,372700,387829,339949,310789,304427,307142,345537,356735,349753,371150,303879}', '{487757,455645,487450,492486,436454,419967,452709,441765,400226,494850,489398,451384,454191,413526,498223,424110,465796,499404,440620,417309,490647,480697,429020,454390,485857,413645,451281}', '2024-04-03 16:19:49.562984-07', '2024-04-29 10:41:49.562984-07', '2024-02-14 17:24:49.562984-08');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('bonnie.70o2o74t@example.org', '武香怡', 'unknown', '2024-01-01 00:23:49.562981-08', '2024-04-08 18:00:49.562981-07', '2024-02-27 08:03:49.562982-08', '{127949,197348,197623,162548,174976,158693,105005,118957,166414,192812,104453,158010,189032,193320,149277,164571,175407,192431,100346,106325,117807,137356,145734,157135,177386,103306,199897}', '{270086,257252,236679,280282,261122,224388,201647,298510,266902,249910,232100,217981,266025,213480,261221}', '{310319,316916,328753,383088,330575,394444,352704,397761,394946,304370,396638,318438,384263,387575,357414,383680,304854,312684,372700,387829,339949,310789,304427,307142,345537,356735,349753,371150,303879}', '{487757,455645,487450,492486,436454,419967,452709,441765,400226,494850,489398,451384,454191,413526,498223,424110,465796,499404,440620,417309,490647,480697,429020,454390,485857,413645,451281}', '2024-04-03 16:19:49.562984-07', '2024-04-29 10:41:49.562984-07', '2024-02-14 17:24:49.562984-08', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('adan.4blxfbe6@example.com', '史鸿承', 'unknown', '2024-01-01 00:24:12.557095-08', '2024-04-25 00:24:12.557095-07', '2024-04-01 06:22:12.557095-07', '{151131,157147,148918,131070,139523,128147,193685,100336,153390,170092,142276,134134,100102,115192,149001,181521,125205,176891,116563,115302,103147,168950,154953,166485,103367,174278,147907,168039,161519,187679,142120,140848,104004,125910,169984,169796,154027,142375,188406,118267,128132,174192,115506,155919}', '{288008,243134,220031,299415,209651,246882,214731,202463,261429,212989,259649,246500,232098,255749,273874,207549,241188,218412,275122,283103}', '{356359,311573,362434,334342,320665,390526,333203,334954,328851,311680,335750,348274,390562,331286,352790,364752,343731,373492,384701,337989,314027,379765,388528,372017,337873,360131}', '{440593,404159,439355,434949,411383,401229,406003,499777,424607,445674,459435,471412,401754,462642,404437}', '2024-04-29 19:47:12.5571-07', '2024-05-03 23:24:12.5571-07', '2024-02-21 07:22:12.557101-08');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('adan.4blxfbe6@example.com', '史鸿承', 'unknown', '2024-01-01 00:24:12.557095-08', '2024-04-25 00:24:12.557095-07', '2024-04-01 06:22:12.557095-07', '{151131,157147,148918,131070,139523,128147,193685,100336,153390,170092,142276,134134,100102,115192,149001,181521,125205,176891,116563,115302,103147,168950,154953,166485,103367,174278,147907,168039,161519,187679,142120,140848,104004,125910,169984,169796,154027,142375,188406,118267,128132,174192,115506,155919}', '{288008,243134,220031,299415,209651,246882,214731,202463,261429,212989,259649,246500,232098,255749,273874,207549,241188,218412,275122,283103}', '{356359,311573,362434,334342,320665,390526,333203,334954,328851,311680,335750,348274,390562,331286,352790,364752,343731,373492,384701,337989,314027,379765,388528,372017,337873,360131}', '{440593,404159,439355,434949,411383,401229,406003,499777,424607,445674,459435,471412,401754,462642,404437}', '2024-04-29 19:47:12.5571-07', '2024-05-03 23:24:12.5571-07', '2024-02-21 07:22:12.557101-08', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('hubert.gv96298d@example.com', '方静璇', 'unknown', '2024-01-01 00:25:02.139017-08', '2024-04-13 00:36:02.139017-07', '2024-03-19 05:04:02.139017-07', '{170163,142941,124621,184096,138515,146238,138191,156512,170101,123729,176738}', '{202023,233537,262837,213615,275950,289802,239950,286285,282738,282716,234821,293358,204302,253232,299940,233846,211698,224865}', '{336198,374958,331295,377502,342415,372785,383432,372070,326064,315516,356547,394868,353304,321776,383145,398191,395616,332207,324171,329608,318774,348115,362622,340781,373781,384182,365799,326651,335925,313095,391756,361944,382036,385982,365773,325675}', '{444968,472096,442843,461533,442142,414733,484529,406538,433041,407765,421680,441473,499869,440745,454983,475486,416407,448278,438244,425092,475249,430067,411832,431932,454298,447570,444242,494910,409612,431281,496128,444829,450512,465348,449146,434542,411741,467056}', '2024-04-10 03:45:02.139019-07', '2024-04-23 17:12:02.139019-07', '2024-04-12 12:15:02.13902-07');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('hubert.gv96298d@example.com', '方静璇', 'unknown', '2024-01-01 00:25:02.139017-08', '2024-04-13 00:36:02.139017-07', '2024-03-19 05:04:02.139017-07', '{170163,142941,124621,184096,138515,146238,138191,156512,170101,123729,176738}', '{202023,233537,262837,213615,275950,289802,239950,286285,282738,282716,234821,293358,204302,253232,299940,233846,211698,224865}', '{336198,374958,331295,377502,342415,372785,383432,372070,326064,315516,356547,394868,353304,321776,383145,398191,395616,332207,324171,329608,318774,348115,362622,340781,373781,384182,365799,326651,335925,313095,391756,361944,382036,385982,365773,325675}', '{444968,472096,442843,461533,442142,414733,484529,406538,433041,407765,421680,441473,499869,440745,454983,475486,416407,448278,438244,425092,475249,430067,411832,431932,454298,447570,444242,494910,409612,431281,496128,444829,450512,465348,449146,434542,411741,467056}', '2024-04-10 03:45:02.139019-07', '2024-04-23 17:12:02.139019-07', '2024-04-12 12:15:02.13902-07', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('jordon.oslm3w5v@example.com', '汪昌贤', 'unknown', '2024-01-01 00:25:30.547771-08', '2024-04-14 00:19:30.547771-07', '2024-02-19 20:02:30.547771-08', '{186600,132643,191585,179084,151028,129857,129741,140806,135219,168820,172103,155563,154165,103103,107844,199736}', '{283461,225054,288129,246187,257283,268723,251066,228322,266444,225478,299211,228495,256578,260787,263926,250190,297096,290190,286384,223423,281914,228896,234538,207877}', '{322907,337868,355318,328549,335807,374491,345374,349361,389248,393794,375826,369780,300696,361634,343156,303501,374308,374314,380914,361858,391857,372190,368967,357770,372322,397073,338708,393963,318419,334352,337211,302971,368216,335508,363424,381536,368044,372460}', '{404022,409730,414620,496812,485176,493266,416411,460125,442266,405261,438073,457583,480809,462470,464661,489449,474307,487996,454381,481866,465061,468414,470763,433515}', '2024-03-24 16:14:30.547774-07', '2024-04-27 22:57:30.547774-07', '2024-02-16 20:27:30.547774-08');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('jordon.oslm3w5v@example.com', '汪昌贤', 'unknown', '2024-01-01 00:25:30.547771-08', '2024-04-14 00:19:30.547771-07', '2024-02-19 20:02:30.547771-08', '{186600,132643,191585,179084,151028,129857,129741,140806,135219,168820,172103,155563,154165,103103,107844,199736}', '{283461,225054,288129,246187,257283,268723,251066,228322,266444,225478,299211,228495,256578,260787,263926,250190,297096,290190,286384,223423,281914,228896,234538,207877}', '{322907,337868,355318,328549,335807,374491,345374,349361,389248,393794,375826,369780,300696,361634,343156,303501,374308,374314,380914,361858,391857,372190,368967,357770,372322,397073,338708,393963,318419,334352,337211,302971,368216,335508,363424,381536,368044,372460}', '{404022,409730,414620,496812,485176,493266,416411,460125,442266,405261,438073,457583,480809,462470,464661,489449,474307,487996,454381,481866,465061,468414,470763,433515}', '2024-03-24 16:14:30.547774-07', '2024-04-27 22:57:30.547774-07', '2024-02-16 20:27:30.547774-08', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('isobel.9vcp2yyi@example.com', '胡月阳', 'unknown', '2024-01-01 00:25:37.308327-08', '2024-04-13 15:55:37.308327-07', '2024-04-24 11:29:37.308328-07', '{160852,137123,137029,172856,153725,195887,106527,130917,107536,182515,161473,108148,114940,120169,175592,110359,120688,128827,135397,115535}', '{247591,242324,271859,266576,239426}', '{307447,384024,342064,392897,378271,382279,333607,385853,335954}', '{456158,428328,455665,488111,465659}', '2024-03-24 04:23:37.308329-07', '2024-04-24 23:13:37.308329-07', '2024-02-20 16:32:37.30833-08');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('isobel.9vcp2yyi@example.com', '胡月阳', 'unknown', '2024-01-01 00:25:37.308327-08', '2024-04-13 15:55:37.308327-07', '2024-04-24 11:29:37.308328-07', '{160852,137123,137029,172856,153725,195887,106527,130917,107536,182515,161473,108148,114940,120169,175592,110359,120688,128827,135397,115535}', '{247591,242324,271859,266576,239426}', '{307447,384024,342064,392897,378271,382279,333607,385853,335954}', '{456158,428328,455665,488111,465659}', '2024-03-24 04:23:37.308329-07', '2024-04-24 23:13:37.308329-07', '2024-02-20 16:32:37.30833-08', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('clara.pxz8cxfl@example.com', '龙清云', 'unknown', '2024-01-01 00:26:07.409356-08', '2024-04-15 16:50:07.409356-07', '2024-02-22 03:41:07.409356-08', '{163680,111405,181044,162936,185279,113610,143710,154054,184461,125826,107456,173361,172493}', '{220497,285236,200565,275583,292274,210347,245067,227619,211657,225589,287797,238482,206894,232198,279493,292076,226835}', '{300138,373147,313991,388047,327676}', '{423271,479929,404083,444026,452786,480252,428649,456348,484370,451013,453289,479443,453101,492351}', '2024-03-29 09:22:07.409357-07', '2024-05-01 19:22:07.409357-07', '2024-04-28 16:27:07.409357-07');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('clara.pxz8cxfl@example.com', '龙清云', 'unknown', '2024-01-01 00:26:07.409356-08', '2024-04-15 16:50:07.409356-07', '2024-02-22 03:41:07.409356-08', '{163680,111405,181044,162936,185279,113610,143710,154054,184461,125826,107456,173361,172493}', '{220497,285236,200565,275583,292274,210347,245067,227619,211657,225589,287797,238482,206894,232198,279493,292076,226835}', '{300138,373147,313991,388047,327676}', '{423271,479929,404083,444026,452786,480252,428649,456348,484370,451013,453289,479443,453101,492351}', '2024-03-29 09:22:07.409357-07', '2024-05-01 19:22:07.409357-07', '2024-04-28 16:27:07.409357-07', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('maritza.hn4tw07a@example.net', '程贵博', 'unknown', '2024-01-01 00:26:13.720152-08', '2024-04-09 00:32:13.720152-07', '2024-04-09 15:14:13.720152-07', '{193691,114521,175039,103657,164394,108830,148213,196704,124520,133439,164703,179253,121387,160150,127689,166289,186393,179628,165518,123455,184838,166318,119868,135739,160105,170038,170850,100433,146519,186943,177234,114133,128298,118767,111443,124044,160824,144783,153437}', '{217076,261905,262886,260621,202923,274081,273454,234676,299130,298781,239315,210045,299824,244457,226176,239504,287118,229558,210098,228808,201867,294601,251890,203709,262705}', '{345972,352913,328019,378726,378995,336098,388909}', '{438529,405778,439858,477759,445255,423812,445209,491671,427065,436016,448095,494519,483267,412216,425680,480862,450477,435807,497562,474266,468165,404421,414347,414165}', '2024-04-06 06:16:13.720154-07', '2024-04-21 13:01:13.720154-07', '2024-02-13 13:24:13.720154-08');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('maritza.hn4tw07a@example.net', '程贵博', 'unknown', '2024-01-01 00:26:13.720152-08', '2024-04-09 00:32:13.720152-07', '2024-04-09 15:14:13.720152-07', '{193691,114521,175039,103657,164394,108830,148213,196704,124520,133439,164703,179253,121387,160150,127689,166289,186393,179628,165518,123455,184838,166318,119868,135739,160105,170038,170850,100433,146519,186943,177234,114133,128298,118767,111443,124044,160824,144783,153437}', '{217076,261905,262886,260621,202923,274081,273454,234676,299130,298781,239315,210045,299824,244457,226176,239504,287118,229558,210098,228808,201867,294601,251890,203709,262705}', '{345972,352913,328019,378726,378995,336098,388909}', '{438529,405778,439858,477759,445255,423812,445209,491671,427065,436016,448095,494519,483267,412216,425680,480862,450477,435807,497562,474266,468165,404421,414347,414165}', '2024-04-06 06:16:13.720154-07', '2024-04-21 13:01:13.720154-07', '2024-02-13 13:24:13.720154-08', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('mekhi.pbv3sxxm@example.org', '欧阳明志', 'unknown', '2024-01-01 00:26:52.238389-08', '2024-04-29 03:50:52.23839-07', '2024-03-01 00:23:52.23839-08', '{176739,151981,149294,155508,109993,170660,107431,193130,118327,183367,115864,162441,145170,103790,164834,104383,110127,141110,159164,128365,182968,102736,164710,181662,166945,191338,193575,171175,105090,170898,123518,129761,106862,134726,110463,192298,179961,163091,147959,173124,156753,186142,111978}', '{284238,240300,272152,270311,211169,266743,287062,282195,282747,252812,255238,227499,269498,284334,287330,275934,232486,277587,255125,214516,236819,271714,298721,251390,207041,207994,222732,285996}', '{369404,384499,374717,322278,313775,344297,327618,300195,373433,321356,309242,343719,363324,319100,373292,349563,362708,302072,352275,370580,386999,306285,368436,391133,395845,344678}', '{444787,491870,453167,410821,452183,458197,480365,489207,432914,438191,495764,427055,476695,496146,403395,416858,455893,431227,408757,431849,459722,433226,476258,412425,444267,471062,483757,468629,469499,433291,407053,456464,479195,419096,460150,449195,438147,439355,421060,458459,495036,405011,413172}', '2024-04-06 23:56:52.238392-07', '2024-04-29 12:31:52.238392-07', '2024-02-09 12:08:52.238393-08');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('mekhi.pbv3sxxm@example.org', '欧阳明志', 'unknown', '2024-01-01 00:26:52.238389-08', '2024-04-29 03:50:52.23839-07', '2024-03-01 00:23:52.23839-08', '{176739,151981,149294,155508,109993,170660,107431,193130,118327,183367,115864,162441,145170,103790,164834,104383,110127,141110,159164,128365,182968,102736,164710,181662,166945,191338,193575,171175,105090,170898,123518,129761,106862,134726,110463,192298,179961,163091,147959,173124,156753,186142,111978}', '{284238,240300,272152,270311,211169,266743,287062,282195,282747,252812,255238,227499,269498,284334,287330,275934,232486,277587,255125,214516,236819,271714,298721,251390,207041,207994,222732,285996}', '{369404,384499,374717,322278,313775,344297,327618,300195,373433,321356,309242,343719,363324,319100,373292,349563,362708,302072,352275,370580,386999,306285,368436,391133,395845,344678}', '{444787,491870,453167,410821,452183,458197,480365,489207,432914,438191,495764,427055,476695,496146,403395,416858,455893,431227,408757,431849,459722,433226,476258,412425,444267,471062,483757,468629,469499,433291,407053,456464,479195,419096,460150,449195,438147,439355,421060,458459,495036,405011,413172}', '2024-04-06 23:56:52.238392-07', '2024-04-29 12:31:52.238392-07', '2024-02-09 12:08:52.238393-08', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('arnulfo.1kb0uvi9@example.com', '吕巧春', 'unknown', '2024-01-01 00:27:43.15959-08', '2024-04-11 20:42:43.15959-07', '2024-04-14 05:52:43.15959-07', '{138452,129467,194853,119138,161050,134327,111490,122038,184947,145119,158612,169142,176603,115678,125647,109710,107995,199136,120203,130177,112349,129938,145794,153037,136529,134302,132119,124741,189942,166308,152275}', '{286219,255024,251236,231412,259241,291802,268645,235217,276466,280775,266245,243115,232738,220933,217357,240277,276866,212537,275355,240532,240505,279026,262798,288906,277063,257675,223445,212447,248338,258915,205111,226405,275470,236604,289583,260105,285170,263374,220080,283421,206286,260483}', '{315998,357963,391904,387632,388925}', '{463521,456229,418007,416752,452560,466455,418004,408869,494863,404071}', '2024-04-21 20:40:43.159591-07', '2024-04-25 18:23:43.159591-07', '2024-02-20 04:15:43.159592-08');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('arnulfo.1kb0uvi9@example.com', '吕巧春', 'unknown', '2024-01-01 00:27:43.15959-08', '2024-04-11 20:42:43.15959-07', '2024-04-14 05:52:43.15959-07', '{138452,129467,194853,119138,161050,134327,111490,122038,184947,145119,158612,169142,176603,115678,125647,109710,107995,199136,120203,130177,112349,129938,145794,153037,136529,134302,132119,124741,189942,166308,152275}', '{286219,255024,251236,231412,259241,291802,268645,235217,276466,280775,266245,243115,232738,220933,217357,240277,276866,212537,275355,240532,240505,279026,262798,288906,277063,257675,223445,212447,248338,258915,205111,226405,275470,236604,289583,260105,285170,263374,220080,283421,206286,260483}', '{315998,357963,391904,387632,388925}', '{463521,456229,418007,416752,452560,466455,418004,408869,494863,404071}', '2024-04-21 20:40:43.159591-07', '2024-04-25 18:23:43.159591-07', '2024-02-20 04:15:43.159592-08', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('holly.h9imzixe@example.net', '邵韵宇', 'unknown', '2024-01-01 00:27:48.742134-08', '2024-04-07 03:16:48.742135-07', '2024-02-22 22:13:48.742135-08', '{189919,151194,115714,138452,127643,160004,139157,172098,108045,112560,137686,162645,161983,186673,199684,153639,191713,130284,107205,192927,183383,199181,180408,192566,149880,189615,135994,140666,123997,185020,161823,186065,159308,186514}', '{240907,293804,283024,282592,221374,283450,231804,223332,260315,259506,216964,277196,251360,263616,296482,266725,297733,202833,251071,215772,241434,282541,242646,280540,245864,201854,253375,276972,288389,200936,239615,281788,222658,289972,233250,269650,291640,231991,219823,270230,258377,238432,255740,293209,221197}', '{320229,367757,395063,322687,352854,399905,393189,398058,334240,386991,386337,347054,316481,339670,344030,384095,322572,353748,321365,385014}', '{436886,491260,482388,417037,427295,497445,469078,480050,471673,442877,428115,403794,472505,449147,444547,432424,472771,473792,403733,406417,470102,442028,435516,425294,458667,453519,401748,421832,412176,470692}', '2024-03-25 09:44:48.742137-07', '2024-04-22 14:39:48.742137-07', '2024-03-12 06:23:48.742137-07');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('holly.h9imzixe@example.net', '邵韵宇', 'unknown', '2024-01-01 00:27:48.742134-08', '2024-04-07 03:16:48.742135-07', '2024-02-22 22:13:48.742135-08', '{189919,151194,115714,138452,127643,160004,139157,172098,108045,112560,137686,162645,161983,186673,199684,153639,191713,130284,107205,192927,183383,199181,180408,192566,149880,189615,135994,140666,123997,185020,161823,186065,159308,186514}', '{240907,293804,283024,282592,221374,283450,231804,223332,260315,259506,216964,277196,251360,263616,296482,266725,297733,202833,251071,215772,241434,282541,242646,280540,245864,201854,253375,276972,288389,200936,239615,281788,222658,289972,233250,269650,291640,231991,219823,270230,258377,238432,255740,293209,221197}', '{320229,367757,395063,322687,352854,399905,393189,398058,334240,386991,386337,347054,316481,339670,344030,384095,322572,353748,321365,385014}', '{436886,491260,482388,417037,427295,497445,469078,480050,471673,442877,428115,403794,472505,449147,444547,432424,472771,473792,403733,406417,470102,442028,435516,425294,458667,453519,401748,421832,412176,470692}', '2024-03-25 09:44:48.742137-07', '2024-04-22 14:39:48.742137-07', '2024-03-12 06:23:48.742137-07', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('libby.5o9cljqm@example.org', '尹琪涵', 'unknown', '2024-01-01 00:28:03.553837-08', '2024-04-30 03:53:03.553837-07', '2024-05-03 03:13:03.553838-07', '{}', '{242238,209588,221194,245424,200506,285669,299568,203858,291333,235789,256863,239083,221614,288245,222912,271670,216071,280838,299747,251764,220602,287710}', '{304432,349455,322561,386515,380229,359869,315515,361966,311797,320829,398622,335653,326134}', '{420169,465535,464824,469414,411194,491426,423699,424872,440054,492784,446463,473101,410482,490170,483822,481537,488126,431029,456904,423045,413049,465386,484894,447561,416155,496462,409422,468001,431677,460740,462565,439196,486269,400400,485791,492606,443145,455258,486451,400409,409585,451541,446101,466945,405840,497676,489567}', '2024-04-23 03:01:03.553839-07', '2024-04-22 08:55:03.553839-07', '2024-03-20 11:03:03.55384-07');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('libby.5o9cljqm@example.org', '尹琪涵', 'unknown', '2024-01-01 00:28:03.553837-08', '2024-04-30 03:53:03.553837-07', '2024-05-03 03:13:03.553838-07', '{}', '{242238,209588,221194,245424,200506,285669,299568,203858,291333,235789,256863,239083,221614,288245,222912,271670,216071,280838,299747,251764,220602,287710}', '{304432,349455,322561,386515,380229,359869,315515,361966,311797,320829,398622,335653,326134}', '{420169,465535,464824,469414,411194,491426,423699,424872,440054,492784,446463,473101,410482,490170,483822,481537,488126,431029,456904,423045,413049,465386,484894,447561,416155,496462,409422,468001,431677,460740,462565,439196,486269,400400,485791,492606,443145,455258,486451,400409,409585,451541,446101,466945,405840,497676,489567}', '2024-04-23 03:01:03.553839-07', '2024-04-22 08:55:03.553839-07', '2024-03-20 11:03:03.55384-07', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('agustin.amdhxnt0@example.net', '江水瑶', 'unknown', '2024-01-01 00:28:04.667823-08', '2024-04-11 13:14:04.667824-07', '2024-02-17 15:03:04.667824-08', '{102827,124537,130333,173743,127663,187215,194572,133314,102487,111177,102086,127003,148703,102730,126392,130143,165868,195298,143528,191699,186593,144978,139953,125181,163182,153532,137839,159356,104686,146318,175649,109803,159844,134694,113844,147364,110267}', '{243199,281594,214581,285431,212970,250197,297370,263443,297647,222295,260835,258458,255045,223692,298370,231219,214367,209873,267214,235019,288402,260998,292847,240390,253022,259690,222153,203227,295820,277777,292203,255389,201950,295882,272446,239142,257924,296118,229444,290869,256769,233232,207677,273187,205240,273127,290429,220559}', '{331960,349619,389253,321468,329807,351716,315064,341453,394236,325437,372830,385455,350378,388293,346405,379659,382131,391908}', '{428277,423575,481491,447686,484917,417172,488411,437581,435032,468014,402989,456264,434761,429904,458732,493334,478994,487844,446562,409097,456203,417514,430035,402859,450002,492684,427202,416403,454306,454534,461282,497209,450949,415215,409083,453239,465359,477138,428640,448592}', '2024-04-15 10:14:04.667826-07', '2024-04-21 10:55:04.667826-07', '2024-03-31 03:52:04.667826-07');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('agustin.amdhxnt0@example.net', '江水瑶', 'unknown', '2024-01-01 00:28:04.667823-08', '2024-04-11 13:14:04.667824-07', '2024-02-17 15:03:04.667824-08', '{102827,124537,130333,173743,127663,187215,194572,133314,102487,111177,102086,127003,148703,102730,126392,130143,165868,195298,143528,191699,186593,144978,139953,125181,163182,153532,137839,159356,104686,146318,175649,109803,159844,134694,113844,147364,110267}', '{243199,281594,214581,285431,212970,250197,297370,263443,297647,222295,260835,258458,255045,223692,298370,231219,214367,209873,267214,235019,288402,260998,292847,240390,253022,259690,222153,203227,295820,277777,292203,255389,201950,295882,272446,239142,257924,296118,229444,290869,256769,233232,207677,273187,205240,273127,290429,220559}', '{331960,349619,389253,321468,329807,351716,315064,341453,394236,325437,372830,385455,350378,388293,346405,379659,382131,391908}', '{428277,423575,481491,447686,484917,417172,488411,437581,435032,468014,402989,456264,434761,429904,458732,493334,478994,487844,446562,409097,456203,417514,430035,402859,450002,492684,427202,416403,454306,454534,461282,497209,450949,415215,409083,453239,465359,477138,428640,448592}', '2024-04-15 10:14:04.667826-07', '2024-04-21 10:55:04.667826-07', '2024-03-31 03:52:04.667826-07', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('princess.a979n1s5@example.net', '张向卉', 'unknown', '2024-01-01 00:30:06.892508-08', '2024-04-08 15:30:06.892508-07', '2024-02-18 21:11:06.892508-08', '{161663,123290,160236,165433,189853,139096,121864,192243,197891,153933,131947,188645,184723,159518,194661,116034}', '{225670,260963,261123,293332,229868,263546,211004,279385,234107,286892,205207,240717}', '{315080,378814,304629,337368,356610,383286,329285,312065,330207,341465,379790,314341}', '{411225,465724,457875,471746,443896,431104,407705,443521,445215,471696,432080,477429,435293,429964,404663,422907,483000,474379,426556,449419,476697,415279,472973,464407,494732,427607,415721}', '2024-03-30 21:42:06.892509-07', '2024-05-05 03:22:06.892509-07', '2024-04-03 20:22:06.89251-07');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('princess.a979n1s5@example.net', '张向卉', 'unknown', '2024-01-01 00:30:06.892508-08', '2024-04-08 15:30:06.892508-07', '2024-02-18 21:11:06.892508-08', '{161663,123290,160236,165433,189853,139096,121864,192243,197891,153933,131947,188645,184723,159518,194661,116034}', '{225670,260963,261123,293332,229868,263546,211004,279385,234107,286892,205207,240717}', '{315080,378814,304629,337368,356610,383286,329285,312065,330207,341465,379790,314341}', '{411225,465724,457875,471746,443896,431104,407705,443521,445215,471696,432080,477429,435293,429964,404663,422907,483000,474379,426556,449419,476697,415279,472973,464407,494732,427607,415721}', '2024-03-30 21:42:06.892509-07', '2024-05-05 03:22:06.892509-07', '2024-04-03 20:22:06.89251-07', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('jaclyn.yi8k0xp7@example.org', '范晋永', 'unknown', '2024-01-01 00:30:10.564245-08', '2024-04-13 07:41:10.564245-07', '2024-03-10 05:12:10.564245-07', '{153555,126137,173711,102790,106892,147606,107185,113799,121518,142907,127371,137854,156264,162083,158146,189358,196845,171855}', '{220462,246335,229760,257775,231057,241907,229096,218626,299665,217328,274872,248461,289536,228992,275061,240108,264860,266072,211537,209901,274139,246350,240039,246488,219130,207143,279544,231987,253109}', '{}', '{459296,442243,464174,429500,402262,459558,463095,489266,464214,444700,453758,494798,437625}', '2024-04-07 03:05:10.56425-07', '2024-05-01 19:59:10.56425-07', '2024-03-06 07:47:10.564251-08');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('jaclyn.yi8k0xp7@example.org', '范晋永', 'unknown', '2024-01-01 00:30:10.564245-08', '2024-04-13 07:41:10.564245-07', '2024-03-10 05:12:10.564245-07', '{153555,126137,173711,102790,106892,147606,107185,113799,121518,142907,127371,137854,156264,162083,158146,189358,196845,171855}', '{220462,246335,229760,257775,231057,241907,229096,218626,299665,217328,274872,248461,289536,228992,275061,240108,264860,266072,211537,209901,274139,246350,240039,246488,219130,207143,279544,231987,253109}', '{}', '{459296,442243,464174,429500,402262,459558,463095,489266,464214,444700,453758,494798,437625}', '2024-04-07 03:05:10.56425-07', '2024-05-01 19:59:10.56425-07', '2024-03-06 07:47:10.564251-08', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('davon.zc1px0xv@example.org', '刘乐奇', 'unknown', '2024-01-01 00:30:33.962964-08', '2024-04-12 06:07:33.962964-07', '2024-04-07 09:45:33.962965-07', '{157207,123045,187990}', '{219252,249134,207855,225532,241946,274278,272520,295890,242401,236036,298285,227462,277035,269649,253620,208490,218709,237313,262716,294049,271688,232764,205287,241525,279420,277183,220828,268566,223543,226471,224958,235649,232310,289025,209806,248343,215106,279110,215766,209027,269813,250766,254958,287743,289583,202345,225272}', '{366950,353111,320805,307390,315316,394192,375975,349169,370468,333076,353685,331235,372081,343613,384965,343304,333336,394763,312142,306035,340662,328493,363251,328102,301161,399402,320697,307644,395697,388753,321821}', '{478433,459378,475173,466267,474949,461579,472007,486199,448996,406515,416326,482039,418858,489804,467275,434374,491553}', '2024-04-08 09:30:33.962966-07', '2024-05-01 15:45:33.962967-07', '2024-04-17 10:36:33.962967-07');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('davon.zc1px0xv@example.org', '刘乐奇', 'unknown', '2024-01-01 00:30:33.962964-08', '2024-04-12 06:07:33.962964-07', '2024-04-07 09:45:33.962965-07', '{157207,123045,187990}', '{219252,249134,207855,225532,241946,274278,272520,295890,242401,236036,298285,227462,277035,269649,253620,208490,218709,237313,262716,294049,271688,232764,205287,241525,279420,277183,220828,268566,223543,226471,224958,235649,232310,289025,209806,248343,215106,279110,215766,209027,269813,250766,254958,287743,289583,202345,225272}', '{366950,353111,320805,307390,315316,394192,375975,349169,370468,333076,353685,331235,372081,343613,384965,343304,333336,394763,312142,306035,340662,328493,363251,328102,301161,399402,320697,307644,395697,388753,321821}', '{478433,459378,475173,466267,474949,461579,472007,486199,448996,406515,416326,482039,418858,489804,467275,434374,491553}', '2024-04-08 09:30:33.962966-07', '2024-05-01 15:45:33.962967-07', '2024-04-17 10:36:33.962967-07', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('emil.w27ygux1@example.com', '邵博远', 'unknown', '2024-01-01 00:31:02.643144-08', '2024-04-23 03:27:02.643144-07', '2024-02-14 22:47:02.643145-08', '{110772,160553,147750,116959,166194,111402,136317,185099,177440,151885,141638,191599,161635,190722,186325,190430,126341,199096,139520,118764,180228,103884,160203,182012,125050,123058,175132,149211,122894,136022,145471,133183,112120,101620,102081}', '{255572,230824,208643,224022,280573,274977,230511,268337,202523,236096,223499,231442,214518,271913,252107,266134,202374,275620,279282,253969,250506,259278,290790,276123,232697,275899,217141,269013,225450,251036,245944,283229,293955,256914,203330,296270,229402,266580,265200,257315,212672,217233,247921,285679,213534}', '{389593,353537,335405,325467,373767,395809,366500,382761,361143,378817,306765,322548,303996,347766,313072,371966,302929,385225,339916,334912,312140,362145,384680,379126,397692,397388,358778,305495,385367,392338,339369,326851,395060,312399,341137,341997,332113,333142,348971,313205,390375}', '{458827,446342,473416,437609,466866,422274,476181,402179,437075,458166,445495,461902,411659,430241,479831,458059}', '2024-04-22 21:16:02.643149-07', '2024-04-24 03:52:02.643149-07', '2024-05-03 20:56:02.64315-07');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('emil.w27ygux1@example.com', '邵博远', 'unknown', '2024-01-01 00:31:02.643144-08', '2024-04-23 03:27:02.643144-07', '2024-02-14 22:47:02.643145-08', '{110772,160553,147750,116959,166194,111402,136317,185099,177440,151885,141638,191599,161635,190722,186325,190430,126341,199096,139520,118764,180228,103884,160203,182012,125050,123058,175132,149211,122894,136022,145471,133183,112120,101620,102081}', '{255572,230824,208643,224022,280573,274977,230511,268337,202523,236096,223499,231442,214518,271913,252107,266134,202374,275620,279282,253969,250506,259278,290790,276123,232697,275899,217141,269013,225450,251036,245944,283229,293955,256914,203330,296270,229402,266580,265200,257315,212672,217233,247921,285679,213534}', '{389593,353537,335405,325467,373767,395809,366500,382761,361143,378817,306765,322548,303996,347766,313072,371966,302929,385225,339916,334912,312140,362145,384680,379126,397692,397388,358778,305495,385367,392338,339369,326851,395060,312399,341137,341997,332113,333142,348971,313205,390375}', '{458827,446342,473416,437609,466866,422274,476181,402179,437075,458166,445495,461902,411659,430241,479831,458059}', '2024-04-22 21:16:02.643149-07', '2024-04-24 03:52:02.643149-07', '2024-05-03 20:56:02.64315-07', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('wilhelmine.k5vsmlrd@example.org', '秦紫怡', 'unknown', '2024-01-01 00:31:02.770893-08', '2024-05-01 16:48:02.770893-07', '2024-02-18 06:28:02.770893-08', '{171729,103931,156323,141740,139562,127872,143328,169657,182879,140393,197205,184162,105326,156312,125998,151775,192922,102207,192085,168280,167165,121130,104134,120701,133869,178282,190017,190463,149401,173220,141159,171249,146635,153935,159693}', '{294537,266651,276159,258869,229814,214176,282199,202980,209799,267314,278923,273508,203636,214203,245148,228602,239023,273391,256474,214231,266183,240726,293682}', '{315682,372266,378537,308833,350504}', '{446248,434062,497104,479369,418567,460633,488033,431369,421299,481790}', '2024-04-29 09:56:02.770894-07', '2024-05-01 22:05:02.770894-07', '2024-02-25 06:22:02.770894-08');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('wilhelmine.k5vsmlrd@example.org', '秦紫怡', 'unknown', '2024-01-01 00:31:02.770893-08', '2024-05-01 16:48:02.770893-07', '2024-02-18 06:28:02.770893-08', '{171729,103931,156323,141740,139562,127872,143328,169657,182879,140393,197205,184162,105326,156312,125998,151775,192922,102207,192085,168280,167165,121130,104134,120701,133869,178282,190017,190463,149401,173220,141159,171249,146635,153935,159693}', '{294537,266651,276159,258869,229814,214176,282199,202980,209799,267314,278923,273508,203636,214203,245148,228602,239023,273391,256474,214231,266183,240726,293682}', '{315682,372266,378537,308833,350504}', '{446248,434062,497104,479369,418567,460633,488033,431369,421299,481790}', '2024-04-29 09:56:02.770894-07', '2024-05-01 22:05:02.770894-07', '2024-02-25 06:22:02.770894-08', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('blair.m1qlcp7m@example.org', '毛杰奥', 'unknown', '2024-01-01 00:31:30.425228-08', '2024-04-14 14:00:30.425228-07', '2024-04-04 14:18:30.425228-07', '{148580,119242,177117,150453,120226,170979,107585,168586,163109,138005,136170,111392,143082,119052,178365,118364,177809,170826,197269,154785,136618,187950,160565,112884}', '{281084,259904,228328,257117,251305,201177,210526,273516,204238,256102,206408,278082,269285,279399}', '{363087,378338,301465,302689,348238,320342,326040,349939,390531,324734,327541,344564,353743,329930}', '{415888,458737}', '2024-04-17 12:05:30.425229-07', '2024-04-22 09:09:30.425229-07', '2024-03-11 10:15:30.42523-07');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('blair.m1qlcp7m@example.org', '毛杰奥', 'unknown', '2024-01-01 00:31:30.425228-08', '2024-04-14 14:00:30.425228-07', '2024-04-04 14:18:30.425228-07', '{148580,119242,177117,150453,120226,170979,107585,168586,163109,138005,136170,111392,143082,119052,178365,118364,177809,170826,197269,154785,136618,187950,160565,112884}', '{281084,259904,228328,257117,251305,201177,210526,273516,204238,256102,206408,278082,269285,279399}', '{363087,378338,301465,302689,348238,320342,326040,349939,390531,324734,327541,344564,353743,329930}', '{415888,458737}', '2024-04-17 12:05:30.425229-07', '2024-04-22 09:09:30.425229-07', '2024-03-11 10:15:30.42523-07', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('dawn.coybn1ol@example.com', '毛沛珊', 'unknown', '2024-01-01 00:31:46.961597-08', '2024-04-14 16:15:46.961597-07', '2024-03-14 23:55:46.961597-07', '{105667,134992,115685,197605,138492,136975,136188,161712,135817,142151,115541,103388,151461,139403,154968,104087,144725,194896,107954,193354}', '{219153,282182,209315,262580,269221,255154,263427,209627,250357,224919,271822,290539,225290,223061,214980,264546,223665,223533,265049,221267,274556,209260,238790,200650,229499,254045,233790,213771,205803,281777,223117,249716,276211,253736}', '{329406,336232,325798,382551,394595,319557,324864,342914,343387,324369,379436,323394,345039,335328,356826,371052,359999,369370,373573,365973,320791,361512,399167}', '{407971}', '2024-03-21 17:43:46.961599-07', '2024-04-21 20:01:46.961599-07', '2024-02-18 13:16:46.961599-08');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('dawn.coybn1ol@example.com', '毛沛珊', 'unknown', '2024-01-01 00:31:46.961597-08', '2024-04-14 16:15:46.961597-07', '2024-03-14 23:55:46.961597-07', '{105667,134992,115685,197605,138492,136975,136188,161712,135817,142151,115541,103388,151461,139403,154968,104087,144725,194896,107954,193354}', '{219153,282182,209315,262580,269221,255154,263427,209627,250357,224919,271822,290539,225290,223061,214980,264546,223665,223533,265049,221267,274556,209260,238790,200650,229499,254045,233790,213771,205803,281777,223117,249716,276211,253736}', '{329406,336232,325798,382551,394595,319557,324864,342914,343387,324369,379436,323394,345039,335328,356826,371052,359999,369370,373573,365973,320791,361512,399167}', '{407971}', '2024-03-21 17:43:46.961599-07', '2024-04-21 20:01:46.961599-07', '2024-02-18 13:16:46.961599-08', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('virgie.d99cbel1@example.com', '王柯勤', 'unknown', '2024-01-01 00:31:50.995411-08', '2024-04-11 14:11:50.995411-07', '2024-04-13 08:20:50.995411-07', '{159420,128015,181149,148331,171054,105726,131364,133947,113022,171873,161781,188588}', '{272667,235962}', '{324894,314574,377628,309375,347702,373671,399809,347943,356254,348874,358329,317398,308705,351581,386138,356512,363372,337592,368926,312579,301302}', '{478360,467098,458306,472299,481846,491649,466111,482461,478674,475657,488992,404934,490235,461591,419054,412946,461079,455956,468179,497808,420837,404324,495560,465106,456351,429894,487364,412423,454721,491377,471632,414802,490344,435112}', '2024-04-07 11:53:50.995413-07', '2024-04-26 15:31:50.995414-07', '2024-03-10 01:27:50.995414-08');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('virgie.d99cbel1@example.com', '王柯勤', 'unknown', '2024-01-01 00:31:50.995411-08', '2024-04-11 14:11:50.995411-07', '2024-04-13 08:20:50.995411-07', '{159420,128015,181149,148331,171054,105726,131364,133947,113022,171873,161781,188588}', '{272667,235962}', '{324894,314574,377628,309375,347702,373671,399809,347943,356254,348874,358329,317398,308705,351581,386138,356512,363372,337592,368926,312579,301302}', '{478360,467098,458306,472299,481846,491649,466111,482461,478674,475657,488992,404934,490235,461591,419054,412946,461079,455956,468179,497808,420837,404324,495560,465106,456351,429894,487364,412423,454721,491377,471632,414802,490344,435112}', '2024-04-07 11:53:50.995413-07', '2024-04-26 15:31:50.995414-07', '2024-03-10 01:27:50.995414-08', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('javier.zxk97d3o@example.net', '郑弘浩', 'unknown', '2024-01-01 00:32:21.2393-08', '2024-04-25 08:16:21.239301-07', '2024-02-17 22:04:21.239301-08', '{121657,126365,173950,100713,107346,155753,128808,138707,139818,129728,147482,145630,142654,185949,190301,118407,156168,105637,174194,134518,117849,115622,134292,137040,133936,150240,138362,184856,152309,118442,179047,165112,168599,197275,164964,141237,120107,159715,158902,126824,151620,117506,149839,193620,115387,141070}', '{227152,288285,262658,207581,201335,287096,253553,286277,214224,222285}', '{370421,389236,332515,345452,394062,306965,341356,368712,368831,315679,357451,340362,367063,398503,310728,392875,396505,325607,339004,326671,303525,397455,370631,397288,347209,351899,350579,353578,315631,314850,359372,312068,362652,342127,302832,326195,352793,372434,393199,301987,318607,350396,311669,372263,323231,375689,391893,363737,380151}', '{441065,438395}', '2024-03-30 02:20:21.239303-07', '2024-04-25 14:55:21.239303-07', '2024-03-31 15:34:21.239304-07');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('javier.zxk97d3o@example.net', '郑弘浩', 'unknown', '2024-01-01 00:32:21.2393-08', '2024-04-25 08:16:21.239301-07', '2024-02-17 22:04:21.239301-08', '{121657,126365,173950,100713,107346,155753,128808,138707,139818,129728,147482,145630,142654,185949,190301,118407,156168,105637,174194,134518,117849,115622,134292,137040,133936,150240,138362,184856,152309,118442,179047,165112,168599,197275,164964,141237,120107,159715,158902,126824,151620,117506,149839,193620,115387,141070}', '{227152,288285,262658,207581,201335,287096,253553,286277,214224,222285}', '{370421,389236,332515,345452,394062,306965,341356,368712,368831,315679,357451,340362,367063,398503,310728,392875,396505,325607,339004,326671,303525,397455,370631,397288,347209,351899,350579,353578,315631,314850,359372,312068,362652,342127,302832,326195,352793,372434,393199,301987,318607,350396,311669,372263,323231,375689,391893,363737,380151}', '{441065,438395}', '2024-03-30 02:20:21.239303-07', '2024-04-25 14:55:21.239303-07', '2024-03-31 15:34:21.239304-07', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('grant.825qgmg9@example.net', '孟雪柳', 'unknown', '2024-01-01 00:33:17.224713-08', '2024-04-24 22:31:17.224713-07', '2024-04-18 09:40:17.224714-07', '{150966,164956,105434,103386,171573,193463,149808,112389}', '{270355,257507}', '{345511,364705,344695,394055,378954,300858,337999,331694,388634,350685,305051,303540,393950,308719,386822,386311,376831,397644,352046,307759,353476,329874,396005,326407,320532,309053,390042,310354,364563,348807,312175}', '{475500,423603,456244,400928,459439,466726,417777,454988,401981,438542,458294}', '2024-04-16 06:10:17.224715-07', '2024-04-26 23:14:17.224715-07', '2024-04-20 05:15:17.224715-07');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('grant.825qgmg9@example.net', '孟雪柳', 'unknown', '2024-01-01 00:33:17.224713-08', '2024-04-24 22:31:17.224713-07', '2024-04-18 09:40:17.224714-07', '{150966,164956,105434,103386,171573,193463,149808,112389}', '{270355,257507}', '{345511,364705,344695,394055,378954,300858,337999,331694,388634,350685,305051,303540,393950,308719,386822,386311,376831,397644,352046,307759,353476,329874,396005,326407,320532,309053,390042,310354,364563,348807,312175}', '{475500,423603,456244,400928,459439,466726,417777,454988,401981,438542,458294}', '2024-04-16 06:10:17.224715-07', '2024-04-26 23:14:17.224715-07', '2024-04-20 05:15:17.224715-07', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('zachery.17km3ggs@example.org', '白紫真', 'unknown', '2024-01-01 00:33:21.793429-08', '2024-04-21 09:12:21.79343-07', '2024-04-06 11:59:21.79343-07', '{173718,168622,119542,168100,167983,149631,127130,187949,133830,105236,177468,120576,191410}', '{209998,248220,207145,254570,258204,249784,239562,243546,283725,224652,253954,231289,256962,235371,286231,209522,232541,217506,283607,285745,251933,237389,277841,207234,290032,217328,298196,219967,239530,217765,254649,218660,216827,237039,223021,247446,241981,225695,265465,293601,234348,265570,211297,276624,270567,226577,229220,281751}', '{332892,373529,310158,368422,388617,311064,366357,346647,370698,315077,358818,347571,399277,338274,334350,341416,390823,396316,383322,329332,306975,329643,361442,376987,311546,329741,311612,355169,300159,369071,312456,383203,327238,344518,369012,358889}', '{420539,499021,478041,425995,435125,494043,451695,418073,449669,496152,412563,435161,417431,455064,499572,439647,492342,434313,495749}', '2024-04-07 12:58:21.793434-07', '2024-04-21 14:02:21.793434-07', '2024-03-14 05:18:21.793434-07');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('zachery.17km3ggs@example.org', '白紫真', 'unknown', '2024-01-01 00:33:21.793429-08', '2024-04-21 09:12:21.79343-07', '2024-04-06 11:59:21.79343-07', '{173718,168622,119542,168100,167983,149631,127130,187949,133830,105236,177468,120576,191410}', '{209998,248220,207145,254570,258204,249784,239562,243546,283725,224652,253954,231289,256962,235371,286231,209522,232541,217506,283607,285745,251933,237389,277841,207234,290032,217328,298196,219967,239530,217765,254649,218660,216827,237039,223021,247446,241981,225695,265465,293601,234348,265570,211297,276624,270567,226577,229220,281751}', '{332892,373529,310158,368422,388617,311064,366357,346647,370698,315077,358818,347571,399277,338274,334350,341416,390823,396316,383322,329332,306975,329643,361442,376987,311546,329741,311612,355169,300159,369071,312456,383203,327238,344518,369012,358889}', '{420539,499021,478041,425995,435125,494043,451695,418073,449669,496152,412563,435161,417431,455064,499572,439647,492342,434313,495749}', '2024-04-07 12:58:21.793434-07', '2024-04-21 14:02:21.793434-07', '2024-03-14 05:18:21.793434-07', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('gloria.igmz81zp@example.com', '莫瀚德', 'unknown', '2024-01-01 00:33:54.943387-08', '2024-04-27 18:58:54.943387-07', '2024-03-13 03:56:54.943388-07', '{169861,122476,124845,124937,182683,102021,176780,100050,177252,197794,183491,129648,101456,117347,185605,106088,165470,134811,187959,191000,117346,143978,193119,174440,144957,189087,162149,154387,116097,121126,126293,150611,196660,132629,135944,118712,188434,141441,134348,128395,143561}', '{221658,218409,294656,257072,257787,210353,259054,298001,225687,297111,212151,249141,259384,262170,238862,234853,223600,217962,262355,283859,295124,223796,289453,245539,215812,283422,255766,202660,207872,208665,296640,287585,211806,242830,223548}', '{386821,380972,319366,386561,301431,319499,390549,313686,361164}', '{480450,444911,461556,428378,401876,423590,401202,434200,479311,409789,461545,457352}', '2024-04-14 20:35:54.943389-07', '2024-04-28 03:38:54.943389-07', '2024-04-29 02:56:54.943389-07');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('gloria.igmz81zp@example.com', '莫瀚德', 'unknown', '2024-01-01 00:33:54.943387-08', '2024-04-27 18:58:54.943387-07', '2024-03-13 03:56:54.943388-07', '{169861,122476,124845,124937,182683,102021,176780,100050,177252,197794,183491,129648,101456,117347,185605,106088,165470,134811,187959,191000,117346,143978,193119,174440,144957,189087,162149,154387,116097,121126,126293,150611,196660,132629,135944,118712,188434,141441,134348,128395,143561}', '{221658,218409,294656,257072,257787,210353,259054,298001,225687,297111,212151,249141,259384,262170,238862,234853,223600,217962,262355,283859,295124,223796,289453,245539,215812,283422,255766,202660,207872,208665,296640,287585,211806,242830,223548}', '{386821,380972,319366,386561,301431,319499,390549,313686,361164}', '{480450,444911,461556,428378,401876,423590,401202,434200,479311,409789,461545,457352}', '2024-04-14 20:35:54.943389-07', '2024-04-28 03:38:54.943389-07', '2024-04-29 02:56:54.943389-07', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('marco.n3m168ks@example.org', '韩紫怡', 'unknown', '2024-01-01 00:34:45.818045-08', '2024-04-20 18:28:45.818045-07', '2024-03-04 23:40:45.818045-08', '{165508}', '{240496,275517,241981,260455,238597,203673,219484,289646,229460,203000,288701,289283,288804,229825,207928,245411,245751,256723,233599}', '{374027,368047,348243,310910,371853}', '{496513,455232,443959,454839,412980,443192,468658,471880,475653,431366,497820,485313,405839,429522,421662,455598,460491,413623,420946,481767,430276,497962,447818,417166,455158,413005,431046,490657,412658,421738,430807,471641,408140}', '2024-05-05 09:42:45.818046-07', '2024-04-24 17:24:45.818046-07', '2024-02-16 09:59:45.818046-08');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('marco.n3m168ks@example.org', '韩紫怡', 'unknown', '2024-01-01 00:34:45.818045-08', '2024-04-20 18:28:45.818045-07', '2024-03-04 23:40:45.818045-08', '{165508}', '{240496,275517,241981,260455,238597,203673,219484,289646,229460,203000,288701,289283,288804,229825,207928,245411,245751,256723,233599}', '{374027,368047,348243,310910,371853}', '{496513,455232,443959,454839,412980,443192,468658,471880,475653,431366,497820,485313,405839,429522,421662,455598,460491,413623,420946,481767,430276,497962,447818,417166,455158,413005,431046,490657,412658,421738,430807,471641,408140}', '2024-05-05 09:42:45.818046-07', '2024-04-24 17:24:45.818046-07', '2024-02-16 09:59:45.818046-08', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('deion.wv8fic9g@example.org', '余映倚', 'unknown', '2024-01-01 00:35:27.534003-08', '2024-04-27 22:58:27.534003-07', '2024-02-18 22:12:27.534004-08', '{152884,149943,160551,126824,122431,115582,190534,183282,138555,186198,131327,166108,149485,113550,172434,134599,140078,161153,187824,162999,128021,191203,185623,141251,187749,111728,100155,106999,179117,177970,113256,142429,133131,188009,106654,189470,114893,169357,135672,124429,131169}', '{223015,276081,293090,215293,256293,298204,215601,213108,224173,277336,276870,278812,260410,227576,270082,221517,202809,264634}', '{365793,382311,373988,317290,355594,389927,372464,377267,303818,340554,332595,396204,350312,373165,357540,378131,377425,362393,326116,364361,390043,382072,382201,386527,322534,379570,358923,362877,306514,374061,364389,382901,372104,384731,315990,375202,320335,307708,326194,359572,365692,313079,304163,354158,303041}', '{483942,425667,406494,437528,476859,478747,414328,450302,457438,417134,462339,437204,423259,463862,428623,488294,478418,454999,444520,403527,414527,463091,488270,415647,406733,456454,466336,499008,445478,458208,476005,422077}', '2024-03-28 18:30:27.534006-07', '2024-04-23 01:17:27.534006-07', '2024-03-05 13:57:27.534006-08');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('deion.wv8fic9g@example.org', '余映倚', 'unknown', '2024-01-01 00:35:27.534003-08', '2024-04-27 22:58:27.534003-07', '2024-02-18 22:12:27.534004-08', '{152884,149943,160551,126824,122431,115582,190534,183282,138555,186198,131327,166108,149485,113550,172434,134599,140078,161153,187824,162999,128021,191203,185623,141251,187749,111728,100155,106999,179117,177970,113256,142429,133131,188009,106654,189470,114893,169357,135672,124429,131169}', '{223015,276081,293090,215293,256293,298204,215601,213108,224173,277336,276870,278812,260410,227576,270082,221517,202809,264634}', '{365793,382311,373988,317290,355594,389927,372464,377267,303818,340554,332595,396204,350312,373165,357540,378131,377425,362393,326116,364361,390043,382072,382201,386527,322534,379570,358923,362877,306514,374061,364389,382901,372104,384731,315990,375202,320335,307708,326194,359572,365692,313079,304163,354158,303041}', '{483942,425667,406494,437528,476859,478747,414328,450302,457438,417134,462339,437204,423259,463862,428623,488294,478418,454999,444520,403527,414527,463091,488270,415647,406733,456454,466336,499008,445478,458208,476005,422077}', '2024-03-28 18:30:27.534006-07', '2024-04-23 01:17:27.534006-07', '2024-03-05 13:57:27.534006-08', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('trey.o7pqfd7d@example.net', '顾玮博', 'unknown', '2024-01-01 00:35:43.162466-08', '2024-04-08 09:04:43.162466-07', '2024-02-22 07:37:43.162466-08', '{183203,199225,175143,118576,161480,106617,164924,178496,117914,188605,172524,127004,172571,150721,170688,169630,153169,187790,194043,110734,185848,196944,186574,134475,181205,156915,112018,146508,106269,106953,115002,126603,128605,145620,120514,102528,133827,134174,169752,179936,148893,132667,189855,173101,113983}', '{293848,247931,238086,230135,262998,235169,269602,211231,212306,245843,241921,262708,221481,237577,220863,277146,214078,243855,213911,244733,233838,254704,279663,271004,241328,288583,207603,247511,264864,225684}', '{337854,383488,356936,309380,336451,355222,346146,305884,389720,398986,395780,329096}', '{401241,498675,464853,484314,439072,463519,423826,404404,473823,487962,418295,432813,488214,471009,490368,446903,466983,492471,409581,499018,460392,435798,470082,454107,483455,496201,427525,411494,478216,496815,447832,482263,498409,438532,409594,429203,432318,493183,454474,466684,406777,497635}', '2024-04-23 02:33:43.162467-07', '2024-05-02 21:52:43.162468-07', '2024-02-25 17:34:43.162468-08');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('trey.o7pqfd7d@example.net', '顾玮博', 'unknown', '2024-01-01 00:35:43.162466-08', '2024-04-08 09:04:43.162466-07', '2024-02-22 07:37:43.162466-08', '{183203,199225,175143,118576,161480,106617,164924,178496,117914,188605,172524,127004,172571,150721,170688,169630,153169,187790,194043,110734,185848,196944,186574,134475,181205,156915,112018,146508,106269,106953,115002,126603,128605,145620,120514,102528,133827,134174,169752,179936,148893,132667,189855,173101,113983}', '{293848,247931,238086,230135,262998,235169,269602,211231,212306,245843,241921,262708,221481,237577,220863,277146,214078,243855,213911,244733,233838,254704,279663,271004,241328,288583,207603,247511,264864,225684}', '{337854,383488,356936,309380,336451,355222,346146,305884,389720,398986,395780,329096}', '{401241,498675,464853,484314,439072,463519,423826,404404,473823,487962,418295,432813,488214,471009,490368,446903,466983,492471,409581,499018,460392,435798,470082,454107,483455,496201,427525,411494,478216,496815,447832,482263,498409,438532,409594,429203,432318,493183,454474,466684,406777,497635}', '2024-04-23 02:33:43.162467-07', '2024-05-02 21:52:43.162468-07', '2024-02-25 17:34:43.162468-08', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('ervin.jm5bdmk6@example.com', '朱远鸿', 'unknown', '2024-01-01 00:35:55.41872-08', '2024-04-25 11:11:55.41872-07', '2024-03-03 04:04:55.41872-08', '{102511,163888,188964,143758,198138,185166,143643,189283,181222,118108,153376,135372,174290,168266,178096,196473,105854,144977,181896,110130,144489,163776,152908,126238,155398,179624,183163,122311,145898,105870,119424,138905,168381,162452,196850,104744,129783,174171,176808,192449,159900,133641,107698,127659,194659,166444}', '{206407,231955,279504,242661,206487,222055,220794,297285,215450,205916,296896,271353,258021,284149,266378,240598,267212,222391,207242,225690,231705,217374}', '{332040,346268,355136,341404,353603,384191,362021,354226,329667,327269,328673,312393,398604,359802,369254,323508,329015,377995,346445,375224,378549,369483,301241,341179,364661,304394,324911}', '{461266,479035,407124,404103,465862,474542,468457}', '2024-04-20 13:32:55.418722-07', '2024-04-22 15:44:55.418722-07', '2024-02-24 19:32:55.418722-08');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('ervin.jm5bdmk6@example.com', '朱远鸿', 'unknown', '2024-01-01 00:35:55.41872-08', '2024-04-25 11:11:55.41872-07', '2024-03-03 04:04:55.41872-08', '{102511,163888,188964,143758,198138,185166,143643,189283,181222,118108,153376,135372,174290,168266,178096,196473,105854,144977,181896,110130,144489,163776,152908,126238,155398,179624,183163,122311,145898,105870,119424,138905,168381,162452,196850,104744,129783,174171,176808,192449,159900,133641,107698,127659,194659,166444}', '{206407,231955,279504,242661,206487,222055,220794,297285,215450,205916,296896,271353,258021,284149,266378,240598,267212,222391,207242,225690,231705,217374}', '{332040,346268,355136,341404,353603,384191,362021,354226,329667,327269,328673,312393,398604,359802,369254,323508,329015,377995,346445,375224,378549,369483,301241,341179,364661,304394,324911}', '{461266,479035,407124,404103,465862,474542,468457}', '2024-04-20 13:32:55.418722-07', '2024-04-22 15:44:55.418722-07', '2024-02-24 19:32:55.418722-08', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('ulices.31re9x53@example.net', '潘以萱', 'unknown', '2024-01-01 00:36:07.680359-08', '2024-04-30 12:35:07.680359-07', '2024-03-19 01:44:07.680359-07', '{133836,153301,131730,174856,112524,161598,166414,177047,119084,128338,141867,188074,185024,148030,144620,184260,105687,141816,137684,174639}', '{218640,232817,235521,210488,245950,214771,275012,282552,298382,271506,287674,226696,263640,280577,277912,269174,264620,251906,277663,276430,253092,250850,263801,241724,257544,296566,210731,229640,203754,210406,267915,293174,209722,207000,252598,222734,280325,289429,256948}', '{340993,370691,369621,325126,351487,314934,345775,380852,393697}', '{423946,466116,404908,442637,429479}', '2024-03-29 13:34:07.68036-07', '2024-04-22 11:19:07.680361-07', '2024-04-03 15:12:07.680361-07');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('ulices.31re9x53@example.net', '潘以萱', 'unknown', '2024-01-01 00:36:07.680359-08', '2024-04-30 12:35:07.680359-07', '2024-03-19 01:44:07.680359-07', '{133836,153301,131730,174856,112524,161598,166414,177047,119084,128338,141867,188074,185024,148030,144620,184260,105687,141816,137684,174639}', '{218640,232817,235521,210488,245950,214771,275012,282552,298382,271506,287674,226696,263640,280577,277912,269174,264620,251906,277663,276430,253092,250850,263801,241724,257544,296566,210731,229640,203754,210406,267915,293174,209722,207000,252598,222734,280325,289429,256948}', '{340993,370691,369621,325126,351487,314934,345775,380852,393697}', '{423946,466116,404908,442637,429479}', '2024-03-29 13:34:07.68036-07', '2024-04-22 11:19:07.680361-07', '2024-04-03 15:12:07.680361-07', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('julie.sdd9eq1f@example.org', '唐瀚君', 'unknown', '2024-01-01 00:36:12.014232-08', '2024-04-10 02:05:12.014232-07', '2024-04-18 01:17:12.014232-07', '{193900,133230,121771,129728,158541,196473}', '{254778,200781,216348,284339,263445,213325,273128,244438,238751,270980,223167,291573,217941,286609,244791,205094,284115,217341,233626,235930,208907,238698,292134,246948,262818,286053,265666,285440,214899,288351,229963,275317,227825,280961,214042,245782,242264,203118,291398,294752,212133,252331,263024,209025,250545,295178,209252,215165}', '{366186}', '{437590,414696,458484,472667,488779,490443,454998,445964,476353,411748,416588,494431,451087,464730,482360,464189,439861,443466,408721,416910,431167}', '2024-04-25 05:56:12.014233-07', '2024-05-03 03:31:12.014234-07', '2024-03-11 16:23:12.014234-07');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('julie.sdd9eq1f@example.org', '唐瀚君', 'unknown', '2024-01-01 00:36:12.014232-08', '2024-04-10 02:05:12.014232-07', '2024-04-18 01:17:12.014232-07', '{193900,133230,121771,129728,158541,196473}', '{254778,200781,216348,284339,263445,213325,273128,244438,238751,270980,223167,291573,217941,286609,244791,205094,284115,217341,233626,235930,208907,238698,292134,246948,262818,286053,265666,285440,214899,288351,229963,275317,227825,280961,214042,245782,242264,203118,291398,294752,212133,252331,263024,209025,250545,295178,209252,215165}', '{366186}', '{437590,414696,458484,472667,488779,490443,454998,445964,476353,411748,416588,494431,451087,464730,482360,464189,439861,443466,408721,416910,431167}', '2024-04-25 05:56:12.014233-07', '2024-05-03 03:31:12.014234-07', '2024-03-11 16:23:12.014234-07', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('alice.z7b4n5bl@example.org', '黎菲霞', 'unknown', '2024-01-01 00:36:41.800764-08', '2024-04-17 09:36:41.800764-07', '2024-04-25 03:09:41.800764-07', '{103842,118376,114326,158324,112014,178496,168092,151242,111477,123101,138761,131778,119676,175324,110044,150749,169344,139000,195675,126123,183569,111626,111942,129915,195013,137631,106332,169684,178098,191807,166872,183899,165482,154933,136835,173028,146227,138137,111513,197910,157301,111956,164136}', '{294733,289724,284339,203338,269368,200975,227269,295007,283553,258875,234041,250458,238747,274073,228259,246306,234284,291814,286549,258481,247245,222584,298897,289398,272754,293228}', '{320532,347408,332278,389495,323784,333080,387493,342193,341582,375880,389277,399690,358470,339294,344006,399613,327428,330502,396719,390977,374451,331695,384405,360647,394249,365169,329535,363314,301072,367593,340325,397446,353000,388504,373873,361107,318741,340357,398622,321091,323870,399011,355323,379833,332298,334714,354159}', '{428957,425445,476395,408048,437749,443417,406717,408499,486846,466074,486572}', '2024-04-23 17:53:41.800766-07', '2024-05-02 08:39:41.800767-07', '2024-03-17 09:39:41.800767-07');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('alice.z7b4n5bl@example.org', '黎菲霞', 'unknown', '2024-01-01 00:36:41.800764-08', '2024-04-17 09:36:41.800764-07', '2024-04-25 03:09:41.800764-07', '{103842,118376,114326,158324,112014,178496,168092,151242,111477,123101,138761,131778,119676,175324,110044,150749,169344,139000,195675,126123,183569,111626,111942,129915,195013,137631,106332,169684,178098,191807,166872,183899,165482,154933,136835,173028,146227,138137,111513,197910,157301,111956,164136}', '{294733,289724,284339,203338,269368,200975,227269,295007,283553,258875,234041,250458,238747,274073,228259,246306,234284,291814,286549,258481,247245,222584,298897,289398,272754,293228}', '{320532,347408,332278,389495,323784,333080,387493,342193,341582,375880,389277,399690,358470,339294,344006,399613,327428,330502,396719,390977,374451,331695,384405,360647,394249,365169,329535,363314,301072,367593,340325,397446,353000,388504,373873,361107,318741,340357,398622,321091,323870,399011,355323,379833,332298,334714,354159}', '{428957,425445,476395,408048,437749,443417,406717,408499,486846,466074,486572}', '2024-04-23 17:53:41.800766-07', '2024-05-02 08:39:41.800767-07', '2024-03-17 09:39:41.800767-07', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('hans.03970s72@example.com', '邹淑颖', 'unknown', '2024-01-01 00:36:45.815954-08', '2024-04-08 21:13:45.815955-07', '2024-03-27 14:36:45.815955-07', '{117534,117663,102539,186070,105524,174467,121907,161751,117714,165432,122688,148180,154363,117743,124591,108015,111035,186359,177654,187013,135356,141719,132122,131957,169598,109200,110633,175440,166419,188152,101940}', '{252333,222567,279501,250379,213158,290868,296143,264514,276692,286025,228746,254446,283124,252194,299728,258263,260345,231989,237399,234186,241447}', '{348854,390342,386213}', '{455698,420591,425841,405952,464128,491347,465057,488411,459060,468491,437445,474974,443877,426213,435353,433135,424439,465242,415449,467706,408324,454249,424008,478914,413523,443734}', '2024-03-30 13:01:45.815956-07', '2024-04-25 10:24:45.815956-07', '2024-04-07 04:02:45.815957-07');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('hans.03970s72@example.com', '邹淑颖', 'unknown', '2024-01-01 00:36:45.815954-08', '2024-04-08 21:13:45.815955-07', '2024-03-27 14:36:45.815955-07', '{117534,117663,102539,186070,105524,174467,121907,161751,117714,165432,122688,148180,154363,117743,124591,108015,111035,186359,177654,187013,135356,141719,132122,131957,169598,109200,110633,175440,166419,188152,101940}', '{252333,222567,279501,250379,213158,290868,296143,264514,276692,286025,228746,254446,283124,252194,299728,258263,260345,231989,237399,234186,241447}', '{348854,390342,386213}', '{455698,420591,425841,405952,464128,491347,465057,488411,459060,468491,437445,474974,443877,426213,435353,433135,424439,465242,415449,467706,408324,454249,424008,478914,413523,443734}', '2024-03-30 13:01:45.815956-07', '2024-04-25 10:24:45.815956-07', '2024-04-07 04:02:45.815957-07', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('luis.grbcv5pk@example.com', '赵歆翠', 'unknown', '2024-01-01 00:36:51.082612-08', '2024-04-11 12:40:51.082612-07', '2024-03-09 06:04:51.082612-08', '{140637,181701,175008,169486,143922,122143,113063,140168,172661,182838,185611,130296,115970,127004,109197,173989,123089,187023,141364,110985,184040,127107,176925,171390,152716,148935,140144,130434,148295,127575,153500,175579,123921,179066,129610,106028,121760,100626,148747,170795,161084,159874,137117,191057,120833}', '{296109,212594,219166,275970,277225,219090,289749,243705,267240,206534,240206,257883,296219,220236,225169,293615,270032,218362,234922,213993,299037,210749,261625,223991,258855,230512,229046}', '{357041,396815,342166,362698,332764,358069,389064,322500,344025,394045,322654,383872,354335,313497,358968,382446,385397,336665,301670,388418,306114,348318,360612,312570,342542,331858,385099,330328,322357,370275,326166,334842,364160,308079}', '{496420,420035,421408,469909,454096,432407,417967,433521,457066,475008,452033,476736,457282,489442,437664,489598,454043,424125,407250,494988,439538,416542,444250,420094,468139,432756,420721,443126,469584,467974,458915,402478}', '2024-04-09 21:43:51.082614-07', '2024-04-23 23:06:51.082614-07', '2024-02-20 10:06:51.082614-08');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('luis.grbcv5pk@example.com', '赵歆翠', 'unknown', '2024-01-01 00:36:51.082612-08', '2024-04-11 12:40:51.082612-07', '2024-03-09 06:04:51.082612-08', '{140637,181701,175008,169486,143922,122143,113063,140168,172661,182838,185611,130296,115970,127004,109197,173989,123089,187023,141364,110985,184040,127107,176925,171390,152716,148935,140144,130434,148295,127575,153500,175579,123921,179066,129610,106028,121760,100626,148747,170795,161084,159874,137117,191057,120833}', '{296109,212594,219166,275970,277225,219090,289749,243705,267240,206534,240206,257883,296219,220236,225169,293615,270032,218362,234922,213993,299037,210749,261625,223991,258855,230512,229046}', '{357041,396815,342166,362698,332764,358069,389064,322500,344025,394045,322654,383872,354335,313497,358968,382446,385397,336665,301670,388418,306114,348318,360612,312570,342542,331858,385099,330328,322357,370275,326166,334842,364160,308079}', '{496420,420035,421408,469909,454096,432407,417967,433521,457066,475008,452033,476736,457282,489442,437664,489598,454043,424125,407250,494988,439538,416542,444250,420094,468139,432756,420721,443126,469584,467974,458915,402478}', '2024-04-09 21:43:51.082614-07', '2024-04-23 23:06:51.082614-07', '2024-02-20 10:06:51.082614-08', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('gabrielle.asdm7sf9@example.org', '向怡钰', 'unknown', '2024-01-01 00:37:00.612936-08', '2024-04-08 18:20:00.612936-07', '2024-04-18 21:17:00.612937-07', '{111911,143429,112195,160814,153224,179794,130353,107044,149189,127376,108942,190113,182658,122599,119340,117129,139418,153504,138382,131851,160672,126945,128829,179002,142782,190617,134791}', '{233672,259754,294052,265610,248717,279062,266909,299380,236303,247394,212883,294124,207322,228224,271958,237515,252074,243261,220769,227876,201258,272126,294211,216528,251688,267398,272379,254101,251382,243183,255389,206547,238663,213253,215272,296896,215256,281531,278970,210696,242372,285534,229969,201105}', '{348091,386235,344010,385602,318448,351008,313277,323937}', '{418631,459193,433992,462860,465903,409168,469497,465378,417183,463593,459903,476011,493562,456419,441472,447867,409500,415919,437240,494177,475336}', '2024-04-14 15:59:00.612939-07', '2024-05-02 17:50:00.612939-07', '2024-03-04 17:39:00.612939-08');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('gabrielle.asdm7sf9@example.org', '向怡钰', 'unknown', '2024-01-01 00:37:00.612936-08', '2024-04-08 18:20:00.612936-07', '2024-04-18 21:17:00.612937-07', '{111911,143429,112195,160814,153224,179794,130353,107044,149189,127376,108942,190113,182658,122599,119340,117129,139418,153504,138382,131851,160672,126945,128829,179002,142782,190617,134791}', '{233672,259754,294052,265610,248717,279062,266909,299380,236303,247394,212883,294124,207322,228224,271958,237515,252074,243261,220769,227876,201258,272126,294211,216528,251688,267398,272379,254101,251382,243183,255389,206547,238663,213253,215272,296896,215256,281531,278970,210696,242372,285534,229969,201105}', '{348091,386235,344010,385602,318448,351008,313277,323937}', '{418631,459193,433992,462860,465903,409168,469497,465378,417183,463593,459903,476011,493562,456419,441472,447867,409500,415919,437240,494177,475336}', '2024-04-14 15:59:00.612939-07', '2024-05-02 17:50:00.612939-07', '2024-03-04 17:39:00.612939-08', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('reece.hu8gsc6v@example.net', '段杰书', 'unknown', '2024-01-01 00:37:14.350877-08', '2024-04-27 11:49:14.350877-07', '2024-04-02 20:46:14.350878-07', '{112802,142496,136067,166250,139689,173763,185410,151986,100512,167959,160538,140593,195558,127033,109400,183908,103634,196758,186501}', '{215811,251276,232593,254551,255480,276777,227728,219060,256408,266160,242496,283073,271777,267122,244756,283324,267498,278522,251152,243599,290355,277307}', '{301567,322342,393007,383265,358253,351228,315490,301648,396267,349429,365163,311764,346143,329670,389309,378630,344937,351633,393737,323240,374901,387883}', '{424676,456995,492507,492878,415614,400948,412168,403017,417228,450646,441288,452705,432043,410035,452769,423913,486842,407237,442454,429267,474206,481509,483407,405530,426515,496914,424578,461827,425922,413229,491735,450878,419588,438897,498747,459844,490970,459697,453176,423754,490819,406641,457730,431906,452430}', '2024-05-01 05:40:14.350882-07', '2024-04-26 15:46:14.350883-07', '2024-05-04 06:07:14.350883-07');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('reece.hu8gsc6v@example.net', '段杰书', 'unknown', '2024-01-01 00:37:14.350877-08', '2024-04-27 11:49:14.350877-07', '2024-04-02 20:46:14.350878-07', '{112802,142496,136067,166250,139689,173763,185410,151986,100512,167959,160538,140593,195558,127033,109400,183908,103634,196758,186501}', '{215811,251276,232593,254551,255480,276777,227728,219060,256408,266160,242496,283073,271777,267122,244756,283324,267498,278522,251152,243599,290355,277307}', '{301567,322342,393007,383265,358253,351228,315490,301648,396267,349429,365163,311764,346143,329670,389309,378630,344937,351633,393737,323240,374901,387883}', '{424676,456995,492507,492878,415614,400948,412168,403017,417228,450646,441288,452705,432043,410035,452769,423913,486842,407237,442454,429267,474206,481509,483407,405530,426515,496914,424578,461827,425922,413229,491735,450878,419588,438897,498747,459844,490970,459697,453176,423754,490819,406641,457730,431906,452430}', '2024-05-01 05:40:14.350882-07', '2024-04-26 15:46:14.350883-07', '2024-05-04 06:07:14.350883-07', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('lucio.stn2vght@example.net', '叶寒烟', 'unknown', '2024-01-01 00:37:33.350327-08', '2024-04-19 02:02:33.350328-07', '2024-03-13 21:41:33.350328-07', '{155666,101471,173087,151589,199985,131745,175062,184839,100885,121929,182387,190666,106432,109648,179422,150332,162243,169714,143106,148669,150612,131826,115556,133936,106034,102159,195268,178067,155647,119833,145684,109487,107979,140715,144408,177095,126739}', '{279127,231001,238075,227930,297174,289560,221535,288105,280467,225840,248082,243729,268442,268831,261941,269266,254227,236613,292155,272480,255893,293459,208500,269019,202104,211352,295274}', '{342110,389944,302296,355305,360662,310497,330835,368776,303304,349493,300985,331663,374916,311455,392317,314321,368299,382271,318999,323135,350755,358903,356531,396633,363310,349995,305024,335186,316698,326753,337161,379575,326915,351779,324206,335424,311754,349948,377117,371583,313222,301926,350126,396846,318470,336911}', '{424375,407549,493176,409915,444138,401572,407975,423906,420990,473089,490807,402027,486779,492293,472241,403299,401009,496285,432466,426181,406363,481215,414788,427776,419693,431885,415468,475518,449877,410882,420176,445991,471386,476642,435672,452422,408840}', '2024-04-03 08:18:33.350332-07', '2024-04-20 15:15:33.350332-07', '2024-04-19 14:03:33.350332-07');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('lucio.stn2vght@example.net', '叶寒烟', 'unknown', '2024-01-01 00:37:33.350327-08', '2024-04-19 02:02:33.350328-07', '2024-03-13 21:41:33.350328-07', '{155666,101471,173087,151589,199985,131745,175062,184839,100885,121929,182387,190666,106432,109648,179422,150332,162243,169714,143106,148669,150612,131826,115556,133936,106034,102159,195268,178067,155647,119833,145684,109487,107979,140715,144408,177095,126739}', '{279127,231001,238075,227930,297174,289560,221535,288105,280467,225840,248082,243729,268442,268831,261941,269266,254227,236613,292155,272480,255893,293459,208500,269019,202104,211352,295274}', '{342110,389944,302296,355305,360662,310497,330835,368776,303304,349493,300985,331663,374916,311455,392317,314321,368299,382271,318999,323135,350755,358903,356531,396633,363310,349995,305024,335186,316698,326753,337161,379575,326915,351779,324206,335424,311754,349948,377117,371583,313222,301926,350126,396846,318470,336911}', '{424375,407549,493176,409915,444138,401572,407975,423906,420990,473089,490807,402027,486779,492293,472241,403299,401009,496285,432466,426181,406363,481215,414788,427776,419693,431885,415468,475518,449877,410882,420176,445991,471386,476642,435672,452422,408840}', '2024-04-03 08:18:33.350332-07', '2024-04-20 15:15:33.350332-07', '2024-04-19 14:03:33.350332-07', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('clarabelle.s7nkg377@example.com', '叶百川', 'unknown', '2024-01-01 00:38:32.374133-08', '2024-04-07 16:11:32.374134-07', '2024-02-27 07:19:32.374134-08', '{129971,112895,161647,174528,118375,111999,137849,113921,178048,101028,121052,153129,140139,182859}', '{248269,226325,262021,227605,233703,217440,297606,246537,229070,201660,263892,207048,280332,217185,255272,255113,201679,239242,280273,284675,221644,210462,217348,298936,270122,241229}', '{395499,395499}', '{461029,495632,478408,453530,471060,498652,484844,411171,419577,471728,491234,424781,401875,426703,494378,447980,404255}', '2024-05-04 19:08:32.374135-07', '2024-04-29 01:44:32.374135-07', '2024-04-10 04:08:32.374136-07');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('clarabelle.s7nkg377@example.com', '叶百川', 'unknown', '2024-01-01 00:38:32.374133-08', '2024-04-07 16:11:32.374134-07', '2024-02-27 07:19:32.374134-08', '{129971,112895,161647,174528,118375,111999,137849,113921,178048,101028,121052,153129,140139,182859}', '{248269,226325,262021,227605,233703,217440,297606,246537,229070,201660,263892,207048,280332,217185,255272,255113,201679,239242,280273,284675,221644,210462,217348,298936,270122,241229}', '{395499,395499}', '{461029,495632,478408,453530,471060,498652,484844,411171,419577,471728,491234,424781,401875,426703,494378,447980,404255}', '2024-05-04 19:08:32.374135-07', '2024-04-29 01:44:32.374135-07', '2024-04-10 04:08:32.374136-07', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('reta.mnxprtmo@example.org', '任紫超', 'unknown', '2024-01-01 00:38:44.484996-08', '2024-04-27 06:43:44.484996-07', '2024-02-16 17:54:44.484996-08', '{139494,148185,182186,158381,148398,172735,112218,171336,118917,139185,171021}', '{235685,217759,209217,257953,263472,265269,298102,276839,221901,253892,245284,249382,226459,259024,210837,284784,277852,281850,232769,273845,269007,291244,235570,270023,268855}', '{388564,373498,360307,332199,343087,305368,317462,309039,383914,355163,378331,363124,340920,327207,319192,369041,370031,382466,332167,305617,384042,303518,306731,398591,351290,351397,349822,301770,341200,374392,302537,342920,334838,363272,340793}', '{494485}', '2024-04-07 14:09:44.484998-07', '2024-05-03 23:36:44.484998-07', '2024-04-15 13:02:44.484998-07');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('reta.mnxprtmo@example.org', '任紫超', 'unknown', '2024-01-01 00:38:44.484996-08', '2024-04-27 06:43:44.484996-07', '2024-02-16 17:54:44.484996-08', '{139494,148185,182186,158381,148398,172735,112218,171336,118917,139185,171021}', '{235685,217759,209217,257953,263472,265269,298102,276839,221901,253892,245284,249382,226459,259024,210837,284784,277852,281850,232769,273845,269007,291244,235570,270023,268855}', '{388564,373498,360307,332199,343087,305368,317462,309039,383914,355163,378331,363124,340920,327207,319192,369041,370031,382466,332167,305617,384042,303518,306731,398591,351290,351397,349822,301770,341200,374392,302537,342920,334838,363272,340793}', '{494485}', '2024-04-07 14:09:44.484998-07', '2024-05-03 23:36:44.484998-07', '2024-04-15 13:02:44.484998-07', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('meredith.plv9k5aa@example.org', '廖娇语', 'unknown', '2024-01-01 00:38:58.563281-08', '2024-04-28 00:08:58.563281-07', '2024-04-04 17:55:58.563281-07', '{134336,103638,147548,125083,132191,174285,172219,183232,145172,107544,148670,128335,135809,144340,103894,138454,158747,175301,150399,140073,128645,145359,149373,142273,137621,194635,105914,115240,109356,185353,102133,198550,122479,164294,155670,105920,166255}', '{210760,205823,204819,257450,254291,278248,218292,292819,267760,201354,213574,272864,284494,293530,290519,203270,225504,256901,242774,236820,248158,220928}', '{381239,360708,372466,353641,302037,371217,310216,310503,315902,355559,330618,320296,396249,326074,368615,394395,323144,303766,329481,358111,342151,329821,342030,377057,376803,374112,324199,311377,372660,378841,339347,335116,303360,353622,332769,340167,393590,382188,340298,395058}', '{434851,483807,443681,413983,495199,440077,460202,433516,487779,411165,469720,478413,445156,414076,441763,406889,413546,465016,408177,484043,465271,492930,454227,443372,440081,424431,457548,481061,493924,421313,434124,405036,484153,415571,414678,416705,426147,464950,412241,483485,440699,432404,435047,482244,445632,474904,429236}', '2024-03-24 20:06:58.563284-07', '2024-04-30 06:35:58.563284-07', '2024-03-30 21:54:58.563285-07');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('meredith.plv9k5aa@example.org', '廖娇语', 'unknown', '2024-01-01 00:38:58.563281-08', '2024-04-28 00:08:58.563281-07', '2024-04-04 17:55:58.563281-07', '{134336,103638,147548,125083,132191,174285,172219,183232,145172,107544,148670,128335,135809,144340,103894,138454,158747,175301,150399,140073,128645,145359,149373,142273,137621,194635,105914,115240,109356,185353,102133,198550,122479,164294,155670,105920,166255}', '{210760,205823,204819,257450,254291,278248,218292,292819,267760,201354,213574,272864,284494,293530,290519,203270,225504,256901,242774,236820,248158,220928}', '{381239,360708,372466,353641,302037,371217,310216,310503,315902,355559,330618,320296,396249,326074,368615,394395,323144,303766,329481,358111,342151,329821,342030,377057,376803,374112,324199,311377,372660,378841,339347,335116,303360,353622,332769,340167,393590,382188,340298,395058}', '{434851,483807,443681,413983,495199,440077,460202,433516,487779,411165,469720,478413,445156,414076,441763,406889,413546,465016,408177,484043,465271,492930,454227,443372,440081,424431,457548,481061,493924,421313,434124,405036,484153,415571,414678,416705,426147,464950,412241,483485,440699,432404,435047,482244,445632,474904,429236}', '2024-03-24 20:06:58.563284-07', '2024-04-30 06:35:58.563284-07', '2024-03-30 21:54:58.563285-07', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('amparo.qhetk3oa@example.com', '陈圣帆', 'unknown', '2024-01-01 00:39:41.139732-08', '2024-04-22 05:55:41.139732-07', '2024-03-06 11:20:41.139732-08', '{128651,187664,196445,105658,127708,106107,169669,141162,193763,152091,168618,142751,183576,111281,150037,175488,181136,151631,140208,105707,183970,141516,137357,120147,118910,160744,115993,189139,192449,124204,123353,130251,128261,191348,100220,141614,179930,133973,123642,102488,112795,136655,164579,152678}', '{259193,245361,208141,298249,289718,259643,267609,295624,262275,245919,200594,258755,245176,297124,205935,244557,230142,273713,262138,254192,257838,256173,254980,260078,215675,252250,275037,210815,246789,201044,286848,286719,248457,212784,267721,255440,276039,289272}', '{374377,353086,300154,336448,375378,368217,382340,381646,367089,319435,334194,385519,343226,353981,374961,397898,385071,385850,396972,389822,336587,330320,388467,377045,345711,353239,324770,339738,359278}', '{457522,453948,485139,430804,476089,499633,439786,474306,483470,412399,407646,488983,407808,431570,484935,401010,403684,458321,453773,409761,496936,489550,464090,474804,483602}', '2024-03-26 07:21:41.139734-07', '2024-04-22 06:22:41.139734-07', '2024-02-26 15:37:41.139735-08');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('amparo.qhetk3oa@example.com', '陈圣帆', 'unknown', '2024-01-01 00:39:41.139732-08', '2024-04-22 05:55:41.139732-07', '2024-03-06 11:20:41.139732-08', '{128651,187664,196445,105658,127708,106107,169669,141162,193763,152091,168618,142751,183576,111281,150037,175488,181136,151631,140208,105707,183970,141516,137357,120147,118910,160744,115993,189139,192449,124204,123353,130251,128261,191348,100220,141614,179930,133973,123642,102488,112795,136655,164579,152678}', '{259193,245361,208141,298249,289718,259643,267609,295624,262275,245919,200594,258755,245176,297124,205935,244557,230142,273713,262138,254192,257838,256173,254980,260078,215675,252250,275037,210815,246789,201044,286848,286719,248457,212784,267721,255440,276039,289272}', '{374377,353086,300154,336448,375378,368217,382340,381646,367089,319435,334194,385519,343226,353981,374961,397898,385071,385850,396972,389822,336587,330320,388467,377045,345711,353239,324770,339738,359278}', '{457522,453948,485139,430804,476089,499633,439786,474306,483470,412399,407646,488983,407808,431570,484935,401010,403684,458321,453773,409761,496936,489550,464090,474804,483602}', '2024-03-26 07:21:41.139734-07', '2024-04-22 06:22:41.139734-07', '2024-02-26 15:37:41.139735-08', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('joshua.h4421xv8@example.net', '冯晓青', 'unknown', '2024-01-01 00:40:16.139686-08', '2024-04-09 03:29:16.139686-07', '2024-02-08 00:27:16.139687-08', '{114523,149637,101789,133480,185422,181795,165156,190103,161044,154674,156775,179187,104042,110229,159124,113939,104888,125609,130289,188906,102600,187999,190260,120776,190084,120434,134559,194548,139718,168395,124774,106984,104038,199770}', '{249135,251591,242363,250568,200751,283831,293960,298318,268325,287577,268142,282999,271421,298925,271049,231749,292374,214603,246517,215600,224662,285384}', '{307850,344507,376551,349544,302358,387751,387270,365425,390822,377917,383589,303261,302446,369328,365041,330780,374379,338222,377159,358223,316074,318872,357688,375476}', '{496118,424777,453373,414279,438520,451054,445944,482315,430488,403533,487937,413071,438979,442890,491148,468666,448359,453536,496801,452756}', '2024-04-25 20:47:16.13969-07', '2024-04-22 21:22:16.139691-07', '2024-04-27 11:00:16.139691-07');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('joshua.h4421xv8@example.net', '冯晓青', 'unknown', '2024-01-01 00:40:16.139686-08', '2024-04-09 03:29:16.139686-07', '2024-02-08 00:27:16.139687-08', '{114523,149637,101789,133480,185422,181795,165156,190103,161044,154674,156775,179187,104042,110229,159124,113939,104888,125609,130289,188906,102600,187999,190260,120776,190084,120434,134559,194548,139718,168395,124774,106984,104038,199770}', '{249135,251591,242363,250568,200751,283831,293960,298318,268325,287577,268142,282999,271421,298925,271049,231749,292374,214603,246517,215600,224662,285384}', '{307850,344507,376551,349544,302358,387751,387270,365425,390822,377917,383589,303261,302446,369328,365041,330780,374379,338222,377159,358223,316074,318872,357688,375476}', '{496118,424777,453373,414279,438520,451054,445944,482315,430488,403533,487937,413071,438979,442890,491148,468666,448359,453536,496801,452756}', '2024-04-25 20:47:16.13969-07', '2024-04-22 21:22:16.139691-07', '2024-04-27 11:00:16.139691-07', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('alvena.eazqeyyd@example.org', '白君壁', 'unknown', '2024-01-01 00:40:35.270121-08', '2024-04-29 00:36:35.270121-07', '2024-03-18 02:10:35.270121-07', '{137194,163938,117121,164703,120185,148808,173677,179444,122956,132642,143391,136466,142347,197186,131054,194993,197621,173219,171996,190745,193500,158770,157346,138396,161372,112555,174419,189638,144982,190410,156322,139037,171794,123318,194049,143442,159833,138807,105889,127725,118387,117177}', '{243977,266987,206374,244291,211204,209675,260977,238391,273029,236581,280879,266270,226524,232584,237422,272892,242647,279790,205706,210883,231882,292644,291912,243489,266289,293031,280485,246179,275646,235177,292728,276205,218881,247496,223188,216251,256849,295459,290731,276201,248538,281693,202443,257818,282216,216174}', '{309716,368668,388114,360831,357882,372020,312581,303093,374080,395919,309953,312618,375686,359709,337528,317912,318059,304104,303132,333961,383351}', '{479771,417165,462458,497658,438396,454822,455852,415004,437555,455466,474120,417386,406404,492444,406532,400489,471600,411326,414689,454959,478377,446828,415122,466896,485315}', '2024-05-03 12:06:35.270124-07', '2024-04-28 05:57:35.270124-07', '2024-03-19 20:07:35.270124-07');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('alvena.eazqeyyd@example.org', '白君壁', 'unknown', '2024-01-01 00:40:35.270121-08', '2024-04-29 00:36:35.270121-07', '2024-03-18 02:10:35.270121-07', '{137194,163938,117121,164703,120185,148808,173677,179444,122956,132642,143391,136466,142347,197186,131054,194993,197621,173219,171996,190745,193500,158770,157346,138396,161372,112555,174419,189638,144982,190410,156322,139037,171794,123318,194049,143442,159833,138807,105889,127725,118387,117177}', '{243977,266987,206374,244291,211204,209675,260977,238391,273029,236581,280879,266270,226524,232584,237422,272892,242647,279790,205706,210883,231882,292644,291912,243489,266289,293031,280485,246179,275646,235177,292728,276205,218881,247496,223188,216251,256849,295459,290731,276201,248538,281693,202443,257818,282216,216174}', '{309716,368668,388114,360831,357882,372020,312581,303093,374080,395919,309953,312618,375686,359709,337528,317912,318059,304104,303132,333961,383351}', '{479771,417165,462458,497658,438396,454822,455852,415004,437555,455466,474120,417386,406404,492444,406532,400489,471600,411326,414689,454959,478377,446828,415122,466896,485315}', '2024-05-03 12:06:35.270124-07', '2024-04-28 05:57:35.270124-07', '2024-03-19 20:07:35.270124-07', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('gregg.yu0tqxfp@example.net', '万香怡', 'unknown', '2024-01-01 00:41:18.69495-08', '2024-04-29 09:19:18.69495-07', '2024-02-29 22:42:18.69495-08', '{175282,192173,141562,120419,102829,138368}', '{237403,236118,211031,254538,233304,223323,250305,287893,250558,256558,279836,216829,216764,229205,298065,267101,255888,221772,286141,283474,206942}', '{397733,360070,304685,395963,385119,377764,382313,340287,383236,325201,390516,394986,301645,346373,383706,337273,353621,367271,310406,371026,380895,303947,385943,363260,368014,389736,337410,398781,372474,305963,322346,333016}', '{439725,460111,470916,434137,424443,481029,417099,415893,418291,476748,493425,432909,487670,423046,433067,413410,410650,458191,452495,440594,417680,465709,427832,434431,487845,454599,455334,431436,476297,438209,449184,468149}', '2024-04-18 05:38:18.694952-07', '2024-04-23 01:02:18.694953-07', '2024-03-13 11:51:18.694953-07');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('gregg.yu0tqxfp@example.net', '万香怡', 'unknown', '2024-01-01 00:41:18.69495-08', '2024-04-29 09:19:18.69495-07', '2024-02-29 22:42:18.69495-08', '{175282,192173,141562,120419,102829,138368}', '{237403,236118,211031,254538,233304,223323,250305,287893,250558,256558,279836,216829,216764,229205,298065,267101,255888,221772,286141,283474,206942}', '{397733,360070,304685,395963,385119,377764,382313,340287,383236,325201,390516,394986,301645,346373,383706,337273,353621,367271,310406,371026,380895,303947,385943,363260,368014,389736,337410,398781,372474,305963,322346,333016}', '{439725,460111,470916,434137,424443,481029,417099,415893,418291,476748,493425,432909,487670,423046,433067,413410,410650,458191,452495,440594,417680,465709,427832,434431,487845,454599,455334,431436,476297,438209,449184,468149}', '2024-04-18 05:38:18.694952-07', '2024-04-23 01:02:18.694953-07', '2024-03-13 11:51:18.694953-07', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('casimer.kfjqzr41@example.org', '唐怡钰', 'unknown', '2024-01-01 00:41:20.922761-08', '2024-04-06 07:45:20.922761-07', '2024-03-21 12:47:20.922761-07', '{157565}', '{272763,223652,255582,287217,235944,260440,297650,289211,202077,244763,287772}', '{394396,384167,397939,390940,378618,390162,388379,332721,395698,398515,305978,340557,359553,339876,375957,382940,315266,390813,304322,319882,356261,328975,339096,335851,334441,325735,336484,388044,301369,374855,320029,336851,348695,385971}', '{}', '2024-04-09 02:52:20.922762-07', '2024-05-01 00:09:20.922762-07', '2024-03-24 20:22:20.922763-07');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('casimer.kfjqzr41@example.org', '唐怡钰', 'unknown', '2024-01-01 00:41:20.922761-08', '2024-04-06 07:45:20.922761-07', '2024-03-21 12:47:20.922761-07', '{157565}', '{272763,223652,255582,287217,235944,260440,297650,289211,202077,244763,287772}', '{394396,384167,397939,390940,378618,390162,388379,332721,395698,398515,305978,340557,359553,339876,375957,382940,315266,390813,304322,319882,356261,328975,339096,335851,334441,325735,336484,388044,301369,374855,320029,336851,348695,385971}', '{}', '2024-04-09 02:52:20.922762-07', '2024-05-01 00:09:20.922762-07', '2024-03-24 20:22:20.922763-07', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('dewitt.vn4cgq3q@example.org', '黎青松', 'unknown', '2024-01-01 00:41:28.290871-08', '2024-04-24 10:53:28.290871-07', '2024-03-20 01:47:28.290872-07', '{121611,133796,120755,117020,106579,186651,112732,110172,165090,130935,109423,136363,131914,106783,125600,189738,157996,184621}', '{209142,218989,265576,286736,293064,244481,261278,257034,207762,271783,246346,203649,224379,280812,260602,288373,205521,232009,268226,251258,293428,219966,268097,256687,209814,273665,243586,242570,218904,243136,246969,256593,251751,228967,285078,261850,217372,285739,243726,287290,266747,212882,237870}', '{365210,381318,342625,370089,388654,379036,396855,348945,311684,388273,306845}', '{454431,469022,412881,407174,485186,491968,404426,412141,465605,497433,497465,410558,489963,466322,438680,477613,457272,430997,464531,499514,472821,463888,469845}', '2024-04-12 21:20:28.290874-07', '2024-04-22 02:55:28.290874-07', '2024-03-10 18:32:28.290874-07');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('dewitt.vn4cgq3q@example.org', '黎青松', 'unknown', '2024-01-01 00:41:28.290871-08', '2024-04-24 10:53:28.290871-07', '2024-03-20 01:47:28.290872-07', '{121611,133796,120755,117020,106579,186651,112732,110172,165090,130935,109423,136363,131914,106783,125600,189738,157996,184621}', '{209142,218989,265576,286736,293064,244481,261278,257034,207762,271783,246346,203649,224379,280812,260602,288373,205521,232009,268226,251258,293428,219966,268097,256687,209814,273665,243586,242570,218904,243136,246969,256593,251751,228967,285078,261850,217372,285739,243726,287290,266747,212882,237870}', '{365210,381318,342625,370089,388654,379036,396855,348945,311684,388273,306845}', '{454431,469022,412881,407174,485186,491968,404426,412141,465605,497433,497465,410558,489963,466322,438680,477613,457272,430997,464531,499514,472821,463888,469845}', '2024-04-12 21:20:28.290874-07', '2024-04-22 02:55:28.290874-07', '2024-03-10 18:32:28.290874-07', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('ottilie.j2qg8zbc@example.org', '孟杰邦', 'unknown', '2024-01-01 00:42:31.735545-08', '2024-04-28 06:30:31.735545-07', '2024-03-11 23:08:31.735546-07', '{152130,173037,141372,156278,188256,105267,191433,115217,103537,121302,148549,171003,127676,135198,148176,175129,141386,196901,120439,153571,103697,129055}', '{261619,260240,224817,287940,292868,238993,278713,272994,239664,251343,233884,212356,297810,216333}', '{385975,368628,326163,377452,335215,330566,372940,359269,366948,343200,386733}', '{453062,464459}', '2024-04-21 19:20:31.735547-07', '2024-04-24 23:18:31.735547-07', '2024-04-03 14:27:31.735547-07');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('ottilie.j2qg8zbc@example.org', '孟杰邦', 'unknown', '2024-01-01 00:42:31.735545-08', '2024-04-28 06:30:31.735545-07', '2024-03-11 23:08:31.735546-07', '{152130,173037,141372,156278,188256,105267,191433,115217,103537,121302,148549,171003,127676,135198,148176,175129,141386,196901,120439,153571,103697,129055}', '{261619,260240,224817,287940,292868,238993,278713,272994,239664,251343,233884,212356,297810,216333}', '{385975,368628,326163,377452,335215,330566,372940,359269,366948,343200,386733}', '{453062,464459}', '2024-04-21 19:20:31.735547-07', '2024-04-24 23:18:31.735547-07', '2024-04-03 14:27:31.735547-07', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('camren.yqtb810v@example.org', '钟晋枝', 'unknown', '2024-01-01 00:42:42.015696-08', '2024-04-15 18:40:42.015697-07', '2024-04-06 23:03:42.015697-07', '{110721,181673,131160,149053,165477,123261,137369,167396,163720,167070,170757,140768}', '{280957,294770,214964,256665,222739,226486,220028,207891,244984,283932,223536,243069,201901,269077,251244,232573,248809,203929,290465,262665,235373,292574,249295,251287,257496,237517,278896}', '{371829,360747,353069,347761,371647,381660,343649,364355,376184,337847,380447,319889,316300,385833,375186,313453,321406,318162,352866,335759,351334,312209,387994,387564}', '{460024,439491,498383,445487,456324,488087,428463,480289,436927,434957,497001,444715,454798,433491,449138,457848,477178,474386,453442,417334,402374,478691,473591,408118,423117,434392,488332,485116,419586,453842,436474,471349,435837,462939,457342,443530,495813,440470,472958,459279,459992,408155,424403,427863,466088,423639,456378,476013}', '2024-04-07 12:01:42.0157-07', '2024-04-25 17:23:42.0157-07', '2024-02-27 08:23:42.0157-08');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('camren.yqtb810v@example.org', '钟晋枝', 'unknown', '2024-01-01 00:42:42.015696-08', '2024-04-15 18:40:42.015697-07', '2024-04-06 23:03:42.015697-07', '{110721,181673,131160,149053,165477,123261,137369,167396,163720,167070,170757,140768}', '{280957,294770,214964,256665,222739,226486,220028,207891,244984,283932,223536,243069,201901,269077,251244,232573,248809,203929,290465,262665,235373,292574,249295,251287,257496,237517,278896}', '{371829,360747,353069,347761,371647,381660,343649,364355,376184,337847,380447,319889,316300,385833,375186,313453,321406,318162,352866,335759,351334,312209,387994,387564}', '{460024,439491,498383,445487,456324,488087,428463,480289,436927,434957,497001,444715,454798,433491,449138,457848,477178,474386,453442,417334,402374,478691,473591,408118,423117,434392,488332,485116,419586,453842,436474,471349,435837,462939,457342,443530,495813,440470,472958,459279,459992,408155,424403,427863,466088,423639,456378,476013}', '2024-04-07 12:01:42.0157-07', '2024-04-25 17:23:42.0157-07', '2024-02-27 08:23:42.0157-08', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('ashly.ye7fdcda@example.com', '覃秋荷', 'unknown', '2024-01-01 00:43:06.36097-08', '2024-04-12 22:07:06.36097-07', '2024-02-23 02:07:06.36097-08', '{112576,133264,100083,121509,192787,119767,174215,103611,112608,165065,192608,156386,168885,116703,119318,154814,144552,193599,101568,135736,193718,160298,148021,128737,169204}', '{219356,213925,218670,286939,223990,245465,231352,246858,293043,234375,258968,276880,270877,204880,287606,201922}', '{362024,319511,360856,363279,358255,314513,373443,376665,318244,355784}', '{480734,492838,453520,498971,417851,451186,427792,402250,467120,409035,410794,447256,458931,494294}', '2024-04-20 07:41:06.360972-07', '2024-04-23 12:55:06.360972-07', '2024-03-13 00:31:06.360972-07');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('ashly.ye7fdcda@example.com', '覃秋荷', 'unknown', '2024-01-01 00:43:06.36097-08', '2024-04-12 22:07:06.36097-07', '2024-02-23 02:07:06.36097-08', '{112576,133264,100083,121509,192787,119767,174215,103611,112608,165065,192608,156386,168885,116703,119318,154814,144552,193599,101568,135736,193718,160298,148021,128737,169204}', '{219356,213925,218670,286939,223990,245465,231352,246858,293043,234375,258968,276880,270877,204880,287606,201922}', '{362024,319511,360856,363279,358255,314513,373443,376665,318244,355784}', '{480734,492838,453520,498971,417851,451186,427792,402250,467120,409035,410794,447256,458931,494294}', '2024-04-20 07:41:06.360972-07', '2024-04-23 12:55:06.360972-07', '2024-03-13 00:31:06.360972-07', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('franz.xnn8cprz@example.net', '欧阳寻春', 'unknown', '2024-01-01 00:43:15.90454-08', '2024-04-11 15:29:15.90454-07', '2024-04-29 06:22:15.904541-07', '{190408,118290,168467,114123,166018,167211,141676,102030,163190,196105,111726,105238,170046,161326,121773,169071,146244,120972}', '{278577,297342,233396,253479,217970,280936,271919,221043,245443,246114,246745,253572,226474,216404,290955,215026,216138,210702,236777,230022,255045,227348,266050,219120,291397}', '{315688,363093,308203,398994,346085,301191,370038,341420,328233,380535,396287,373088,363936,375910,376077,337869,354279}', '{422164,499471,469705,420223,484063,410912,481466,499866,461418,464462,409450,462827,490538,421163,487493,406592,460648,432471,421951,452720,405147,460795,440705,436769,410990,457011,476220,430192,463804,420338,428136,463307,420569,481512,405558,432437,470208,442730,483184,410235}', '2024-04-10 00:49:15.904542-07', '2024-05-05 10:54:15.904543-07', '2024-04-02 15:22:15.904543-07');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('franz.xnn8cprz@example.net', '欧阳寻春', 'unknown', '2024-01-01 00:43:15.90454-08', '2024-04-11 15:29:15.90454-07', '2024-04-29 06:22:15.904541-07', '{190408,118290,168467,114123,166018,167211,141676,102030,163190,196105,111726,105238,170046,161326,121773,169071,146244,120972}', '{278577,297342,233396,253479,217970,280936,271919,221043,245443,246114,246745,253572,226474,216404,290955,215026,216138,210702,236777,230022,255045,227348,266050,219120,291397}', '{315688,363093,308203,398994,346085,301191,370038,341420,328233,380535,396287,373088,363936,375910,376077,337869,354279}', '{422164,499471,469705,420223,484063,410912,481466,499866,461418,464462,409450,462827,490538,421163,487493,406592,460648,432471,421951,452720,405147,460795,440705,436769,410990,457011,476220,430192,463804,420338,428136,463307,420569,481512,405558,432437,470208,442730,483184,410235}', '2024-04-10 00:49:15.904542-07', '2024-05-05 10:54:15.904543-07', '2024-04-02 15:22:15.904543-07', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('haylee.kl8r6gq5@example.net', '贺瀚景', 'unknown', '2024-01-01 00:43:22.623507-08', '2024-04-17 03:12:22.623507-07', '2024-04-14 01:13:22.623507-07', '{157650,165979,142896,160346,163605,138371,149222,137087,109806,196486,193189,170697,116898,113580,105842,109799,197434,140024,134838,109321,185528,176016,108911,123229,129028,172198,183643}', '{268673,268756,295224,221824,277684,251885,275286,224702,254200,296844,211550,208614,264031,220993,275618,232241,239758,254964,276305,209332,256786,291240,260659,271765,236171,281116,289935,288911,215712,221847,215436,293934,264943}', '{352202,300632,332539,373214,320615,301067,393479,363661,306612,379737,391924,370002,384996,351767,309393,353619,384419,392810,391409,310758,357326,352444,300624,300793,389418,355542,380484,376944,302081,395993,345087,372912,382968,360953,337326,389192,361060,364790,391796,353496,397768,399433,372794,356981,358409}', '{409443,424093,428118,431393,416494,422240,425316,447373,478662,497225,487796,486063,401574,460792,438984,436227,434501,487290,437471,419048,480409,413964,472836,499440,486040,470643,420878,423967,412925,447825,463093,458149,479958,429712,443802,472859,480906,460486,408367}', '2024-04-20 06:05:22.62351-07', '2024-05-01 10:55:22.62351-07', '2024-02-13 00:40:22.623511-08');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('haylee.kl8r6gq5@example.net', '贺瀚景', 'unknown', '2024-01-01 00:43:22.623507-08', '2024-04-17 03:12:22.623507-07', '2024-04-14 01:13:22.623507-07', '{157650,165979,142896,160346,163605,138371,149222,137087,109806,196486,193189,170697,116898,113580,105842,109799,197434,140024,134838,109321,185528,176016,108911,123229,129028,172198,183643}', '{268673,268756,295224,221824,277684,251885,275286,224702,254200,296844,211550,208614,264031,220993,275618,232241,239758,254964,276305,209332,256786,291240,260659,271765,236171,281116,289935,288911,215712,221847,215436,293934,264943}', '{352202,300632,332539,373214,320615,301067,393479,363661,306612,379737,391924,370002,384996,351767,309393,353619,384419,392810,391409,310758,357326,352444,300624,300793,389418,355542,380484,376944,302081,395993,345087,372912,382968,360953,337326,389192,361060,364790,391796,353496,397768,399433,372794,356981,358409}', '{409443,424093,428118,431393,416494,422240,425316,447373,478662,497225,487796,486063,401574,460792,438984,436227,434501,487290,437471,419048,480409,413964,472836,499440,486040,470643,420878,423967,412925,447825,463093,458149,479958,429712,443802,472859,480906,460486,408367}', '2024-04-20 06:05:22.62351-07', '2024-05-01 10:55:22.62351-07', '2024-02-13 00:40:22.623511-08', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('zechariah.9yne3okx@example.net', '汤冰烟', 'unknown', '2024-01-01 00:44:06.702917-08', '2024-04-21 08:43:06.702917-07', '2024-04-10 19:08:06.702918-07', '{154462,122431,153858,109638,184929,180603,150049,127470,178158,162559,189355,195507,155914,165115,135162,158666,182762,107728,152763,199899,194958,104274,161792,150787,151035,139569,177910,105634,143677,107873,108999,123732,181366,145079,117483}', '{286020,274391,225087,243111,229371,272112,214341,209147,288405,288733,266973,234119,204661,233932,206153,217126,255502,233997,287924,212347,205277,253931,251792,262431,260592,296557,246941}', '{381113,360472,392525,316202,351088,386846,393488,320302,318434,328742,359640,391083,383297,334550,391066,308311,305845,391009,302984,331625,397114,347907,319380,343368,349245,373647,329012,381016}', '{477723,406071}', '2024-04-21 14:14:06.702921-07', '2024-04-28 08:12:06.702921-07', '2024-03-01 20:27:06.702922-08');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('zechariah.9yne3okx@example.net', '汤冰烟', 'unknown', '2024-01-01 00:44:06.702917-08', '2024-04-21 08:43:06.702917-07', '2024-04-10 19:08:06.702918-07', '{154462,122431,153858,109638,184929,180603,150049,127470,178158,162559,189355,195507,155914,165115,135162,158666,182762,107728,152763,199899,194958,104274,161792,150787,151035,139569,177910,105634,143677,107873,108999,123732,181366,145079,117483}', '{286020,274391,225087,243111,229371,272112,214341,209147,288405,288733,266973,234119,204661,233932,206153,217126,255502,233997,287924,212347,205277,253931,251792,262431,260592,296557,246941}', '{381113,360472,392525,316202,351088,386846,393488,320302,318434,328742,359640,391083,383297,334550,391066,308311,305845,391009,302984,331625,397114,347907,319380,343368,349245,373647,329012,381016}', '{477723,406071}', '2024-04-21 14:14:06.702921-07', '2024-04-28 08:12:06.702921-07', '2024-03-01 20:27:06.702922-08', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('jayda.68s33bfr@example.com', '金宇驰', 'unknown', '2024-01-01 00:44:23.212377-08', '2024-04-24 14:11:23.212377-07', '2024-02-15 17:45:23.212378-08', '{152961,115659,161809,162654,185460,156858,109185,152528,120504,118983,108190,112128,133347,155634,117169,168543,141444,114601,180584,155607,199605,161482,197734,198469,162258,187212,157706,177426,182991,136274}', '{201287,222782,229683,237493,244060,202691,287369,270143,240827,233574,287910,252326,228494,285673,218770,251388,297538,272977,227516}', '{346339,395112,309785,351344,389558,371139,351635,315642,344150,386613,371846,344053,327111,327140,390953,317584,322961,399422,348485,303440,331767,391899,304137,326948,344766,344280,370770,335651,315182,363133,322071,322139}', '{460258,427088,482861,474599,434326,443023,492359,456268,484316,408442,482843,448738,434920,481707,475389,429633,473415,417423,450145,473618,455582,449051,466915,447654,429448,471778,408034,464163,496505,422606,454166,443916,458838,473881,443108,490212,401352,413633,448243,417682,488746,487885,447646,455224}', '2024-04-29 13:05:23.21238-07', '2024-04-25 10:54:23.21238-07', '2024-05-03 20:21:23.21238-07');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('jayda.68s33bfr@example.com', '金宇驰', 'unknown', '2024-01-01 00:44:23.212377-08', '2024-04-24 14:11:23.212377-07', '2024-02-15 17:45:23.212378-08', '{152961,115659,161809,162654,185460,156858,109185,152528,120504,118983,108190,112128,133347,155634,117169,168543,141444,114601,180584,155607,199605,161482,197734,198469,162258,187212,157706,177426,182991,136274}', '{201287,222782,229683,237493,244060,202691,287369,270143,240827,233574,287910,252326,228494,285673,218770,251388,297538,272977,227516}', '{346339,395112,309785,351344,389558,371139,351635,315642,344150,386613,371846,344053,327111,327140,390953,317584,322961,399422,348485,303440,331767,391899,304137,326948,344766,344280,370770,335651,315182,363133,322071,322139}', '{460258,427088,482861,474599,434326,443023,492359,456268,484316,408442,482843,448738,434920,481707,475389,429633,473415,417423,450145,473618,455582,449051,466915,447654,429448,471778,408034,464163,496505,422606,454166,443916,458838,473881,443108,490212,401352,413633,448243,417682,488746,487885,447646,455224}', '2024-04-29 13:05:23.21238-07', '2024-04-25 10:54:23.21238-07', '2024-05-03 20:21:23.21238-07', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('vinnie.8poefns3@example.org', '潘雅彤', 'unknown', '2024-01-01 00:44:31.556936-08', '2024-04-09 11:31:31.556937-07', '2024-04-09 19:48:31.556937-07', '{188244,113885,172302,135244,120548,127554,114360,119025,196171,190701,138919,170253,111812,107593,163366,100661,118540,103478,175632,186654,131454,102344,170578,117478,159434,108709,143335,191135,198530,111636,168169,185871,105374,131682,176226,180460,102262,112299,199207,176520,114127,179299,177607,144056,116343,130961}', '{247767,261533,299885,260227,251753,210664,255701,250954,275805,262232,224633,218604,235369,235370,276970,213176,297918,280882,209707,249382,231715,252314,291761,288056,255806,278382,297482,213054,240859,227122,209930,266127,291577,214757}', '{300991,317517,339664,312225,308179,328159,336937,376647,300194}', '{401593,435106,427200,435883,428072,478919,469779,423199,481073,442123,460613,478605,470327,442109,441145,455888,479035,497788,433941,417326,422503,493318,438066,439065,430008,458415,454774,451196,452020,495383,450693,479778,473088,473212,454512}', '2024-05-04 05:57:31.55694-07', '2024-04-24 16:08:31.55694-07', '2024-03-10 09:59:31.55694-07');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('vinnie.8poefns3@example.org', '潘雅彤', 'unknown', '2024-01-01 00:44:31.556936-08', '2024-04-09 11:31:31.556937-07', '2024-04-09 19:48:31.556937-07', '{188244,113885,172302,135244,120548,127554,114360,119025,196171,190701,138919,170253,111812,107593,163366,100661,118540,103478,175632,186654,131454,102344,170578,117478,159434,108709,143335,191135,198530,111636,168169,185871,105374,131682,176226,180460,102262,112299,199207,176520,114127,179299,177607,144056,116343,130961}', '{247767,261533,299885,260227,251753,210664,255701,250954,275805,262232,224633,218604,235369,235370,276970,213176,297918,280882,209707,249382,231715,252314,291761,288056,255806,278382,297482,213054,240859,227122,209930,266127,291577,214757}', '{300991,317517,339664,312225,308179,328159,336937,376647,300194}', '{401593,435106,427200,435883,428072,478919,469779,423199,481073,442123,460613,478605,470327,442109,441145,455888,479035,497788,433941,417326,422503,493318,438066,439065,430008,458415,454774,451196,452020,495383,450693,479778,473088,473212,454512}', '2024-05-04 05:57:31.55694-07', '2024-04-24 16:08:31.55694-07', '2024-03-10 09:59:31.55694-07', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('gunnar.08wntk6g@example.com', '廖水莹', 'unknown', '2024-01-01 00:45:12.654027-08', '2024-04-30 08:17:12.654028-07', '2024-02-10 09:21:12.654028-08', '{195969,177318,189809,129095,170551,105349}', '{249263}', '{348520,394755,306167,355708,322088,323592,303017,376352,305468,306665,335633,342645,385458,393249,389935,338730,335819,375000,371169,379385,383458,331288}', '{497181,475222,416432,441393,457304,473716,448667,467128,456174}', '2024-04-30 03:49:12.654029-07', '2024-05-04 23:33:12.654029-07', '2024-03-17 17:23:12.654029-07');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('gunnar.08wntk6g@example.com', '廖水莹', 'unknown', '2024-01-01 00:45:12.654027-08', '2024-04-30 08:17:12.654028-07', '2024-02-10 09:21:12.654028-08', '{195969,177318,189809,129095,170551,105349}', '{249263}', '{348520,394755,306167,355708,322088,323592,303017,376352,305468,306665,335633,342645,385458,393249,389935,338730,335819,375000,371169,379385,383458,331288}', '{497181,475222,416432,441393,457304,473716,448667,467128,456174}', '2024-04-30 03:49:12.654029-07', '2024-05-04 23:33:12.654029-07', '2024-03-17 17:23:12.654029-07', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('sheila.acifhin6@example.com', '郑蕾碧', 'unknown', '2024-01-01 00:45:17.255012-08', '2024-04-08 03:45:17.255012-07', '2024-04-09 20:21:17.255012-07', '{124703}', '{257789,293450,201058,296481,248267,250853,281256,250283,220827,263577,205786,274721,264331,294734,239371,278318,264815,218529,216098,203640,295658,272839,272680,284390,270926,255241,213915,273010,241437,273926,204351,259851,229545,220382,276427,241402,243837,284451,258682,271948,287648,297410,221547,295140,249735,208324,259478}', '{318931}', '{413880,410588,493151,484269,488116,402978,419213,437930,409237,421400,407442,476760,441354,470516,482836,441931,485195,431545,447664,463113,479525,414838,481370,445302,481043,450069,407724,491080,482011,457544,437538,425163,436036,423612,440131,404766,428180,401906,442502,452062,466842,445064,413321,495566,465865}', '2024-03-30 16:54:17.255014-07', '2024-04-21 04:30:17.255014-07', '2024-04-09 00:54:17.255014-07');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('sheila.acifhin6@example.com', '郑蕾碧', 'unknown', '2024-01-01 00:45:17.255012-08', '2024-04-08 03:45:17.255012-07', '2024-04-09 20:21:17.255012-07', '{124703}', '{257789,293450,201058,296481,248267,250853,281256,250283,220827,263577,205786,274721,264331,294734,239371,278318,264815,218529,216098,203640,295658,272839,272680,284390,270926,255241,213915,273010,241437,273926,204351,259851,229545,220382,276427,241402,243837,284451,258682,271948,287648,297410,221547,295140,249735,208324,259478}', '{318931}', '{413880,410588,493151,484269,488116,402978,419213,437930,409237,421400,407442,476760,441354,470516,482836,441931,485195,431545,447664,463113,479525,414838,481370,445302,481043,450069,407724,491080,482011,457544,437538,425163,436036,423612,440131,404766,428180,401906,442502,452062,466842,445064,413321,495566,465865}', '2024-03-30 16:54:17.255014-07', '2024-04-21 04:30:17.255014-07', '2024-04-09 00:54:17.255014-07', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('velva.pvi3frmv@example.net', '侯远旭', 'unknown', '2024-01-01 00:45:58.869409-08', '2024-04-25 23:57:58.869409-07', '2024-03-27 00:45:58.869409-07', '{108437,170568,191889}', '{204885,297869,209546,221456,283274,235229,210202,217431,237970,279304,264313,258444,273226,204844,202371,240378,210037,239515,242051,280149,278173,206958,279505,244402,259946,288720,252569,246662,229665,252228}', '{332696,395107,356481,353151,304682,384310,377781,399483,358726,311348,333370,316621,342491,366148,331261,341777,366497,302522,358006,324628,367702,383954,320283,341734,367121,368834,335623,385572,350252,391109,347098,352131,311569,386462,351246,340252,363441,362498,327914,338380,371114,355583,390592}', '{448191,444935,423273,434489,401740,490190,480127,485607,422248,494456,447762,486956,468264,449549,453536,481538,466945,445443,486883,478035,415751,409663,430971,404952,475363,479460,470170,493183}', '2024-03-27 05:13:58.869411-07', '2024-04-25 11:44:58.869411-07', '2024-04-20 16:34:58.869411-07');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('velva.pvi3frmv@example.net', '侯远旭', 'unknown', '2024-01-01 00:45:58.869409-08', '2024-04-25 23:57:58.869409-07', '2024-03-27 00:45:58.869409-07', '{108437,170568,191889}', '{204885,297869,209546,221456,283274,235229,210202,217431,237970,279304,264313,258444,273226,204844,202371,240378,210037,239515,242051,280149,278173,206958,279505,244402,259946,288720,252569,246662,229665,252228}', '{332696,395107,356481,353151,304682,384310,377781,399483,358726,311348,333370,316621,342491,366148,331261,341777,366497,302522,358006,324628,367702,383954,320283,341734,367121,368834,335623,385572,350252,391109,347098,352131,311569,386462,351246,340252,363441,362498,327914,338380,371114,355583,390592}', '{448191,444935,423273,434489,401740,490190,480127,485607,422248,494456,447762,486956,468264,449549,453536,481538,466945,445443,486883,478035,415751,409663,430971,404952,475363,479460,470170,493183}', '2024-03-27 05:13:58.869411-07', '2024-04-25 11:44:58.869411-07', '2024-04-20 16:34:58.869411-07', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('rosetta.x9ucka9i@example.com', '侯文辉', 'unknown', '2024-01-10 23:12:02.151342-08', '2024-04-23 00:17:02.151342-07', '2024-02-07 22:53:02.151342-08', '{156452,129440,199860,147198,147281,168932,186697,116710,184318,116958,139429,175419,174528,140278,135047,125152,190074,107653,137558,143027,194377,127360,176585,132696,123027,136769,165804,148718,140536}', '{249406,200444,281441,244324,243082,224812,276126,288475,251865,245887,296875,252902,295902,256790,276767,202749,252790,208283,296976,285253,295971,288624,268043}', '{390492,389007,378569,301381,370858,359034,388589,326105,343300,332533,312631,306315,390777,374888,393929,333411,332374,390164}', '{493849,420916,414432,458946,488091,486550,461010,420560,419922,462166,477847,453344,445610,400294,436623,437252,458051,475262,461182,486737,414350,473404,474164,457063,427195,473018,485974,491069,435388,498730,495980,476612,428539,430028,480821,471058,432437,459648,488552,466469,435676,443239,480535,427560,492472}', '2024-04-02 12:31:02.151345-07', '2024-04-30 16:27:02.151345-07', '2024-04-17 17:11:02.151345-07');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('rosetta.x9ucka9i@example.com', '侯文辉', 'unknown', '2024-01-10 23:12:02.151342-08', '2024-04-23 00:17:02.151342-07', '2024-02-07 22:53:02.151342-08', '{156452,129440,199860,147198,147281,168932,186697,116710,184318,116958,139429,175419,174528,140278,135047,125152,190074,107653,137558,143027,194377,127360,176585,132696,123027,136769,165804,148718,140536}', '{249406,200444,281441,244324,243082,224812,276126,288475,251865,245887,296875,252902,295902,256790,276767,202749,252790,208283,296976,285253,295971,288624,268043}', '{390492,389007,378569,301381,370858,359034,388589,326105,343300,332533,312631,306315,390777,374888,393929,333411,332374,390164}', '{493849,420916,414432,458946,488091,486550,461010,420560,419922,462166,477847,453344,445610,400294,436623,437252,458051,475262,461182,486737,414350,473404,474164,457063,427195,473018,485974,491069,435388,498730,495980,476612,428539,430028,480821,471058,432437,459648,488552,466469,435676,443239,480535,427560,492472}', '2024-04-02 12:31:02.151345-07', '2024-04-30 16:27:02.151345-07', '2024-04-17 17:11:02.151345-07', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('brock.f2n9f9c9@example.net', '史彬锐', 'unknown', '2024-02-04 05:23:03.21402-08', '2024-04-13 02:07:03.21402-07', '2024-04-14 07:02:03.214021-07', '{187770,127510,167225,137672,149145,143934,181365,199446,128830,103502,166417,112133,122336,179449,148837,131297,126388}', '{207348,294454,252650,269190,292938,252790,263436,264142,223253,271386,291594,247936,208279,243862,275894,242968}', '{357306,379942,386156,360377,345744,349918,367113,396916,386707,349355,324747,316899,333057,361345,388248,389954,398859,336690,359603,357413,378818,306606,308296,312277,365292,341858,332241,389594,381354,377579,301331,393852,386392,306356,352887,328679,393018,303209,338401,341157,355848,386627,356231,327040,306624,318649}', '{491762,454669,436601,415953,491356,425668,414534,439632,455655,439508,495322,465367,487081,485574,405342,423708,474104,466520,444956,465016,478868,497900,402558,484951,450085,419654,472351,446538,425051,424398,402073,409213,460716,496163}', '2024-03-22 07:55:03.214024-07', '2024-05-03 22:11:03.214024-07', '2024-04-20 19:39:03.214024-07');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('brock.f2n9f9c9@example.net', '史彬锐', 'unknown', '2024-02-04 05:23:03.21402-08', '2024-04-13 02:07:03.21402-07', '2024-04-14 07:02:03.214021-07', '{187770,127510,167225,137672,149145,143934,181365,199446,128830,103502,166417,112133,122336,179449,148837,131297,126388}', '{207348,294454,252650,269190,292938,252790,263436,264142,223253,271386,291594,247936,208279,243862,275894,242968}', '{357306,379942,386156,360377,345744,349918,367113,396916,386707,349355,324747,316899,333057,361345,388248,389954,398859,336690,359603,357413,378818,306606,308296,312277,365292,341858,332241,389594,381354,377579,301331,393852,386392,306356,352887,328679,393018,303209,338401,341157,355848,386627,356231,327040,306624,318649}', '{491762,454669,436601,415953,491356,425668,414534,439632,455655,439508,495322,465367,487081,485574,405342,423708,474104,466520,444956,465016,478868,497900,402558,484951,450085,419654,472351,446538,425051,424398,402073,409213,460716,496163}', '2024-03-22 07:55:03.214024-07', '2024-05-03 22:11:03.214024-07', '2024-04-20 19:39:03.214024-07', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('terry.xnmtmdsg@example.org', '孔伦博', 'unknown', '2024-01-23 21:42:14.19398-08', '2024-04-26 01:25:14.19398-07', '2024-03-23 12:40:14.19398-07', '{111338,193566,128074,110673,161467,189314,122121,125942,132396,142789,106326,130536,102600,113470,197129,172447,175427,190202,164345,105053,136557,188477,165657,186707,108648,186033,192378,134379,114608,175319,133347,180362,198946,134711,167559}', '{243034,287352,237596,238866,262378,273552,246058,231692,242921,202864,229296,238809,263510,217116,283413,245225,266768,258274,277354,200110,240452,267314,202591,265507,245769,221295,216400,252790,229440,229629,267070,276704,237205,263358,270509,271308,253378,298120,262760,236289,209864}', '{384571,383879,382320,385934,324451,348694,354392,301531,376875,318932,368341,321444,309744,397202}', '{456171,456999,468364,436823,492351,430010,412174,421197,423549,475536,492897,410867,470519,410792,419829,407117,416571,485669,423385,479930,495590,428481,499857,419020,497552,438657,494647,480594,457922,469445,472574,428448,468009,483619}', '2024-04-01 16:07:14.193984-07', '2024-05-03 12:56:14.193984-07', '2024-04-17 08:38:14.193984-07');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('terry.xnmtmdsg@example.org', '孔伦博', 'unknown', '2024-01-23 21:42:14.19398-08', '2024-04-26 01:25:14.19398-07', '2024-03-23 12:40:14.19398-07', '{111338,193566,128074,110673,161467,189314,122121,125942,132396,142789,106326,130536,102600,113470,197129,172447,175427,190202,164345,105053,136557,188477,165657,186707,108648,186033,192378,134379,114608,175319,133347,180362,198946,134711,167559}', '{243034,287352,237596,238866,262378,273552,246058,231692,242921,202864,229296,238809,263510,217116,283413,245225,266768,258274,277354,200110,240452,267314,202591,265507,245769,221295,216400,252790,229440,229629,267070,276704,237205,263358,270509,271308,253378,298120,262760,236289,209864}', '{384571,383879,382320,385934,324451,348694,354392,301531,376875,318932,368341,321444,309744,397202}', '{456171,456999,468364,436823,492351,430010,412174,421197,423549,475536,492897,410867,470519,410792,419829,407117,416571,485669,423385,479930,495590,428481,499857,419020,497552,438657,494647,480594,457922,469445,472574,428448,468009,483619}', '2024-04-01 16:07:14.193984-07', '2024-05-03 12:56:14.193984-07', '2024-04-17 08:38:14.193984-07', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('una.rj63xhog@example.net', '雷幻莲', 'unknown', '2024-01-15 23:57:21.417506-08', '2024-04-22 15:37:21.417506-07', '2024-04-16 14:56:21.417506-07', '{150401,124313,160226,175247,188688,114568,148259,164281,184058,134288,195562,122589,123650,112591,124087,142781,178114,137806,121980,152846,134743,113811,145445,147074,143990,160903,116549,115410,150585,161889,146362,156238,115717,187436,176954,140919,188962,164005,191192,188837,192170,100608}', '{241954,252561,255034,295654,255394,235875,201530,232262,256372,262654,282331,203763,276474,234862,203636,223954,278244,235847,227228,258793,253451,228785,276227,282885,234324,269496,252790,206499,238804,203255,242076,275857,233486,276900,251471,250368,272859,254247,285422,204266,220382,287293,230295,216749,289361,205966,295535}', '{352046,391497,362592,358959,372076,399963,384746,392961,370531,395622,333710,389774,331284,308489,379182,318800,318588,320723,398879,335725,367773,354832,335298,335018,334705,394425,335119,311352,376863,380917,379862,304357}', '{487604,468887,464639,453855,484677,427825,491316,459663,478332,494168,461852,460477,472246,486565,400803,475013,411543,403563,467333,417880,490389,497603,492588,480948,498419,414478,449180,487067,485743,469597,406934,438524,406138,494476,454871,451110,423455,480092,471856,476672,481947,431862,472765,412149,498333}', '2024-04-01 05:35:21.417509-07', '2024-04-21 06:10:21.41751-07', '2024-04-02 19:11:21.41751-07');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('una.rj63xhog@example.net', '雷幻莲', 'unknown', '2024-01-15 23:57:21.417506-08', '2024-04-22 15:37:21.417506-07', '2024-04-16 14:56:21.417506-07', '{150401,124313,160226,175247,188688,114568,148259,164281,184058,134288,195562,122589,123650,112591,124087,142781,178114,137806,121980,152846,134743,113811,145445,147074,143990,160903,116549,115410,150585,161889,146362,156238,115717,187436,176954,140919,188962,164005,191192,188837,192170,100608}', '{241954,252561,255034,295654,255394,235875,201530,232262,256372,262654,282331,203763,276474,234862,203636,223954,278244,235847,227228,258793,253451,228785,276227,282885,234324,269496,252790,206499,238804,203255,242076,275857,233486,276900,251471,250368,272859,254247,285422,204266,220382,287293,230295,216749,289361,205966,295535}', '{352046,391497,362592,358959,372076,399963,384746,392961,370531,395622,333710,389774,331284,308489,379182,318800,318588,320723,398879,335725,367773,354832,335298,335018,334705,394425,335119,311352,376863,380917,379862,304357}', '{487604,468887,464639,453855,484677,427825,491316,459663,478332,494168,461852,460477,472246,486565,400803,475013,411543,403563,467333,417880,490389,497603,492588,480948,498419,414478,449180,487067,485743,469597,406934,438524,406138,494476,454871,451110,423455,480092,471856,476672,481947,431862,472765,412149,498333}', '2024-04-01 05:35:21.417509-07', '2024-04-21 06:10:21.41751-07', '2024-04-02 19:11:21.41751-07', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('martin.5y6zd810@example.net', '杜紫超', 'unknown', '2024-01-23 04:10:24.497488-08', '2024-04-27 01:39:24.497488-07', '2024-02-07 16:23:24.497488-08', '{106461,194111,104399,180444,157326,165734,118238,181961,159555,145883,149113,176115,153152,105509,135139,115074,184175,119243,137721,176995,128270,156099,168364,137724,180661,105986,101934}', '{265857,234980,236595,252790,292861,204865,293809,270802,238623,275743,205029,234739,243760,237145,295361,222908,251702,252556,217283,275470,285024,273140,201950,261125,208772,253613,237292,259083,213796,208714,249443,207842,288142,252066}', '{371906,386419,392377,306531,366578,399940}', '{493591,488208,482221,482397,483715,449127,464676,428733,422841,458981,437149,442971,415367,475989,476895,444927,419832,446146,409107,414371,488733,425346,497407,430236,479664,466035,461568,432346,401606,471635,466499,411986}', '2024-04-05 18:28:24.49749-07', '2024-05-03 23:51:24.49749-07', '2024-03-03 03:07:24.497491-08');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('martin.5y6zd810@example.net', '杜紫超', 'unknown', '2024-01-23 04:10:24.497488-08', '2024-04-27 01:39:24.497488-07', '2024-02-07 16:23:24.497488-08', '{106461,194111,104399,180444,157326,165734,118238,181961,159555,145883,149113,176115,153152,105509,135139,115074,184175,119243,137721,176995,128270,156099,168364,137724,180661,105986,101934}', '{265857,234980,236595,252790,292861,204865,293809,270802,238623,275743,205029,234739,243760,237145,295361,222908,251702,252556,217283,275470,285024,273140,201950,261125,208772,253613,237292,259083,213796,208714,249443,207842,288142,252066}', '{371906,386419,392377,306531,366578,399940}', '{493591,488208,482221,482397,483715,449127,464676,428733,422841,458981,437149,442971,415367,475989,476895,444927,419832,446146,409107,414371,488733,425346,497407,430236,479664,466035,461568,432346,401606,471635,466499,411986}', '2024-04-05 18:28:24.49749-07', '2024-05-03 23:51:24.49749-07', '2024-03-03 03:07:24.497491-08', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('baylee.ihpa0355@example.com', '潘修永', 'unknown', '2024-01-17 11:42:59.374555-08', '2024-04-30 11:29:59.374556-07', '2024-03-03 16:37:59.374556-08', '{197465,124093,147951,183378,166057,189672,128338}', '{220793,252994,296687,272033,246481,263094,242053,286792,218631,240906,219397,236798,281284,252790,269291,223721,253435,211985,287624,219489}', '{364354,346540,378464,344488,384605,371633,300588,320751,345774,342358,380391,303115,392833,381640,375332,375345,340074,349963,338470,364894,368869,327020,371295,309077,320374,397781,389388,356654,333438,379484,303797,386522,310061,301481,332015,397917,337613}', '{467213,446693,496107,423534,459830,472738,485234,478595,476070,404456,435760,448651,405790,431681,466723,464558,435564,401004,474876,481355,457669,457436,406362,495744,431104,455417,411692,491470,443538,403923,474160,429299,471415,446426,469614,463537,450105,436754,446993,428932,489611,452416,411466,490700,400294,451168}', '2024-05-04 01:30:59.374558-07', '2024-04-20 16:31:59.374558-07', '2024-04-19 21:26:59.374558-07');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('baylee.ihpa0355@example.com', '潘修永', 'unknown', '2024-01-17 11:42:59.374555-08', '2024-04-30 11:29:59.374556-07', '2024-03-03 16:37:59.374556-08', '{197465,124093,147951,183378,166057,189672,128338}', '{220793,252994,296687,272033,246481,263094,242053,286792,218631,240906,219397,236798,281284,252790,269291,223721,253435,211985,287624,219489}', '{364354,346540,378464,344488,384605,371633,300588,320751,345774,342358,380391,303115,392833,381640,375332,375345,340074,349963,338470,364894,368869,327020,371295,309077,320374,397781,389388,356654,333438,379484,303797,386522,310061,301481,332015,397917,337613}', '{467213,446693,496107,423534,459830,472738,485234,478595,476070,404456,435760,448651,405790,431681,466723,464558,435564,401004,474876,481355,457669,457436,406362,495744,431104,455417,411692,491470,443538,403923,474160,429299,471415,446426,469614,463537,450105,436754,446993,428932,489611,452416,411466,490700,400294,451168}', '2024-05-04 01:30:59.374558-07', '2024-04-20 16:31:59.374558-07', '2024-04-19 21:26:59.374558-07', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('beverly.73v9obv3@example.org', '韩泽信', 'unknown', '2024-01-20 16:03:01.076105-08', '2024-04-21 00:26:01.076105-07', '2024-04-07 21:12:01.076105-07', '{135785,101693,131222,131824,112924,154369,171817,104368,165831,164940,148397,103718,170364,139735,189502,123955,149543,133712,179934,158625,159781,119692,190264,140859,131979,168294,102365,162915,121424,193355,188259}', '{217300,229930,292506,252790,297088,200520,241439,262092,237140,202659,276188,234088,237112,231049,236097,207846,220621,208406,250025,232387,257981,297539,256905,284139}', '{388218,300168,363728,365666,314438,374415,361604,361889,387298}', '{488734,469165,419368,489442,463920,420012,473491,488597,488759,492574,465041,421323,419116,482114,430898,442444,478526,421342,486763,425299,492750,404275,406594}', '2024-03-29 09:30:01.076107-07', '2024-04-21 20:20:01.076107-07', '2024-05-04 23:28:01.076107-07');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('beverly.73v9obv3@example.org', '韩泽信', 'unknown', '2024-01-20 16:03:01.076105-08', '2024-04-21 00:26:01.076105-07', '2024-04-07 21:12:01.076105-07', '{135785,101693,131222,131824,112924,154369,171817,104368,165831,164940,148397,103718,170364,139735,189502,123955,149543,133712,179934,158625,159781,119692,190264,140859,131979,168294,102365,162915,121424,193355,188259}', '{217300,229930,292506,252790,297088,200520,241439,262092,237140,202659,276188,234088,237112,231049,236097,207846,220621,208406,250025,232387,257981,297539,256905,284139}', '{388218,300168,363728,365666,314438,374415,361604,361889,387298}', '{488734,469165,419368,489442,463920,420012,473491,488597,488759,492574,465041,421323,419116,482114,430898,442444,478526,421342,486763,425299,492750,404275,406594}', '2024-03-29 09:30:01.076107-07', '2024-04-21 20:20:01.076107-07', '2024-05-04 23:28:01.076107-07', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('kameron.qb0ru7bt@example.com', '莫幻紫', 'unknown', '2024-01-10 19:16:29.271569-08', '2024-04-25 09:18:29.271569-07', '2024-04-13 11:56:29.271569-07', '{138677,143948,180411,138606,174758,141773,158457}', '{223768,286262,256153,222054,275535,235090,291226,263223,252790,247469}', '{359793,353448,336906,323661,310884,353134,357899,330671,314337,392748,378236,372754,322117,385692,394321,348486,363209,326774,366284,314080,351143,342972,326842,302509,391747,320926,396268,350584,344063,375984,335392}', '{425288,490411,440283,489270,488279,497686,480512,471479,432328,414807,460173,405424,416490,411806,409402,462598,468717,487439,496037,435881,467398,492058,458515,458413,422153,447392,450477,493610,444790,479698,408885,456754,413974,440240,489477,490187,430227,404374,422225,469483,403642,485186,489968}', '2024-03-24 10:25:29.271572-07', '2024-05-04 05:40:29.271572-07', '2024-02-21 11:43:29.271572-08');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('kameron.qb0ru7bt@example.com', '莫幻紫', 'unknown', '2024-01-10 19:16:29.271569-08', '2024-04-25 09:18:29.271569-07', '2024-04-13 11:56:29.271569-07', '{138677,143948,180411,138606,174758,141773,158457}', '{223768,286262,256153,222054,275535,235090,291226,263223,252790,247469}', '{359793,353448,336906,323661,310884,353134,357899,330671,314337,392748,378236,372754,322117,385692,394321,348486,363209,326774,366284,314080,351143,342972,326842,302509,391747,320926,396268,350584,344063,375984,335392}', '{425288,490411,440283,489270,488279,497686,480512,471479,432328,414807,460173,405424,416490,411806,409402,462598,468717,487439,496037,435881,467398,492058,458515,458413,422153,447392,450477,493610,444790,479698,408885,456754,413974,440240,489477,490187,430227,404374,422225,469483,403642,485186,489968}', '2024-03-24 10:25:29.271572-07', '2024-05-04 05:40:29.271572-07', '2024-02-21 11:43:29.271572-08', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('arvilla.pcxrols9@example.org', '苏宇瀚', 'unknown', '2024-01-30 04:20:54.809104-08', '2024-04-30 10:55:54.809104-07', '2024-04-19 08:54:54.809105-07', '{133486,145229}', '{232312,252790,211701,202483,270560,241217,248961,219949,223950,233305,252190,253564,268110,230732,227614,207695,208252,211056}', '{361837,303539,313992,311982,367090,372563,332824,394962,376029,389297,384324,327859,343569,307325,343046,338901,307756,356107,322505,342647,364988,342566,382026,350802,387984,391320,300316,301046,315385,355228,345530,344770,394447,362150,315964,364158,357353,306183,331820,371282,390066,333743,375653,327772,322587,348748,355155,368869,301736}', '{405595,498870,446926,449405,424290,448941,403646,477361,469242,411437,492096,407068,435374,443287,405944,475637,446269,493161}', '2024-04-15 18:57:54.809106-07', '2024-04-27 12:17:54.809106-07', '2024-03-14 02:26:54.809106-07');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('arvilla.pcxrols9@example.org', '苏宇瀚', 'unknown', '2024-01-30 04:20:54.809104-08', '2024-04-30 10:55:54.809104-07', '2024-04-19 08:54:54.809105-07', '{133486,145229}', '{232312,252790,211701,202483,270560,241217,248961,219949,223950,233305,252190,253564,268110,230732,227614,207695,208252,211056}', '{361837,303539,313992,311982,367090,372563,332824,394962,376029,389297,384324,327859,343569,307325,343046,338901,307756,356107,322505,342647,364988,342566,382026,350802,387984,391320,300316,301046,315385,355228,345530,344770,394447,362150,315964,364158,357353,306183,331820,371282,390066,333743,375653,327772,322587,348748,355155,368869,301736}', '{405595,498870,446926,449405,424290,448941,403646,477361,469242,411437,492096,407068,435374,443287,405944,475637,446269,493161}', '2024-04-15 18:57:54.809106-07', '2024-04-27 12:17:54.809106-07', '2024-03-14 02:26:54.809106-07', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('curt.rj8qbv0g@example.org', '戴醉珊', 'unknown', '2024-01-31 18:18:38.680796-08', '2024-04-27 19:57:38.680796-07', '2024-04-15 05:40:38.680797-07', '{129780,116017,144671,155957,151462,106563,169026,155252,102293,103983,193195,130048,195332,197922,168741,149297,160110,110400}', '{224141,247777,298848,287931,275718,204507,263550,201470,293077,266157,252666,219720,223981,227626,212177,253918,252790,262059,233476,285157,227731,206474,206355,240578,289069,257731,250056,244082,296685,222416,272365,265828}', '{327346,317531}', '{470238,456772,431395,452864,417907,452665,477116,426978,408856,481643,440233,419389,400117,435684,458571,455168,468838,497987,402435,483100,499177,492827,439815,450065,488262,431611,496785,440751,489762,453706,459328,421100,450233,432790,409908,439595,486638,433469,441389,404438,486069,415496,410606,424519,441220,439292}', '2024-04-23 20:55:38.680798-07', '2024-05-02 15:40:38.680798-07', '2024-03-19 20:38:38.680799-07');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('curt.rj8qbv0g@example.org', '戴醉珊', 'unknown', '2024-01-31 18:18:38.680796-08', '2024-04-27 19:57:38.680796-07', '2024-04-15 05:40:38.680797-07', '{129780,116017,144671,155957,151462,106563,169026,155252,102293,103983,193195,130048,195332,197922,168741,149297,160110,110400}', '{224141,247777,298848,287931,275718,204507,263550,201470,293077,266157,252666,219720,223981,227626,212177,253918,252790,262059,233476,285157,227731,206474,206355,240578,289069,257731,250056,244082,296685,222416,272365,265828}', '{327346,317531}', '{470238,456772,431395,452864,417907,452665,477116,426978,408856,481643,440233,419389,400117,435684,458571,455168,468838,497987,402435,483100,499177,492827,439815,450065,488262,431611,496785,440751,489762,453706,459328,421100,450233,432790,409908,439595,486638,433469,441389,404438,486069,415496,410606,424519,441220,439292}', '2024-04-23 20:55:38.680798-07', '2024-05-02 15:40:38.680798-07', '2024-03-19 20:38:38.680799-07', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('destany.a2b35zmo@example.net', '钱甜熙', 'unknown', '2024-01-11 06:36:50.359013-08', '2024-04-19 03:34:50.359013-07', '2024-04-06 13:42:50.359014-07', '{104894,137661,113034,127085,130959,167961,107665,104085,142894,117987,135987,160027,120681,119396,162282,162166,104849,154452,173085,148043,156820,133817,169344,121230,191772,170048,114677,133320,126271,184423,147316,102518,167329,148902,175996,131196,125356,131632,126978}', '{293026,223224,288327,265588,240708,274662,285844,255903,290313,245054,226318,218690,287621,255906,263510,283215,298370,291542,231897,258819,222592,246970,213631,224344,251768,227895,245561,289747,209880,214364,242242,271683,246953,275034,257732,252790,208351,282840,248405,260670,202456,201651,205432,294318,214973,203015}', '{376440,385685,353975,376978,326381,394595,301328,392369,314611,370732,318819,397696,343861,375603,318614,373394,311385,378154,320083,359217,302685,322710,387290,370898,305985,307806,364764,318115,356081,342356,353243,309224,371456,373472,351060,304418,350470,393625,340234,359637,377838,312261,344861,379521}', '{405010,489009,424317,431381,466340}', '2024-04-29 02:49:50.359016-07', '2024-04-25 02:03:50.359016-07', '2024-04-08 12:33:50.359016-07');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('destany.a2b35zmo@example.net', '钱甜熙', 'unknown', '2024-01-11 06:36:50.359013-08', '2024-04-19 03:34:50.359013-07', '2024-04-06 13:42:50.359014-07', '{104894,137661,113034,127085,130959,167961,107665,104085,142894,117987,135987,160027,120681,119396,162282,162166,104849,154452,173085,148043,156820,133817,169344,121230,191772,170048,114677,133320,126271,184423,147316,102518,167329,148902,175996,131196,125356,131632,126978}', '{293026,223224,288327,265588,240708,274662,285844,255903,290313,245054,226318,218690,287621,255906,263510,283215,298370,291542,231897,258819,222592,246970,213631,224344,251768,227895,245561,289747,209880,214364,242242,271683,246953,275034,257732,252790,208351,282840,248405,260670,202456,201651,205432,294318,214973,203015}', '{376440,385685,353975,376978,326381,394595,301328,392369,314611,370732,318819,397696,343861,375603,318614,373394,311385,378154,320083,359217,302685,322710,387290,370898,305985,307806,364764,318115,356081,342356,353243,309224,371456,373472,351060,304418,350470,393625,340234,359637,377838,312261,344861,379521}', '{405010,489009,424317,431381,466340}', '2024-04-29 02:49:50.359016-07', '2024-04-25 02:03:50.359016-07', '2024-04-08 12:33:50.359016-07', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('lulu.df22115e@example.net', '毛寒承', 'unknown', '2024-01-24 10:45:59.156634-08', '2024-04-24 13:55:59.156634-07', '2024-04-11 16:18:59.156634-07', '{149534,194896,117886,164208,113831,139521,133171,149864,110092,107489,188528,174045,192224,148120,119396,128717,167494,168960,170054,171658,139526,113599,120029,182937,103068,189852,120776,132970,159211,133876,138215,176573,150379,110159,181379,185231,145995,143818,154342,111078,164225,124338,157350,163615,117298,192463}', '{251307,252790,290389,262568,292156,213146,210601,213932,242350,266299,255263,240360,212951,210644,243916,251442,202344,201054,228308,294195,240330,223228,201268,203580,238279,274386,220116,289097,252103,282438,279101,232120,234436,210661,286485,262061,268798,287906,240179,282543,288356,211974,295369,200314,292712,243517,204640}', '{347985,390835}', '{424462,441175,453592,414418,453044,425639,413864,458398,420204,481985,410716,406259,435670,436735,454248,484743,478905,417262}', '2024-04-25 00:34:59.156635-07', '2024-04-26 00:47:59.156636-07', '2024-04-16 01:26:59.156636-07');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('lulu.df22115e@example.net', '毛寒承', 'unknown', '2024-01-24 10:45:59.156634-08', '2024-04-24 13:55:59.156634-07', '2024-04-11 16:18:59.156634-07', '{149534,194896,117886,164208,113831,139521,133171,149864,110092,107489,188528,174045,192224,148120,119396,128717,167494,168960,170054,171658,139526,113599,120029,182937,103068,189852,120776,132970,159211,133876,138215,176573,150379,110159,181379,185231,145995,143818,154342,111078,164225,124338,157350,163615,117298,192463}', '{251307,252790,290389,262568,292156,213146,210601,213932,242350,266299,255263,240360,212951,210644,243916,251442,202344,201054,228308,294195,240330,223228,201268,203580,238279,274386,220116,289097,252103,282438,279101,232120,234436,210661,286485,262061,268798,287906,240179,282543,288356,211974,295369,200314,292712,243517,204640}', '{347985,390835}', '{424462,441175,453592,414418,453044,425639,413864,458398,420204,481985,410716,406259,435670,436735,454248,484743,478905,417262}', '2024-04-25 00:34:59.156635-07', '2024-04-26 00:47:59.156636-07', '2024-04-16 01:26:59.156636-07', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('brenna.elx4os2u@example.net', '高菲霞', 'unknown', '2024-01-18 14:07:01.919141-08', '2024-04-25 02:57:01.919141-07', '2024-03-03 12:26:01.919141-08', '{106010,187707,104771,118386,110555,149767,160844,111843,126664,124611,117559,158230,142582,163456,100154,138765,116985,192813,127777,147483,148859,159358,143078,147422,159229,198973,137898,108549,106440,107178,182863,168375,103332,135357,156077,147892,178224,191323,136970}', '{294337,228115,268501,259069,236194,259064,205520,261488,231816,230272,210579,254853,250079,289130,281607,226150,264194,277454,216339,217384,295599,295623,290357,279836,252790}', '{334261,391970,302614,377592,380769,352323,398820,375851}', '{402730}', '2024-04-26 02:27:01.919143-07', '2024-05-03 01:06:01.919143-07', '2024-02-23 05:47:01.919143-08');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('brenna.elx4os2u@example.net', '高菲霞', 'unknown', '2024-01-18 14:07:01.919141-08', '2024-04-25 02:57:01.919141-07', '2024-03-03 12:26:01.919141-08', '{106010,187707,104771,118386,110555,149767,160844,111843,126664,124611,117559,158230,142582,163456,100154,138765,116985,192813,127777,147483,148859,159358,143078,147422,159229,198973,137898,108549,106440,107178,182863,168375,103332,135357,156077,147892,178224,191323,136970}', '{294337,228115,268501,259069,236194,259064,205520,261488,231816,230272,210579,254853,250079,289130,281607,226150,264194,277454,216339,217384,295599,295623,290357,279836,252790}', '{334261,391970,302614,377592,380769,352323,398820,375851}', '{402730}', '2024-04-26 02:27:01.919143-07', '2024-05-03 01:06:01.919143-07', '2024-02-23 05:47:01.919143-08', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('joanie.1r5bdjrm@example.org', '黄水瑶', 'unknown', '2024-01-16 06:46:07.679411-08', '2024-05-02 18:43:07.679411-07', '2024-02-29 09:31:07.679411-08', '{180693,186645,137819,187701,191945,175196,151448,103199,191356,191705,104743,159409,190816,123402,187637,164990,126817}', '{217778,265409,276823,269271,229653,276962,251988,261848,272157,204343,251828,203571,290586,279865,240333,232981,221821,220577,272365,209992,263552,255202,252790,208966,261025,239443,244921,221732,236292,271430,227922,290865,280913,221769,229366,272617,208808,275979,205360}', '{380694,366276,332395,390748,312101,398363,376466,369065,308850,314627,320377,310987,323188,322660,374202,341364,387305,326030,318729,372266,362056,343991,338579,324883,378569,364565,377701,393302,320888,377493,322494,331062,386635,386611,326069,312700,348502,395361,377884,305335,320621,380898,300816}', '{421119,493249,440479,475297,445311,474879,440137,418722,413333,462669,408393,452277,495844,484973,432191,412295,420250,416733,409054,470615,471680,497962,432686,462732,460537,442550,415307,406325,475692,487817,493262,431907,440673,476071,443954,447662,493304,425250,482531,447664,408457,408232,402905,488805,458798,433785,437524}', '2024-03-29 16:30:07.679414-07', '2024-05-03 20:38:07.679414-07', '2024-02-09 01:49:07.679414-08');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('joanie.1r5bdjrm@example.org', '黄水瑶', 'unknown', '2024-01-16 06:46:07.679411-08', '2024-05-02 18:43:07.679411-07', '2024-02-29 09:31:07.679411-08', '{180693,186645,137819,187701,191945,175196,151448,103199,191356,191705,104743,159409,190816,123402,187637,164990,126817}', '{217778,265409,276823,269271,229653,276962,251988,261848,272157,204343,251828,203571,290586,279865,240333,232981,221821,220577,272365,209992,263552,255202,252790,208966,261025,239443,244921,221732,236292,271430,227922,290865,280913,221769,229366,272617,208808,275979,205360}', '{380694,366276,332395,390748,312101,398363,376466,369065,308850,314627,320377,310987,323188,322660,374202,341364,387305,326030,318729,372266,362056,343991,338579,324883,378569,364565,377701,393302,320888,377493,322494,331062,386635,386611,326069,312700,348502,395361,377884,305335,320621,380898,300816}', '{421119,493249,440479,475297,445311,474879,440137,418722,413333,462669,408393,452277,495844,484973,432191,412295,420250,416733,409054,470615,471680,497962,432686,462732,460537,442550,415307,406325,475692,487817,493262,431907,440673,476071,443954,447662,493304,425250,482531,447664,408457,408232,402905,488805,458798,433785,437524}', '2024-03-29 16:30:07.679414-07', '2024-05-03 20:38:07.679414-07', '2024-02-09 01:49:07.679414-08', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('eulalia.b4srhslu@example.net', '陈明健', 'unknown', '2024-01-10 19:52:59.056043-08', '2024-05-02 03:26:59.056044-07', '2024-03-05 09:33:59.056044-08', '{173982,184566,168134,138173,133185,186241,148860,184885,100885,184739,199248,100028,122318,181817,196783,143585,146591,154114,185337,106387,194405,158474,110575,107760,102928,106924,189252,187405,149518,157428,187889,104275}', '{257147,267956,254005,267041,264008,299062,246333,289287,240542,252790,215688,243897,280309,200744,289934,227057,290054,224752,287831,274360,224012,204257,240658,282619,284625,246237,222589,278336,230652,203175,215499,273165,213267,297161,257194,217770,251616,242846,266285,213078,208417}', '{393768,347088,387342,365330,375796,386860,354012,373360,365130,372358,353296,371814,351054,327790,361875,348769,317142,372900,396045,341990,386425,315863,376348,340402,359472,306685,374326,398413,392231,356382,325943,355507,399974,325411,329606,386710,352137}', '{464519,419626,432487,428842,469531,452024,415685,419764,407680,490442,428875,404635,433338,485531,496300,439691,401119,476687,437339,424765,487519,486924,461425,447070,415884,467368,446848,416192,498517,416775,408897,471179,479846,456329,486768,453599,485003,438941}', '2024-04-02 02:39:59.05605-07', '2024-05-04 19:13:59.05605-07', '2024-03-02 21:32:59.056051-08');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('eulalia.b4srhslu@example.net', '陈明健', 'unknown', '2024-01-10 19:52:59.056043-08', '2024-05-02 03:26:59.056044-07', '2024-03-05 09:33:59.056044-08', '{173982,184566,168134,138173,133185,186241,148860,184885,100885,184739,199248,100028,122318,181817,196783,143585,146591,154114,185337,106387,194405,158474,110575,107760,102928,106924,189252,187405,149518,157428,187889,104275}', '{257147,267956,254005,267041,264008,299062,246333,289287,240542,252790,215688,243897,280309,200744,289934,227057,290054,224752,287831,274360,224012,204257,240658,282619,284625,246237,222589,278336,230652,203175,215499,273165,213267,297161,257194,217770,251616,242846,266285,213078,208417}', '{393768,347088,387342,365330,375796,386860,354012,373360,365130,372358,353296,371814,351054,327790,361875,348769,317142,372900,396045,341990,386425,315863,376348,340402,359472,306685,374326,398413,392231,356382,325943,355507,399974,325411,329606,386710,352137}', '{464519,419626,432487,428842,469531,452024,415685,419764,407680,490442,428875,404635,433338,485531,496300,439691,401119,476687,437339,424765,487519,486924,461425,447070,415884,467368,446848,416192,498517,416775,408897,471179,479846,456329,486768,453599,485003,438941}', '2024-04-02 02:39:59.05605-07', '2024-05-04 19:13:59.05605-07', '2024-03-02 21:32:59.056051-08', 1);
 
-INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
-  VALUES ('kristian.0rryng3u@example.org', '周雅儿', 'unknown', '2024-01-28 05:55:27.533741-08', '2024-04-19 10:00:27.533741-07', '2024-03-16 21:20:27.533742-07', '{}', '{277117,292465,277932,202947,255371,236690,262174,252790,293376,240909,281332,250598,286755,276202,295622,257544,232057,212099,221190,279232,250518,296147,271445,258399,248134,268758,277589,236446,214845,201057,238541,214883,220283,264232,235955,293087}', '{393964,325047,388391,390650,397032,363624,364408,386261,353545,328694,320142,386962,342702,356151,322578,324817,369887,350392,349136,324177,372548,334888,310664,331967,383186,378001,306422,340658,379021,322534,363468,374206,391799}', '{475451,450771,449095,402447,415877,410409,474826,485925,436578,401185,405349,467468,452988,453111,468026,428007,421632,468803,425496,490459,441580,437383,433101,480801,453259,416112,498665,437395,406465,481491,425107,485701,460511,444587,464856,414368,499301}', '2024-04-24 10:07:27.533744-07', '2024-05-03 22:13:27.533744-07', '2024-04-09 06:00:27.533745-07');
+INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification, ws_id)
+  VALUES ('kristian.0rryng3u@example.org', '周雅儿', 'unknown', '2024-01-28 05:55:27.533741-08', '2024-04-19 10:00:27.533741-07', '2024-03-16 21:20:27.533742-07', '{}', '{277117,292465,277932,202947,255371,236690,262174,252790,293376,240909,281332,250598,286755,276202,295622,257544,232057,212099,221190,279232,250518,296147,271445,258399,248134,268758,277589,236446,214845,201057,238541,214883,220283,264232,235955,293087}', '{393964,325047,388391,390650,397032,363624,364408,386261,353545,328694,320142,386962,342702,356151,322578,324817,369887,350392,349136,324177,372548,334888,310664,331967,383186,378001,306422,340658,379021,322534,363468,374206,391799}', '{475451,450771,449095,402447,415877,410409,474826,485925,436578,401185,405349,467468,452988,453111,468026,428007,421632,468803,425496,490459,441580,437383,433101,480801,453259,416112,498665,437395,406465,481491,425107,485701,460511,444587,464856,414368,499301}', '2024-04-24 10:07:27.533744-07', '2024-05-03 22:13:27.533744-07', '2024-04-09 06:00:27.533745-07', 1);
@@ -0,0 +1,9 @@
+-- users belong to a workspace. The users so far signed up to the only workspace there was, so
+-- they are moved to workspace 1 here, once. New users must be given their workspace
+ALTER TABLE user_stats ADD COLUMN ws_id bigint;
+
+UPDATE user_stats SET ws_id = 1;
+
+ALTER TABLE user_stats ALTER COLUMN ws_id SET NOT NULL;
+
+CREATE INDEX user_stats_ws_id_idx ON user_stats(ws_id);
@@ -1,105 +1,210 @@
-use std::fmt;
+use std::time::Instant;
 
 use chrono::{DateTime, TimeZone, Utc};
+use crm_bootstrap::trace::item_spans;
 use itertools::Itertools;
+use metrics::histogram;
 use prost_types::Timestamp;
+use sqlx::{postgres::PgArguments, query::QueryAs, Postgres, QueryBuilder};
 use tonic::{Response, Status};
-use tracing::info;
+use tracing::{info, warn};
 
 use crate::{
-    pb::{QueryRequest, RowQueryRequest, User},
+    pb::{
+        NotificationChannel, QueryRequest, RowQueryRequest, UpdateNotificationRequest,
+        UpdateNotificationResponse, User,
+    },
     QueryRequestBuilder, ResponseStream, ServiceResult, TimeQuery, UserStatsService,
 };
 
+const USER_COLUMNS: &str =
+    "email, name, COALESCE(started_but_not_finished, '{}') AS started_but_not_finished";
+/// the timestamp columns a query can filter on
+const TIMESTAMP_COLUMNS: &[&str] = &[
+    "created_at",
+    "last_visited_at",
+    "last_watched_at",
+    "last_email_notification",
+    "last_in_app_notification",
+    "last_sms_notification",
+];
+/// the content id columns a query can filter on
+const ID_COLUMNS: &[&str] = &[
+    "recent_watched",
+    "viewed_but_not_started",
+    "started_but_not_finished",
+    "finished",
+];
+
 impl UserStatsService {
     pub async fn query(&self, query: QueryRequest) -> ServiceResult<ResponseStream> {
-        // generate sql query from query request
-        let sql = query.to_string();
-        self.raw_query(RowQueryRequest { query: sql }).await
+        let mut sql = query.to_sql()?;
+        info!("Generated SQL query: {}", sql.sql());
+        self.fetch_users(sql.build_query_as()).await
     }
 
+    /// run arbitrary SQL, across workspaces
     pub async fn raw_query(&self, req: RowQueryRequest) -> ServiceResult<ResponseStream> {
         // TODO: query must only return email and name, so we should use sqlparser to parse the query
-        let Ok(ret) = sqlx::query_as::<_, User>(&req.query)
-            .fetch_all(&self.inner.pool)
-            .await
-        else {
-            return Err(Status::internal(format!(
-                "Failed to fetch data with query: {}",
-                req.query
-            )));
-        };
+        let ret = self.fetch_users(sqlx::query_as(&req.query)).await;
+        if ret.is_err() {
+            warn!("Failed raw query: {}", req.query);
+        }
+        ret
+    }
+
+    /// stream the users a query fetches, its error doesn't include the query
+    async fn fetch_users(
+        &self,
+        query: QueryAs<'_, Postgres, User, PgArguments>,
+    ) -> ServiceResult<ResponseStream> {
+        let start = Instant::now();
+        let ret = query.fetch_all(&self.inner.pool).await;
+        let result = if ret.is_ok() { "ok" } else { "error" };
+        histogram!("user_stat_query_duration_seconds", "result" => result)
+            .record(start.elapsed().as_secs_f64());
+        let ret = ret.map_err(|e| Status::internal(format!("Failed to fetch data: {}", e)))?;
 
-        Ok(Response::new(Box::pin(futures::stream::iter(
-            ret.into_iter().map(Ok),
-        ))))
+        histogram!("user_stat_query_users").record(ret.len() as f64);
+        let users = futures::stream::iter(ret.into_iter().map(Ok));
+        Ok(Response::new(Box::pin(item_spans(users, "user"))))
     }
-}
 
-fn ids_query(name: &str, ids: &[u32]) -> String {
-    if ids.is_empty() {
-        return "TRUE".to_string();
+    pub async fn update_notification(
+        &self,
+        req: UpdateNotificationRequest,
+    ) -> ServiceResult<UpdateNotificationResponse> {
+        let Some(column) = req.channel().column() else {
+            return Err(Status::invalid_argument("notification channel is required"));
+        };
+        let notified_at = match req.notified_at {
+            Some(ts) => ts_to_utc(ts)
+                .ok_or_else(|| Status::invalid_argument(format!("invalid timestamp {}", ts)))?,
+            None => Utc::now(),
+        };
+        // column comes from a fixed set of names, so it is safe to format into the sql
+        let sql = format!(
+            "UPDATE user_stats SET {} = $1 WHERE email = ANY($2) AND ws_id = $3",
+            column
+        );
+        let ret = sqlx::query(&sql)
+            .bind(notified_at)
+            .bind(&req.emails)
+            .bind(req.ws_id)
+            .execute(&self.inner.pool)
+            .await
+            .map_err(|e| {
+                Status::internal(format!("Failed to update {} for users: {}", column, e))
+            })?;
+
+        Ok(Response::new(UpdateNotificationResponse {
+            updated: ret.rows_affected() as u32,
+        }))
     }
-    format!("array{:?} <@ {}", ids, name)
 }
 
-fn timestamp_query(name: &str, lower: Option<Timestamp>, upper: Option<Timestamp>) -> String {
-    if lower.is_none() && upper.is_none() {
-        return "TRUE".to_string();
-    }
-    if lower.is_none() {
-        let upper = to_use_utc(upper.unwrap());
-        return format!("{} <= {}", name, upper.to_rfc3339());
-    }
-    if upper.is_none() {
-        let lower = ts_to_utc(lower.unwrap());
-        return format!("{} >= '{}'", name, lower.to_rfc3339());
+impl NotificationChannel {
+    /// the user_stats column recording the last notification sent on this channel
+    pub fn column(&self) -> Option<&'static str> {
+        match self {
+            NotificationChannel::Email => Some("last_email_notification"),
+            NotificationChannel::InApp => Some("last_in_app_notification"),
+            NotificationChannel::Sms => Some("last_sms_notification"),
+            NotificationChannel::Unspecified => None,
+        }
     }
+}
 
-    format!(
-        "{} BETWEEN '{}' AND '{}'",
-        name,
-        ts_to_utc(lower.unwrap()).to_rfc3339(),
-        ts_to_utc(upper.unwrap()).to_rfc3339()
-    )
+/// the column of a query, if it is one of the given columns
+fn column(name: &str, columns: &[&'static str]) -> Option<&'static str> {
+    columns.iter().find(|column| **column == name).copied()
 }
-fn ts_to_utc(ts: Timestamp) -> DateTime<Utc> {
-    Utc.timestamp_opt(ts.seconds, ts.nanos as _).unwrap()
+
+/// users whose column contains all the ids
+fn push_ids(sql: &mut QueryBuilder<'static, Postgres>, column: &str, ids: &[u32]) {
+    if ids.is_empty() {
+        return;
+    }
+    let ids = ids.iter().map(|id| *id as i32).collect::<Vec<_>>();
+    sql.push(format_args!(" AND {} @> ", column)).push_bind(ids);
 }
-fn to_use_utc(ts: Timestamp) -> DateTime<Utc> {
-    Utc.timestamp_opt(ts.seconds, ts.nanos as _).unwrap()
+
+/// users whose column is in the time range, or null if the query includes null
+fn push_timestamp(
+    sql: &mut QueryBuilder<'static, Postgres>,
+    column: &str,
+    tq: &TimeQuery,
+) -> Option<()> {
+    let lower = match tq.lower {
+        Some(ts) => Some(ts_to_utc(ts)?),
+        None => None,
+    };
+    let upper = match tq.upper {
+        Some(ts) => Some(ts_to_utc(ts)?),
+        None => None,
+    };
+    sql.push(" AND (");
+    if tq.include_null {
+        sql.push(format_args!("{} IS NULL OR ", column));
+    }
+    match (lower, upper) {
+        (None, None) => sql.push("TRUE"),
+        (None, Some(upper)) => sql.push(format_args!("{} <= ", column)).push_bind(upper),
+        (Some(lower), None) => sql.push(format_args!("{} >= ", column)).push_bind(lower),
+        (Some(lower), Some(upper)) => sql
+            .push(format_args!("{} BETWEEN ", column))
+            .push_bind(lower)
+            .push(" AND ")
+            .push_bind(upper),
+    };
+    sql.push(")");
+    Some(())
 }
-impl fmt::Display for QueryRequest {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        //generate sql based on query
-        let mut sql = "SELECT email, name FROM user_stats WHERE ".to_string();
-
-        let time_condition = self
-            .timestamps
-            .iter()
-            .map(|(k, v)| timestamp_query(k, v.lower, v.upper))
-            .join(" AND ");
-
-        sql.push_str(&time_condition);
-
-        let id_condition = self
-            .ids
-            .iter()
-            .map(|(k, v)| ids_query(k, &v.ids))
-            .join(" AND ");
-
-        if !id_condition.is_empty() {
-            sql.push_str(" AND ");
-            sql.push_str(&id_condition);
-        }
 
-        info!("Generated SQL query: {}", sql);
+/// the time of a timestamp, none if it is out of range
+fn ts_to_utc(ts: Timestamp) -> Option<DateTime<Utc>> {
+    Utc.timestamp_opt(ts.seconds, ts.nanos as _).single()
+}
 
-        write!(f, "{}", sql)
+impl TimeQuery {
+    /// match rows where the column is null or not after the given time
+    pub fn null_or_before(upper: DateTime<Utc>) -> Self {
+        TimeQuery {
+            lower: None,
+            upper: Some(Timestamp {
+                seconds: upper.timestamp(),
+                nanos: 0,
+            }),
+            include_null: true,
+        }
     }
 }
 
 impl QueryRequest {
+    /// the sql selecting the users of the workspace that match the query, with its values
+    /// bound. Only the known columns can be queried, in a stable order
+    #[allow(clippy::result_large_err)]
+    pub fn to_sql(&self) -> Result<QueryBuilder<'static, Postgres>, Status> {
+        let mut sql = QueryBuilder::new(format!(
+            "SELECT {} FROM user_stats WHERE ws_id = ",
+            USER_COLUMNS
+        ));
+        sql.push_bind(self.ws_id);
+        let unknown =
+            |name: &str| Status::invalid_argument(format!("can't query users by {:?}", name));
+        for (name, tq) in self.timestamps.iter().sorted_by_key(|(name, _)| *name) {
+            let column = column(name, TIMESTAMP_COLUMNS).ok_or_else(|| unknown(name))?;
+            push_timestamp(&mut sql, column, tq).ok_or_else(|| {
+                Status::invalid_argument(format!("invalid time range of {}", name))
+            })?;
+        }
+        for (name, ids) in self.ids.iter().sorted_by_key(|(name, _)| *name) {
+            let column = column(name, ID_COLUMNS).ok_or_else(|| unknown(name))?;
+            push_ids(&mut sql, column, &ids.ids);
+        }
+        Ok(sql)
+    }
+
     pub fn new_with_dt(name: &str, lower: DateTime<Utc>, upper: DateTime<Utc>) -> Self {
         let ts = Timestamp {
             seconds: lower.timestamp(),
@@ -113,6 +218,7 @@ impl QueryRequest {
         let tq = TimeQuery {
             lower: Some(ts),
             upper: Some(ts1),
+            include_null: false,
         };
 
         QueryRequestBuilder::default()
@@ -128,20 +234,88 @@ mod tests {
 
     use super::*;
     use crate::{
-        pb::QueryRequestBuilder,
+        pb::{QueryRequestBuilder, UpdateNotificationRequestBuilder},
         test_utils::{id, tq},
     };
 
     #[test]
-    fn query_request_to_string_should_work() {
+    fn query_request_to_sql_should_work() -> Result<()> {
         let d1 = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
         let d2 = Utc.with_ymd_and_hms(2024, 1, 2, 0, 0, 0).unwrap();
         let query = QueryRequest::new_with_dt("created_at", d1, d2);
-        let sql = query.to_string();
         assert_eq!(
-            sql,
-            "SELECT email, name FROM user_stats WHERE created_at BETWEEN '2024-01-01T00:00:00+00:00' AND '2024-01-02T00:00:00+00:00'"
+            query.to_sql()?.sql(),
+            "SELECT email, name, COALESCE(started_but_not_finished, '{}') AS started_but_not_finished FROM user_stats WHERE ws_id = $1 AND (created_at BETWEEN $2 AND $3)"
         );
+        Ok(())
+    }
+
+    #[test]
+    fn query_request_should_bind_ids() -> Result<()> {
+        let query = QueryRequestBuilder::default()
+            .id(("finished".to_string(), id(&[1, 2])))
+            .id(("recent_watched".to_string(), id(&[])))
+            .build()?;
+        assert!(query
+            .to_sql()?
+            .sql()
+            .ends_with("WHERE ws_id = $1 AND finished @> $2"));
+        Ok(())
+    }
+
+    #[test]
+    fn query_request_should_reject_unknown_columns() -> Result<()> {
+        let query = QueryRequestBuilder::default()
+            .timestamp(("TRUE OR created_at".to_string(), tq(Some(1), None)))
+            .build()?;
+        let code = query.to_sql().err().map(|e| e.code());
+        assert_eq!(code, Some(tonic::Code::InvalidArgument));
+
+        let query = QueryRequestBuilder::default()
+            .id(("created_at".to_string(), id(&[1])))
+            .build()?;
+        let code = query.to_sql().err().map(|e| e.code());
+        assert_eq!(code, Some(tonic::Code::InvalidArgument));
+        Ok(())
+    }
+
+    #[test]
+    fn timestamp_query_should_include_null() -> Result<()> {
+        let d1 = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let query = QueryRequestBuilder::default()
+            .timestamp((
+                "last_email_notification".to_string(),
+                TimeQuery::null_or_before(d1),
+            ))
+            .build()?;
+        assert!(query
+            .to_sql()?
+            .sql()
+            .ends_with("(last_email_notification IS NULL OR last_email_notification <= $2)"));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn update_notification_should_work() -> Result<()> {
+        let (_tdb, svc) = UserStatsService::new_for_test().await?;
+        let req = UpdateNotificationRequestBuilder::default()
+            .email("adolph.02ts3f95@example.org")
+            .email("not-exist@example.org")
+            .channel(NotificationChannel::Email as i32)
+            .ws_id(1)
+            .build()?;
+        let ret = svc.update_notification(req).await?.into_inner();
+        assert_eq!(ret.updated, 1);
+
+        // users of another workspace are left as is
+        let req = UpdateNotificationRequestBuilder::default()
+            .email("adolph.02ts3f95@example.org")
+            .channel(NotificationChannel::Email as i32)
+            .ws_id(2)
+            .build()?;
+        let ret = svc.update_notification(req).await?.into_inner();
+        assert_eq!(ret.updated, 0);
+        Ok(())
     }
 
     #[tokio::test]
@@ -166,6 +340,7 @@ mod tests {
             .timestamp(("created_at".to_string(), tq(Some(120), None)))
             .timestamp(("last_visited_at".to_string(), tq(Some(30), None)))
             .id(("viewed_but_not_started".to_string(), id(&[252790])))
+            .ws_id(1)
             .build()
             .unwrap();
         let mut stream = svc.query(query).await?.into_inner();
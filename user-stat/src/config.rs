@@ -1,37 +1,65 @@
-use anyhow::{bail, Result};
+use crm_auth::AuthConfig;
+use crm_bootstrap::{Config, TelemetryConfig};
+use crm_config::{Errors, Source, Validate};
 use serde::{Deserialize, Serialize};
-use std::{env, fs::File};
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct AppConfig {
     pub server: ServerConfig,
     pub auth: AuthConfig,
-}
-
-#[derive(Debug, Serialize, Deserialize)]
-pub struct AuthConfig {
-    pub pk: String,
+    #[serde(flatten)]
+    pub telemetry: TelemetryConfig,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ServerConfig {
-    pub port: u16,
+    #[serde(flatten)]
+    pub base: crm_bootstrap::ServerConfig,
     pub db_url: String,
 }
 
 impl AppConfig {
-    pub fn load() -> Result<Self> {
-        // read from  ./app.yml, or /etc/config/app.yml, or from env CHAT_CONFIG
-        let ret = match (
-            File::open("user_stat.yml"),
-            File::open("/etc/config/user_stat.yml"),
-            env::var("USER_STAT_CONFIG"),
-        ) {
-            (Ok(reader), _, _) => serde_yaml::from_reader(reader),
-            (_, Ok(reader), _) => serde_yaml::from_reader(reader),
-            (_, _, Ok(path)) => serde_yaml::from_reader(File::open(path)?),
-            _ => bail!("Config file not found"),
-        };
-        Ok(ret?)
+    /// the config of the tests, with a token for crm
+    #[cfg(feature = "test_utils")]
+    pub fn load_for_test() -> anyhow::Result<Self> {
+        let var = format!("{}_AUTH__SERVICE_TOKENS__CRM", Self::SOURCE.prefix);
+        let test = (var, "crm-test-token".to_string());
+        crm_config::load_from(&Self::SOURCE, std::env::vars().chain([test]))
+    }
+}
+
+impl Config for AppConfig {
+    /// read from ./user_stat.yml or /etc/config/user_stat.yml, then from USER_STAT_CONFIG and
+    /// USER_STAT_* env vars
+    const SOURCE: Source = Source {
+        file: "user_stat.yml",
+        prefix: "USER_STAT",
+    };
+
+    fn server(&self) -> &crm_bootstrap::ServerConfig {
+        &self.server.base
+    }
+
+    fn telemetry(&self) -> &TelemetryConfig {
+        &self.telemetry
+    }
+}
+
+impl Validate for AppConfig {
+    fn validate(&self, errors: &mut Errors) {
+        errors.field("server", &self.server);
+        errors.field("auth", &self.auth);
+        self.auth
+            .require_services(Self::SOURCE.prefix, &["crm"], errors);
+        self.telemetry.validate(errors);
+    }
+}
+
+impl Validate for ServerConfig {
+    fn validate(&self, errors: &mut Errors) {
+        self.base.validate(errors);
+        if self.db_url.is_empty() {
+            errors.add("db_url", "must not be empty");
+        }
     }
 }
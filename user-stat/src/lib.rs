@@ -1,15 +1,21 @@
 use std::{ops::Deref, pin::Pin, sync::Arc};
 
 pub use config::AppConfig;
+use crm_auth::{scope, Authenticated, DecodingKey, Scopes};
 use futures::Stream;
 pub use pb::*;
 use sqlx::PgPool;
-use tonic::{async_trait, Request, Response, Status};
+use tonic::{async_trait, server::NamedService, service::RoutesBuilder, Request, Response, Status};
+use tracing::warn;
 use user_stats_server::{UserStats, UserStatsServer};
 mod abi;
 mod config;
 mod pb;
 
+/// scope of each method. Raw queries read every workspace, so only services can run them, and
+/// only crm records the notifications it sent
+const SCOPES: Scopes = &[("Query", scope::CAMPAIGN_PREVIEW)];
+
 type ServiceResult<T> = Result<Response<T>, Status>;
 type ResponseStream = Pin<Box<dyn Stream<Item = Result<User, Status>> + Send + Sync>>;
 
@@ -30,7 +36,11 @@ impl UserStats for UserStatsService {
     type RowQueryStream = ResponseStream;
 
     async fn query(&self, request: Request<QueryRequest>) -> ServiceResult<Self::QueryStream> {
-        let query = request.into_inner();
+        let ws_id = workspace(&request, request.get_ref().ws_id)?;
+        let query = QueryRequest {
+            ws_id,
+            ..request.into_inner()
+        };
         self.query(query).await
     }
 
@@ -41,23 +51,94 @@ impl UserStats for UserStatsService {
         let query = request.into_inner();
         self.raw_query(query).await
     }
+
+    async fn update_notification(
+        &self,
+        request: Request<UpdateNotificationRequest>,
+    ) -> ServiceResult<UpdateNotificationResponse> {
+        let ws_id = workspace(&request, request.get_ref().ws_id)?;
+        let req = UpdateNotificationRequest {
+            ws_id,
+            ..request.into_inner()
+        };
+        self.update_notification(req).await
+    }
+}
+
+/// a request naming no workspace, or one the caller can't access
+#[derive(Debug, PartialEq)]
+enum WorkspaceError {
+    /// services must name the workspace
+    Missing,
+    /// users can only access their own
+    Other,
+}
+
+/// the workspace whose users a request reads or updates. Users are confined to their own, which
+/// is the default, services must name one
+fn workspace<T>(request: &Request<T>, ws_id: i64) -> Result<i64, WorkspaceError> {
+    match request.extensions().get::<crm_auth::User>() {
+        Some(user) if ws_id == 0 || ws_id == user.ws_id => Ok(user.ws_id),
+        Some(_) => Err(WorkspaceError::Other),
+        None if ws_id == 0 => Err(WorkspaceError::Missing),
+        None => Ok(ws_id),
+    }
+}
+
+impl From<WorkspaceError> for Status {
+    fn from(e: WorkspaceError) -> Self {
+        match e {
+            WorkspaceError::Missing => Status::invalid_argument("ws_id is required"),
+            WorkspaceError::Other => {
+                Status::permission_denied("users of other workspaces are not accessible")
+            }
+        }
+    }
 }
 
 impl UserStatsService {
-    pub async fn new(config: AppConfig) -> Self {
-        let pool = PgPool::connect(&config.server.db_url)
-            .await
-            .expect("Failed to connect to database");
+    pub async fn new(config: AppConfig) -> anyhow::Result<Self> {
+        let pool = PgPool::connect(&config.server.db_url).await?;
         let inner = UserStatsServiceInner { config, pool };
 
-        Self {
+        Ok(Self {
             inner: Arc::new(inner),
-        }
+        })
+    }
+    pub fn into_server(self) -> anyhow::Result<Authenticated<UserStatsServer<Self>>> {
+        let dk = DecodingKey::load(&self.config.auth)?;
+        dk.watch_jwks();
+        Ok(dk.wrap(UserStatsServer::new(self), SCOPES))
+    }
+}
+
+#[async_trait]
+impl crm_bootstrap::Service for UserStatsService {
+    type Config = AppConfig;
+    const NAME: &'static str = UserStatsServer::<Self>::NAME;
+    const FILE_DESCRIPTOR_SET: &'static [u8] = FILE_DESCRIPTOR_SET;
+
+    async fn start(config: AppConfig) -> anyhow::Result<Self> {
+        Self::new(config).await
+    }
+
+    fn add_to(self, routes: &mut RoutesBuilder) -> anyhow::Result<()> {
+        routes.add_service(self.into_server()?);
+        Ok(())
     }
-    pub fn into_server(self) -> UserStatsServer<Self> {
-        UserStatsServer::new(self)
+
+    /// healthy while the database answers
+    async fn is_healthy(&self) -> bool {
+        match sqlx::query("SELECT 1").execute(&self.pool).await {
+            Ok(_) => true,
+            Err(e) => {
+                warn!("database is unavailable: {}", e);
+                false
+            }
+        }
     }
 }
+
 impl Deref for UserStatsService {
     type Target = UserStatsServiceInner;
 
@@ -83,7 +164,7 @@ pub mod test_utils {
 
     impl UserStatsService {
         pub async fn new_for_test() -> Result<(TestPg, Self)> {
-            let config = AppConfig::load()?;
+            let config = AppConfig::load_for_test()?;
             let post = config.server.db_url.rfind('/').expect("invalid db_url");
             let server_url = &config.server.db_url[..post];
             let (tdb, pool) = get_test_pool(Some(server_url)).await;
@@ -125,6 +206,7 @@ pub mod test_utils {
         TimeQuery {
             lower: lower.map(to_ts),
             upper: upper.map(to_ts),
+            include_null: false,
         }
     }
 
@@ -140,3 +222,32 @@ pub mod test_utils {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn users_should_only_access_their_workspace() {
+        let mut request = Request::new(());
+        request.extensions_mut().insert(crm_auth::User {
+            id: 1,
+            ws_id: 1,
+            fullname: "Tyr Chen".to_string(),
+            email: "tchen@acme.org".to_string(),
+            password_hash: String::new(),
+            created_at: "2024-07-01T00:00:00Z".to_string(),
+            scopes: vec![crm_auth::scope::CAMPAIGN_PREVIEW.to_string()],
+        });
+        assert_eq!(workspace(&request, 0), Ok(1));
+        assert_eq!(workspace(&request, 1), Ok(1));
+        assert_eq!(workspace(&request, 2), Err(WorkspaceError::Other));
+
+        let mut request = Request::new(());
+        request.extensions_mut().insert(crm_auth::Service {
+            name: "crm".to_string(),
+        });
+        assert_eq!(workspace(&request, 2), Ok(2));
+        assert_eq!(workspace(&request, 0), Err(WorkspaceError::Missing));
+    }
+}
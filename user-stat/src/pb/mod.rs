@@ -1,2 +1,5 @@
 mod user_stats;
 pub use user_stats::*;
+
+/// descriptors of the protos, for the reflection service
+pub const FILE_DESCRIPTOR_SET: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/user_stats.bin"));
@@ -12,6 +12,10 @@ pub struct User {
     #[prost(string, tag = "2")]
     #[builder(setter(into))]
     pub name: ::prost::alloc::string::String,
+    /// content ids the user has started but not finished yet
+    #[prost(int32, repeated, tag = "3")]
+    #[sqlx(default)]
+    pub started_but_not_finished: ::prost::alloc::vec::Vec<i32>,
 }
 /// create_at,last_visited_at,..
 #[derive(derive_builder::Builder)]
@@ -25,6 +29,10 @@ pub struct QueryRequest {
     #[prost(map = "string, message", tag = "2")]
     #[builder(setter(each(name = "id", into)))]
     pub ids: ::std::collections::HashMap<::prost::alloc::string::String, IdQuery>,
+    /// only users of this workspace. Users can only query their own, which is the default, services
+    /// must set it
+    #[prost(int64, tag = "3")]
+    pub ws_id: i64,
 }
 #[derive(derive_builder::Builder)]
 #[builder(setter(into, strip_option), default)]
@@ -43,6 +51,9 @@ pub struct TimeQuery {
     pub lower: ::core::option::Option<::prost_types::Timestamp>,
     #[prost(message, optional, tag = "2")]
     pub upper: ::core::option::Option<::prost_types::Timestamp>,
+    /// also match rows where the column is null
+    #[prost(bool, tag = "3")]
+    pub include_null: bool,
 }
 #[derive(derive_builder::Builder)]
 #[builder(setter(into, strip_option), default)]
@@ -52,6 +63,62 @@ pub struct IdQuery {
     #[prost(uint32, repeated, tag = "1")]
     pub ids: ::prost::alloc::vec::Vec<u32>,
 }
+#[derive(derive_builder::Builder)]
+#[builder(setter(into, strip_option), default)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpdateNotificationRequest {
+    /// emails of the users that were notified
+    #[prost(string, repeated, tag = "1")]
+    #[builder(setter(each(name = "email", into)))]
+    pub emails: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(enumeration = "NotificationChannel", tag = "2")]
+    pub channel: i32,
+    /// defaults to now
+    #[prost(message, optional, tag = "3")]
+    pub notified_at: ::core::option::Option<::prost_types::Timestamp>,
+    /// only update users of this workspace, required
+    #[prost(int64, tag = "4")]
+    pub ws_id: i64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpdateNotificationResponse {
+    #[prost(uint32, tag = "1")]
+    pub updated: u32,
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum NotificationChannel {
+    Unspecified = 0,
+    Email = 1,
+    InApp = 2,
+    Sms = 3,
+}
+impl NotificationChannel {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            NotificationChannel::Unspecified => "NOTIFICATION_CHANNEL_UNSPECIFIED",
+            NotificationChannel::Email => "NOTIFICATION_CHANNEL_EMAIL",
+            NotificationChannel::InApp => "NOTIFICATION_CHANNEL_IN_APP",
+            NotificationChannel::Sms => "NOTIFICATION_CHANNEL_SMS",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "NOTIFICATION_CHANNEL_UNSPECIFIED" => Some(Self::Unspecified),
+            "NOTIFICATION_CHANNEL_EMAIL" => Some(Self::Email),
+            "NOTIFICATION_CHANNEL_IN_APP" => Some(Self::InApp),
+            "NOTIFICATION_CHANNEL_SMS" => Some(Self::Sms),
+            _ => None,
+        }
+    }
+}
 /// Generated client implementations.
 pub mod user_stats_client {
     #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
@@ -172,6 +239,27 @@ pub mod user_stats_client {
                 .insert(GrpcMethod::new("user_stats.UserStats", "RowQuery"));
             self.inner.server_streaming(req, path, codec).await
         }
+        pub async fn update_notification(
+            &mut self,
+            request: impl tonic::IntoRequest<super::UpdateNotificationRequest>,
+        ) -> std::result::Result<tonic::Response<super::UpdateNotificationResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path =
+                http::uri::PathAndQuery::from_static("/user_stats.UserStats/UpdateNotification");
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "user_stats.UserStats",
+                "UpdateNotification",
+            ));
+            self.inner.unary(req, path, codec).await
+        }
     }
 }
 /// Generated server implementations.
@@ -199,6 +287,10 @@ pub mod user_stats_server {
             &self,
             request: tonic::Request<super::RowQueryRequest>,
         ) -> std::result::Result<tonic::Response<Self::RowQueryStream>, tonic::Status>;
+        async fn update_notification(
+            &self,
+            request: tonic::Request<super::UpdateNotificationRequest>,
+        ) -> std::result::Result<tonic::Response<super::UpdateNotificationResponse>, tonic::Status>;
     }
     #[derive(Debug)]
     pub struct UserStatsServer<T: UserStats> {
@@ -359,6 +451,48 @@ pub mod user_stats_server {
                     };
                     Box::pin(fut)
                 }
+                "/user_stats.UserStats/UpdateNotification" => {
+                    #[allow(non_camel_case_types)]
+                    struct UpdateNotificationSvc<T: UserStats>(pub Arc<T>);
+                    impl<T: UserStats> tonic::server::UnaryService<super::UpdateNotificationRequest>
+                        for UpdateNotificationSvc<T>
+                    {
+                        type Response = super::UpdateNotificationResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::UpdateNotificationRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as UserStats>::update_notification(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = UpdateNotificationSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 _ => Box::pin(async move {
                     Ok(http::Response::builder()
                         .status(200)
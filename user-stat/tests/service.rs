@@ -1,21 +1,29 @@
 use std::{net::SocketAddr, time::Duration};
 
 use anyhow::Result;
+use crm_auth::ServiceToken;
 use futures::StreamExt;
 use sqlx_db_tester::TestPg;
 use tokio::time::sleep;
-use tonic::transport::Server;
+use tonic::{
+    service::interceptor::InterceptedService,
+    transport::{Channel, Server},
+    Code,
+};
+use tonic_health::pb::{
+    health_check_response::ServingStatus, health_client::HealthClient, HealthCheckRequest,
+};
 use user_stat::{
     test_utils::{id, tq},
     user_stats_client::UserStatsClient,
-    QueryRequestBuilder, RowQueryRequestBuilder, UserStatsService,
+    AppConfig, QueryRequestBuilder, RowQueryRequestBuilder, UserStatsService,
 };
 const PORT_BASE: u32 = 60000;
 
 #[tokio::test]
 async fn raw_query_should_work() -> Result<()> {
     let (_tdb, addr) = start_server(PORT_BASE).await?;
-    let mut client = UserStatsClient::connect(format!("http://{}", addr)).await?;
+    let mut client = connect(addr).await?;
     let req = RowQueryRequestBuilder::default()
         .query("SELECT * FROM user_stats WHERE created_at > '2024-01-01' LIMIT 5")
         .build()?;
@@ -32,11 +40,12 @@ async fn raw_query_should_work() -> Result<()> {
 #[tokio::test]
 async fn query_should_work() -> Result<()> {
     let (_tdb, addr) = start_server(PORT_BASE + 1).await?;
-    let mut client = UserStatsClient::connect(format!("http://{}", addr)).await?;
+    let mut client = connect(addr).await?;
     let query = QueryRequestBuilder::default()
         .timestamp(("created_at".to_string(), tq(Some(180), None)))
         .timestamp(("last_visited_at".to_string(), tq(Some(30), None)))
         .id(("viewed_but_not_started".to_string(), id(&[252790])))
+        .ws_id(1)
         .build()
         .unwrap();
     let stream = client.query(query).await?.into_inner();
@@ -46,14 +55,54 @@ async fn query_should_work() -> Result<()> {
     }
     Ok(())
 }
+#[tokio::test]
+async fn raw_query_without_token_should_fail() -> Result<()> {
+    let (_tdb, addr) = start_server(PORT_BASE + 2).await?;
+    let mut client = UserStatsClient::connect(format!("http://{}", addr)).await?;
+    let req = RowQueryRequestBuilder::default()
+        .query("SELECT * FROM user_stats LIMIT 5")
+        .build()?;
+    let ret = client.row_query(req).await;
+    assert_eq!(ret.unwrap_err().code(), Code::Unauthenticated);
+    Ok(())
+}
+
+#[tokio::test]
+async fn health_should_be_serving_with_database() -> Result<()> {
+    let (_tdb, addr) = start_server(PORT_BASE + 3).await?;
+    let channel = Channel::from_shared(format!("http://{}", addr))?
+        .connect()
+        .await?;
+    let mut client = HealthClient::new(channel);
+    let req = HealthCheckRequest {
+        service: "user_stats.UserStats".to_string(),
+    };
+    let res = client.check(req).await?.into_inner();
+    assert_eq!(res.status(), ServingStatus::Serving);
+    Ok(())
+}
+
+/// a client calling with the token of crm
+async fn connect(
+    addr: SocketAddr,
+) -> Result<UserStatsClient<InterceptedService<Channel, ServiceToken>>> {
+    let config = AppConfig::load_for_test()?;
+    let token = ServiceToken::new(config.auth.service_tokens.get("crm").map(|t| t.as_str()))?;
+    let channel = Channel::from_shared(format!("http://{}", addr))?
+        .connect()
+        .await?;
+    Ok(UserStatsClient::with_interceptor(channel, token))
+}
+
 async fn start_server(port: u32) -> Result<(TestPg, SocketAddr)> {
     let addr = format!("[::1]:{}", port).parse()?;
 
     let (tdb, svc) = UserStatsService::new_for_test().await?;
+    let routes = crm_bootstrap::routes(svc, std::future::pending()).await?;
 
     tokio::spawn(async move {
         Server::builder()
-            .add_service(svc.into_server())
+            .add_routes(routes)
             .serve(addr)
             .await
             .unwrap();